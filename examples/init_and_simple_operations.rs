@@ -1,5 +1,3 @@
-use simple_redis;
-
 fn main() {
     match simple_redis::create("redis://127.0.0.1:6379/") {
         Ok(mut client) => {
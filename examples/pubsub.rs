@@ -1,4 +1,3 @@
-use simple_redis;
 use simple_redis::{Interrupts, Message};
 use std::{thread, time};
 
@@ -37,7 +36,7 @@ fn main() {
                         println!("Read message: {}", payload);
                         assert_eq!(payload, "example message");
 
-                        counter = counter + 1;
+                        counter += 1;
 
                         counter >= 2
                     },
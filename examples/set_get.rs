@@ -1,4 +1,3 @@
-use simple_redis;
 use std::{thread, time};
 
 fn main() {
@@ -1,4 +1,3 @@
-use simple_redis;
 use simple_redis::{Interrupts, Message};
 
 fn main() {
@@ -27,7 +26,7 @@ fn main() {
                         let mut interrupts = Interrupts::new();
                         interrupts.next_polling_time = Some(150);
 
-                        polling_counter = polling_counter + 1;
+                        polling_counter += 1;
                         if polling_counter > 3 {
                             interrupts.stop = true;
                         }
@@ -0,0 +1,211 @@
+#![deny(
+    future_incompatible,
+    keyword_idents,
+    let_underscore,
+    nonstandard_style,
+    unused
+)]
+#![warn(unknown_lints)]
+
+//! # simple_redis_derive
+//!
+//! Derive macros for the [simple_redis](https://crates.io/crates/simple_redis) crate.<br>
+//! This crate should not be used directly, instead enable the `derive` feature of the simple_redis crate.
+//!
+
+use proc_macro::TokenStream;
+use proc_macro2::TokenStream as TokenStream2;
+use quote::quote;
+use syn::{
+    parse_macro_input, Data, DeriveInput, Error, Fields, GenericArgument, LitStr, PathArguments,
+    Type,
+};
+
+/// Holds the hash mapping of a single struct field
+struct HashField {
+    /// The struct field name
+    ident: syn::Ident,
+    /// The redis hash field name
+    name: String,
+    /// The inner type in case the field type is an Option
+    optional_type: Option<Type>,
+    /// The field type
+    field_type: Type,
+}
+
+/// Returns the inner type in case the provided type is an Option.
+fn get_optional_type(field_type: &Type) -> Option<Type> {
+    match field_type {
+        Type::Path(type_path) if type_path.qself.is_none() => {
+            let segment = type_path.path.segments.last()?;
+            if segment.ident != "Option" {
+                return None;
+            }
+
+            match segment.arguments {
+                PathArguments::AngleBracketed(ref arguments) if arguments.args.len() == 1 => {
+                    match arguments.args.first() {
+                        Some(GenericArgument::Type(inner_type)) => Some(inner_type.clone()),
+                        _ => None,
+                    }
+                }
+                _ => None,
+            }
+        }
+        _ => None,
+    }
+}
+
+/// Returns the hash field name, which is the struct field name unless renamed via `#[redis(rename = "...")]`.
+fn get_field_name(field: &syn::Field, ident: &syn::Ident) -> Result<String, Error> {
+    let mut name = ident.to_string();
+
+    for attribute in &field.attrs {
+        if attribute.path().is_ident("redis") {
+            attribute.parse_nested_meta(|meta| {
+                if meta.path.is_ident("rename") {
+                    let value: LitStr = meta.value()?.parse()?;
+                    name = value.value();
+                    Ok(())
+                } else {
+                    Err(meta.error("unsupported redis attribute"))
+                }
+            })?;
+        }
+    }
+
+    Ok(name)
+}
+
+fn get_fields(input: &DeriveInput) -> Result<Vec<HashField>, Error> {
+    let named_fields = match input.data {
+        Data::Struct(ref data) => match data.fields {
+            Fields::Named(ref fields) => &fields.named,
+            _ => {
+                return Err(Error::new_spanned(
+                    &input.ident,
+                    "RedisHash can only be derived for structs with named fields",
+                ))
+            }
+        },
+        _ => {
+            return Err(Error::new_spanned(
+                &input.ident,
+                "RedisHash can only be derived for structs",
+            ))
+        }
+    };
+
+    let mut fields = vec![];
+    for field in named_fields {
+        let ident = match field.ident {
+            Some(ref ident) => ident.clone(),
+            None => return Err(Error::new_spanned(field, "missing field name")),
+        };
+        let name = get_field_name(field, &ident)?;
+
+        fields.push(HashField {
+            ident,
+            name,
+            optional_type: get_optional_type(&field.ty),
+            field_type: field.ty.clone(),
+        });
+    }
+
+    Ok(fields)
+}
+
+fn expand(input: &DeriveInput) -> Result<TokenStream2, Error> {
+    let fields = get_fields(input)?;
+
+    let to_fields = fields.iter().map(|field| {
+        let ident = &field.ident;
+        let name = &field.name;
+
+        match field.optional_type {
+            Some(_) => quote! {
+                if let ::std::option::Option::Some(ref value) = self.#ident {
+                    fields.push((#name.to_string(), value.to_string()));
+                }
+            },
+            None => quote! {
+                fields.push((#name.to_string(), self.#ident.to_string()));
+            },
+        }
+    });
+
+    let removed_fields = fields
+        .iter()
+        .filter(|field| field.optional_type.is_some())
+        .map(|field| {
+            let ident = &field.ident;
+            let name = &field.name;
+
+            quote! {
+                if self.#ident.is_none() {
+                    fields.push(#name.to_string());
+                }
+            }
+        });
+
+    let from_fields = fields.iter().map(|field| {
+        let ident = &field.ident;
+        let name = &field.name;
+
+        match field.optional_type {
+            Some(ref inner_type) => quote! {
+                #ident: ::simple_redis::hash::parse_optional_field::<#inner_type>(key, #name, values)?
+            },
+            None => {
+                let field_type = &field.field_type;
+                quote! {
+                    #ident: ::simple_redis::hash::parse_field::<#field_type>(key, #name, values)?
+                }
+            }
+        }
+    });
+
+    let name = &input.ident;
+    let (impl_generics, type_generics, where_clause) = input.generics.split_for_impl();
+    let capacity = fields.len();
+
+    Ok(quote! {
+        impl #impl_generics ::simple_redis::hash::RedisHash for #name #type_generics #where_clause {
+            fn to_redis_fields(&self) -> ::std::vec::Vec<(::std::string::String, ::std::string::String)> {
+                let mut fields = ::std::vec::Vec::with_capacity(#capacity);
+                #(#to_fields)*
+                fields
+            }
+
+            fn to_removed_redis_fields(&self) -> ::std::vec::Vec<::std::string::String> {
+                let mut fields = ::std::vec::Vec::new();
+                #(#removed_fields)*
+                fields
+            }
+
+            fn from_redis_fields(
+                key: &str,
+                values: &::std::collections::HashMap<::std::string::String, ::std::string::String>,
+            ) -> ::simple_redis::RedisResult<Self> {
+                ::std::result::Result::Ok(#name {
+                    #(#from_fields,)*
+                })
+            }
+        }
+    })
+}
+
+/// Implements the simple_redis `RedisHash` trait for structs with named fields.<br>
+/// Each field is stored as a hash field using its `ToString` implementation and parsed back using
+/// its `FromStr` implementation.<br>
+/// Fields of an `Option` type are removed from the hash when `None` and are optional when reading the hash.<br>
+/// The hash field name can be modified via the `#[redis(rename = "name")]` attribute.
+#[proc_macro_derive(RedisHash, attributes(redis))]
+pub fn derive_redis_hash(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+
+    match expand(&input) {
+        Ok(output) => output.into(),
+        Err(error) => error.to_compile_error().into(),
+    }
+}
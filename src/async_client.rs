@@ -0,0 +1,560 @@
+//! # async_client
+//!
+//! Implements the async redis client capabilities, backed by a multiplexed connection.<br>
+//! This module is only available when the `async` feature is enabled.
+//!
+
+#[cfg(test)]
+#[path = "./async_client_test.rs"]
+mod async_client_test;
+
+use crate::client::{is_idempotent_command, ClientBuilder};
+use crate::connection::{self, ConnectionOptions};
+use crate::types::{
+    create_not_found_error, create_parse_error, from_redis_error, Message, RedisBoolResult,
+    RedisEmptyResult, RedisError, RedisResult, RedisStringResult,
+};
+use futures_util::stream::{self, Stream, StreamExt};
+use redis::aio::MultiplexedConnection;
+use std::future::Future;
+use std::pin::Pin;
+use std::str::FromStr;
+use std::time::{Duration, Instant};
+
+/// Stream of the messages received from all subscriptions.<br>
+/// In case the subscription connection is lost, it is reopened and all subscriptions are issued again.<br>
+/// The stream ends after returning an error, in case the connection could not be reopened based on the
+/// reconnect policy.
+pub type MessageStream = Pin<Box<dyn Stream<Item = RedisResult<Message>> + Send>>;
+
+/// The async redis client which enables to invoke redis operations without blocking the current thread.<br>
+/// The client can be cloned cheaply, in which case all clones share the same multiplexed connection until it
+/// has to be reopened.
+#[derive(Clone)]
+pub struct AsyncClient {
+    /// Internal redis client
+    client: redis::Client,
+    /// Holds the current multiplexed connection
+    connection: Option<MultiplexedConnection>,
+    /// The last time the connection was used by this client
+    last_used: Option<Instant>,
+    /// Options applied on every opened connection
+    options: ConnectionOptions,
+    /// The subscribed channels
+    subscriptions: Vec<String>,
+    /// The subscribed channel patterns
+    psubscriptions: Vec<String>,
+}
+
+/// Holds the message stream state.
+struct SubscriptionState {
+    client: redis::Client,
+    options: ConnectionOptions,
+    subscriptions: Vec<String>,
+    psubscriptions: Vec<String>,
+    messages: Option<Pin<Box<dyn Stream<Item = Message> + Send>>>,
+    done: bool,
+}
+
+/// Invokes the provided connect future while applying the connect timeout (if defined).
+async fn with_connect_timeout<T, F>(timeout: Option<Duration>, future: F) -> RedisResult<T>
+where
+    F: Future<Output = redis::RedisResult<T>>,
+{
+    let result = match timeout {
+        Some(timeout) => match tokio::time::timeout(timeout, future).await {
+            Ok(result) => result,
+            Err(_) => return Err(RedisError::Timeout(None)),
+        },
+        None => future.await,
+    };
+
+    match result {
+        Ok(output) => Ok(output),
+        Err(error) => Err(error.into()),
+    }
+}
+
+/// Invokes the provided open function until it succeeds or no more attempts should be made based on the
+/// reconnect policy.
+async fn open_with_retries<T, F, O>(options: &ConnectionOptions, mut open: O) -> RedisResult<T>
+where
+    F: Future<Output = RedisResult<T>>,
+    O: FnMut() -> F,
+{
+    let start_time = Instant::now();
+    let mut attempt = 0;
+    loop {
+        match open().await {
+            Ok(output) => return Ok(output),
+            Err(error) => {
+                attempt += 1;
+
+                match connection::get_reconnect_delay(
+                    &options.reconnect_policy,
+                    attempt,
+                    start_time.elapsed(),
+                ) {
+                    Some(delay) => tokio::time::sleep(delay).await,
+                    None => return Err(error),
+                }
+            }
+        }
+    }
+}
+
+/// Returns the response timeout of the multiplexed connection.<br>
+/// The multiplexed connection does not expose separate socket timeouts, so the response timeout bounds both
+/// writing the request and reading the response (the sum of the write and read timeouts).
+fn get_response_timeout(options: &ConnectionOptions) -> Option<Duration> {
+    match (options.write_timeout, options.read_timeout) {
+        (Some(write_timeout), Some(read_timeout)) => Some(write_timeout + read_timeout),
+        (Some(timeout), None) | (None, Some(timeout)) => Some(timeout),
+        (None, None) => None,
+    }
+}
+
+/// Opens a new multiplexed connection and applies all the provided options on it.
+async fn open(
+    client: &redis::Client,
+    options: &ConnectionOptions,
+) -> RedisResult<MultiplexedConnection> {
+    let mut config = redis::AsyncConnectionConfig::new();
+    if let Some(timeout) = options.connect_timeout {
+        config = config.set_connection_timeout(timeout);
+    }
+    if let Some(timeout) = get_response_timeout(options) {
+        config = config.set_response_timeout(timeout);
+    }
+
+    let mut redis_connection = match client
+        .get_multiplexed_async_connection_with_config(&config)
+        .await
+    {
+        Ok(redis_connection) => redis_connection,
+        Err(error) => return Err(error.into()),
+    };
+
+    if let Some(ref client_name) = options.client_name {
+        let result: redis::RedisResult<()> = redis::cmd("CLIENT")
+            .arg("SETNAME")
+            .arg(client_name)
+            .query_async(&mut redis_connection)
+            .await;
+
+        if let Err(error) = result {
+            return Err(error.into());
+        }
+    }
+
+    Ok(redis_connection)
+}
+
+/// Opens a new pubsub connection, subscribes to all provided channels and returns the messages stream.
+async fn subscribe_all(
+    client: &redis::Client,
+    options: &ConnectionOptions,
+    subscriptions: &[String],
+    psubscriptions: &[String],
+) -> RedisResult<Pin<Box<dyn Stream<Item = Message> + Send>>> {
+    let mut redis_pubsub =
+        with_connect_timeout(options.connect_timeout, client.get_async_pubsub()).await?;
+
+    for channel in subscriptions {
+        if let Err(error) = redis_pubsub.subscribe(channel).await {
+            return Err(error.into());
+        }
+    }
+
+    for channel in psubscriptions {
+        if let Err(error) = redis_pubsub.psubscribe(channel).await {
+            return Err(error.into());
+        }
+    }
+
+    Ok(Box::pin(redis_pubsub.into_on_message()))
+}
+
+/// Returns the next message, resubscribing in case the subscription connection was lost.
+async fn next_message(
+    mut state: SubscriptionState,
+) -> Option<(RedisResult<Message>, SubscriptionState)> {
+    loop {
+        if state.done {
+            return None;
+        }
+
+        match state.messages {
+            Some(ref mut messages) => match messages.next().await {
+                Some(message) => return Some((Ok(message), state)),
+                // the connection was lost
+                None => state.messages = None,
+            },
+            None => {
+                let result = open_with_retries(&state.options, || {
+                    subscribe_all(
+                        &state.client,
+                        &state.options,
+                        &state.subscriptions,
+                        &state.psubscriptions,
+                    )
+                })
+                .await;
+
+                match result {
+                    Ok(messages) => state.messages = Some(messages),
+                    Err(error) => {
+                        state.done = true;
+                        return Some((Err(error), state));
+                    }
+                }
+            }
+        }
+    }
+}
+
+fn remove_channel(channels: &mut Vec<String>, channel: &str) {
+    if let Some(index) = channels.iter().position(|value| value == channel) {
+        channels.remove(index);
+    }
+}
+
+impl AsyncClient {
+    /// Returns true if a connection is currently held by the client.<br>
+    /// There is no need to call this function as any redis operation invocation will
+    /// ensure a valid connection is created.
+    pub fn is_connection_open(self: &AsyncClient) -> bool {
+        self.connection.is_some()
+    }
+
+    /// Drops the internal connection to redis.<br>
+    /// The client can still be reused and any invocation of other operations after this call,
+    /// will reopen the connection.<br>
+    /// Other clones of this client keep using the previous connection until it is no longer valid.
+    pub fn quit(self: &mut AsyncClient) -> RedisEmptyResult {
+        self.connection = None;
+        self.last_used = None;
+
+        self.unsubscribe_all()
+    }
+
+    /// Invokes the requested command with the provided arguments (all provided via args) and returns the operation
+    /// response.<br>
+    /// In case the connection was lost while invoking an idempotent command (for example `GET`), the connection
+    /// is reopened based on the reconnect policy and the command is invoked again.
+    ///
+    /// # Arguments
+    ///
+    /// * `command` - The Redis command, for example: `GET`
+    /// * `args` - Vector of arguments for the given command
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # #[tokio::main]
+    /// # async fn main() {
+    /// # let mut client = simple_redis::create_async("redis://127.0.0.1:6379/").unwrap();
+    /// match client.run_command::<String>("ECHO", vec!["testing"]).await {
+    ///     Ok(value) => assert_eq!(value, "testing"),
+    ///     _ => panic!("test error"),
+    /// }
+    /// # }
+    /// ```
+    pub async fn run_command<T: redis::FromRedisValue>(
+        self: &mut AsyncClient,
+        command: &str,
+        args: Vec<&str>,
+    ) -> RedisResult<T> {
+        self.run_binary_command(command, args.iter().map(|arg| arg.as_bytes()).collect())
+            .await
+    }
+
+    /// Invokes the requested command with the provided binary arguments and returns the operation response.
+    ///
+    /// # Arguments
+    ///
+    /// * `command` - The Redis command, for example: `SET`
+    /// * `args` - Vector of binary arguments for the given command
+    pub async fn run_binary_command<T: redis::FromRedisValue>(
+        self: &mut AsyncClient,
+        command: &str,
+        args: Vec<&[u8]>,
+    ) -> RedisResult<T> {
+        let mut cmd = redis::cmd(command);
+        for arg in args {
+            cmd.arg(arg);
+        }
+
+        let result = self.run_command_once(&cmd).await;
+
+        match result {
+            Err(ref error) if error.is_connection_error() => {
+                self.connection = None;
+                self.last_used = None;
+
+                if is_idempotent_command(command)
+                    && self.options.reconnect_policy.retry_idempotent_commands
+                {
+                    self.run_command_once(&cmd).await
+                } else {
+                    result
+                }
+            }
+            _ => result,
+        }
+    }
+
+    async fn run_command_once<T: redis::FromRedisValue>(
+        self: &mut AsyncClient,
+        cmd: &redis::Cmd,
+    ) -> RedisResult<T> {
+        let mut redis_connection = self.get_redis_connection().await?;
+
+        let result: redis::RedisResult<T> = cmd.query_async(&mut redis_connection).await;
+
+        match result {
+            Err(error) => Err(from_redis_error::<T>(error)),
+            Ok(output) => Ok(output),
+        }
+    }
+
+    /// Returns true if the current connection can be used, verifying it (via PING) only if required
+    /// by the health check policy.
+    async fn is_connection_valid(
+        self: &mut AsyncClient,
+        redis_connection: &mut MultiplexedConnection,
+    ) -> bool {
+        if connection::should_verify(self.options.health_check, self.last_used) {
+            let result: redis::RedisResult<()> =
+                redis::cmd("PING").query_async(redis_connection).await;
+
+            result.is_ok()
+        } else {
+            true
+        }
+    }
+
+    /// Returns the current connection (if still valid based on the health check policy) or opens a new one based
+    /// on the reconnect policy.
+    async fn get_redis_connection(self: &mut AsyncClient) -> RedisResult<MultiplexedConnection> {
+        let mut current_connection = self.connection.clone();
+        if let Some(ref mut redis_connection) = current_connection {
+            if !self.is_connection_valid(redis_connection).await {
+                current_connection = None;
+            }
+        }
+
+        let redis_connection = match current_connection {
+            Some(redis_connection) => redis_connection,
+            None => {
+                let client = &self.client;
+                let options = &self.options;
+                let redis_connection = open_with_retries(options, || open(client, options)).await?;
+
+                self.connection = Some(redis_connection.clone());
+
+                redis_connection
+            }
+        };
+
+        self.last_used = Some(Instant::now());
+
+        Ok(redis_connection)
+    }
+
+    /// invokes the run_command and returns typed result.<br>
+    /// In case the value can not be parsed, a parse error is returned for the first argument (the key).
+    pub async fn run_command_from_string_response<T: FromStr>(
+        self: &mut AsyncClient,
+        command: &str,
+        args: Vec<&str>,
+    ) -> RedisResult<T> {
+        let key = args.first().map(|key| key.to_string()).unwrap_or_default();
+
+        self.run_key_command_from_string_response(command, args, &key, None)
+            .await
+    }
+
+    /// invokes the run_command and returns typed result.<br>
+    /// In case no value is returned or it can not be parsed, the error holds the provided key and field.
+    pub(crate) async fn run_key_command_from_string_response<T: FromStr>(
+        self: &mut AsyncClient,
+        command: &str,
+        args: Vec<&str>,
+        key: &str,
+        field: Option<&str>,
+    ) -> RedisResult<T> {
+        match self
+            .run_key_command_string_response(command, args, key, field)
+            .await
+        {
+            Ok(value) => match T::from_str(&value) {
+                Ok(typed_value) => Ok(typed_value),
+                _ => Err(create_parse_error::<T>(Some(key), field, Some(value))),
+            },
+            Err(error) => Err(error),
+        }
+    }
+
+    /// invokes the run_command but returns empty result
+    pub async fn run_command_empty_response(
+        self: &mut AsyncClient,
+        command: &str,
+        args: Vec<&str>,
+    ) -> RedisEmptyResult {
+        self.run_command(command, args).await
+    }
+
+    /// invokes the run_command but returns string result.<br>
+    /// In case no value is returned, a not found error is returned for the first argument (the key).
+    pub async fn run_command_string_response(
+        self: &mut AsyncClient,
+        command: &str,
+        args: Vec<&str>,
+    ) -> RedisStringResult {
+        let key = args.first().map(|key| key.to_string()).unwrap_or_default();
+
+        self.run_key_command_string_response(command, args, &key, None)
+            .await
+    }
+
+    /// invokes the run_command but returns string result.<br>
+    /// In case no value is returned, a not found error is returned for the provided key and field.
+    pub(crate) async fn run_key_command_string_response(
+        self: &mut AsyncClient,
+        command: &str,
+        args: Vec<&str>,
+        key: &str,
+        field: Option<&str>,
+    ) -> RedisStringResult {
+        match self.run_command::<Option<String>>(command, args).await {
+            Ok(Some(value)) => Ok(value),
+            Ok(None) => Err(create_not_found_error(key, field)),
+            Err(error) => Err(error),
+        }
+    }
+
+    /// invokes the run_command but returns bool result
+    pub async fn run_command_bool_response(
+        self: &mut AsyncClient,
+        command: &str,
+        args: Vec<&str>,
+    ) -> RedisBoolResult {
+        self.run_command(command, args).await
+    }
+
+    /// Subscribes to the provided channel.<br>
+    /// Actual subscription only occurs once the message stream is created.
+    pub fn subscribe(self: &mut AsyncClient, channel: &str) -> RedisEmptyResult {
+        self.subscriptions.push(channel.to_string());
+
+        Ok(())
+    }
+
+    /// Subscribes to the provided channel pattern.<br>
+    /// Actual subscription only occurs once the message stream is created.
+    pub fn psubscribe(self: &mut AsyncClient, channel: &str) -> RedisEmptyResult {
+        self.psubscriptions.push(channel.to_string());
+
+        Ok(())
+    }
+
+    /// Returns true if subscribed to the provided channel.
+    pub fn is_subscribed(self: &AsyncClient, channel: &str) -> bool {
+        self.subscriptions.iter().any(|value| value == channel)
+    }
+
+    /// Returns true if subscribed to the provided channel pattern.
+    pub fn is_psubscribed(self: &AsyncClient, channel: &str) -> bool {
+        self.psubscriptions.iter().any(|value| value == channel)
+    }
+
+    /// Unsubscribes from the provided channel.<br>
+    /// Message streams which were already created are not modified.
+    pub fn unsubscribe(self: &mut AsyncClient, channel: &str) -> RedisEmptyResult {
+        remove_channel(&mut self.subscriptions, channel);
+
+        Ok(())
+    }
+
+    /// Unsubscribes from the provided channel pattern.<br>
+    /// Message streams which were already created are not modified.
+    pub fn punsubscribe(self: &mut AsyncClient, channel: &str) -> RedisEmptyResult {
+        remove_channel(&mut self.psubscriptions, channel);
+
+        Ok(())
+    }
+
+    /// Unsubscribes from all channels.<br>
+    /// Message streams which were already created are not modified.
+    pub fn unsubscribe_all(self: &mut AsyncClient) -> RedisEmptyResult {
+        self.subscriptions.clear();
+        self.psubscriptions.clear();
+
+        Ok(())
+    }
+
+    /// Returns a stream of the messages from all subscribed channels, which is the async equivalent of
+    /// the client fetch_messages function.<br>
+    /// The stream uses a dedicated connection and is not affected by later subscription changes.<br>
+    /// This function will return an error in case no subscriptions are defined.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use futures_util::StreamExt;
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() {
+    /// # let mut client = simple_redis::create_async("redis://127.0.0.1:6379/").unwrap();
+    /// client.subscribe("important_notifications").unwrap();
+    ///
+    /// let mut messages = client.message_stream().unwrap();
+    /// while let Some(message) = messages.next().await {
+    ///     let payload: String = message.unwrap().get_payload().unwrap();
+    ///     println!("Got message: {}", payload);
+    /// }
+    /// # }
+    /// ```
+    pub fn message_stream(self: &AsyncClient) -> RedisResult<MessageStream> {
+        if self.subscriptions.is_empty() && self.psubscriptions.is_empty() {
+            return Err(RedisError::NoSubscriptions);
+        }
+
+        let state = SubscriptionState {
+            client: self.client.clone(),
+            options: self.options.clone(),
+            subscriptions: self.subscriptions.clone(),
+            psubscriptions: self.psubscriptions.clone(),
+            messages: None,
+            done: false,
+        };
+
+        Ok(Box::pin(stream::unfold(state, next_message)))
+    }
+}
+
+/// Returns a new async client for the provided redis client and options.
+pub(crate) fn create_from_redis_client(
+    client: redis::Client,
+    options: ConnectionOptions,
+) -> AsyncClient {
+    AsyncClient {
+        client,
+        connection: None,
+        last_used: None,
+        options,
+        subscriptions: vec![],
+        psubscriptions: vec![],
+    }
+}
+
+/// Constructs a new async redis client.<br>
+/// The redis connection string must be in the following format: `redis://[:<passwd>@]<hostname>[:port][/<db>]`
+///
+/// # Arguments
+///
+/// * `connection_string` - The connection string in the format of: `redis://[:<passwd>@]<hostname>[:port][/<db>]`
+pub fn create(connection_string: &str) -> Result<AsyncClient, RedisError> {
+    ClientBuilder::new(connection_string).build_async()
+}
@@ -0,0 +1,116 @@
+use super::*;
+use crate::types::ReconnectPolicy;
+
+#[test]
+fn create_invalid_url() {
+    let result = create("test/bad/url");
+    assert!(result.is_err());
+}
+
+#[test]
+fn create_valid_url() {
+    let client = create("redis://127.0.0.1:6379/").unwrap();
+    assert!(!client.is_connection_open());
+}
+
+#[test]
+fn create_sentinel_url() {
+    match create("redis+sentinel://127.0.0.1:26379/mymaster") {
+        Err(RedisError::InvalidInput(description)) => assert_eq!(
+            description,
+            "Sentinel connection strings are not supported by the async client."
+        ),
+        _ => panic!("Invalid result"),
+    }
+}
+
+#[test]
+fn subscriptions() {
+    let mut client = create("redis://127.0.0.1:6379/").unwrap();
+
+    client.subscribe("sub1").unwrap();
+    client.psubscribe("sub2*").unwrap();
+    assert!(client.is_subscribed("sub1"));
+    assert!(!client.is_psubscribed("sub1"));
+    assert!(client.is_psubscribed("sub2*"));
+
+    client.unsubscribe("sub1").unwrap();
+    assert!(!client.is_subscribed("sub1"));
+    assert!(client.is_psubscribed("sub2*"));
+
+    client.unsubscribe_all().unwrap();
+    assert!(!client.is_psubscribed("sub2*"));
+}
+
+#[test]
+fn message_stream_no_subscriptions() {
+    let client = create("redis://127.0.0.1:6379/").unwrap();
+
+    match client.message_stream() {
+        Err(RedisError::NoSubscriptions) => (),
+        _ => panic!("Invalid result"),
+    }
+}
+
+#[tokio::test]
+async fn run_command_not_connected() {
+    let mut client = create("redis://127.0.0.1:1/").unwrap();
+
+    let result = client.run_command::<String>("ECHO", vec!["testing"]).await;
+    match result {
+        Err(ref error) => assert!(error.is_connection_error()),
+        _ => panic!("Invalid result"),
+    }
+    assert!(!client.is_connection_open());
+}
+
+#[tokio::test]
+async fn open_with_retries_attempts() {
+    let options = ConnectionOptions {
+        reconnect_policy: ReconnectPolicy {
+            max_attempts: 3,
+            initial_delay: Duration::from_millis(1),
+            jitter: false,
+            ..ReconnectPolicy::new()
+        },
+        ..Default::default()
+    };
+
+    let mut attempts = 0;
+    let result: RedisResult<()> = open_with_retries(&options, || {
+        attempts += 1;
+        async { Err(RedisError::NotConnected(None)) }
+    })
+    .await;
+
+    assert!(result.is_err());
+    assert_eq!(attempts, 3);
+}
+
+#[test]
+fn get_response_timeout_none() {
+    let options = ConnectionOptions::default();
+
+    assert_eq!(get_response_timeout(&options), None);
+}
+
+#[test]
+fn get_response_timeout_read_only() {
+    let options = ConnectionOptions {
+        read_timeout: Some(Duration::from_secs(2)),
+        ..Default::default()
+    };
+
+    assert_eq!(get_response_timeout(&options), Some(Duration::from_secs(2)));
+}
+
+#[test]
+fn get_response_timeout_write_and_read() {
+    let options = ConnectionOptions {
+        read_timeout: Some(Duration::from_secs(2)),
+        write_timeout: Some(Duration::from_secs(1)),
+        ..Default::default()
+    };
+
+    assert_eq!(get_response_timeout(&options), Some(Duration::from_secs(3)));
+}
@@ -0,0 +1,684 @@
+//! # async_commands
+//!
+//! Defines the redis commands exposed by the async redis client.
+//!
+
+use crate::async_client::AsyncClient;
+use crate::commands::{create_limit_args, create_store_args, create_zadd_options_args};
+use crate::types::{
+    Aggregate, RedisArg, RedisBoolResult, RedisEmptyResult, RedisResult, RedisStringResult,
+    ZAddOptions,
+};
+use std::collections::HashMap;
+use std::str::FromStr;
+use std::time::Duration;
+
+/// Defines the redis commands exposed by the async redis client.
+impl AsyncClient {
+    /// See redis [AUTH](https://redis.io/commands/auth) command.
+    pub async fn auth(&mut self, password: &str) -> RedisEmptyResult {
+        self.run_command_empty_response("AUTH", vec![password])
+            .await
+    }
+
+    /// See redis [ECHO](https://redis.io/commands/echo) command.
+    pub async fn echo(&mut self, value: &str) -> RedisStringResult {
+        self.run_command::<String>("ECHO", vec![value]).await
+    }
+
+    /// See redis [PUBLISH](https://redis.io/commands/publish) command.
+    pub async fn publish(&mut self, channel: &str, message: &str) -> RedisEmptyResult {
+        self.run_command_empty_response("PUBLISH", vec![channel, message])
+            .await
+    }
+
+    /// See redis [GET](https://redis.io/commands/get) command.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # #[tokio::main]
+    /// # async fn main() {
+    /// # let mut client = simple_redis::create_async("redis://127.0.0.1:6379/").unwrap();
+    /// match client.get::<i64>("my_key").await {
+    ///     Ok(value) => println!("Read value from Redis: {}", value),
+    ///     Err(error) => println!("Unable to get value from Redis: {}", error)
+    /// }
+    /// # }
+    /// ```
+    ///
+    pub async fn get<T: FromStr>(self: &mut AsyncClient, key: &str) -> RedisResult<T> {
+        self.run_key_command_from_string_response("GET", vec![key], key, None)
+            .await
+    }
+
+    /// See redis [GET](https://redis.io/commands/get) command.<br>
+    /// This function will always return a String response.
+    pub async fn get_string(self: &mut AsyncClient, key: &str) -> RedisStringResult {
+        self.run_key_command_string_response("GET", vec![key], key, None)
+            .await
+    }
+
+    /// See redis [SET](https://redis.io/commands/set) command.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # #[tokio::main]
+    /// # async fn main() {
+    /// # let mut client = simple_redis::create_async("redis://127.0.0.1:6379/").unwrap();
+    /// match client.set("my_key", "my_value").await {
+    ///     Err(error) => println!("Unable to set value in Redis: {}", error),
+    ///     _ => println!("Value set in Redis")
+    /// }
+    /// # }
+    /// ```
+    ///
+    pub async fn set<T: RedisArg>(self: &mut AsyncClient, key: &str, value: T) -> RedisEmptyResult {
+        self.run_command_empty_response("SET", vec![key, &value.to_string()])
+            .await
+    }
+
+    /// See redis [SETEX](https://redis.io/commands/setex) command.
+    pub async fn setex<T: RedisArg>(
+        &mut self,
+        key: &str,
+        value: T,
+        seconds: usize,
+    ) -> RedisEmptyResult {
+        self.run_command_empty_response(
+            "SETEX",
+            vec![key, &*seconds.to_string(), &value.to_string()],
+        )
+        .await
+    }
+
+    /// See redis [SETNX](https://redis.io/commands/setnx) command.
+    pub async fn setnx<T: RedisArg>(&mut self, key: &str, value: T) -> RedisEmptyResult {
+        self.run_command_empty_response("SETNX", vec![key, &value.to_string()])
+            .await
+    }
+
+    /// See redis [GETSET](https://redis.io/commands/getset) command.
+    pub async fn getset<T: RedisArg, V: FromStr>(&mut self, key: &str, value: T) -> RedisResult<V> {
+        self.run_key_command_from_string_response::<V>(
+            "GETSET",
+            vec![key, &value.to_string()],
+            key,
+            None,
+        )
+        .await
+    }
+
+    /// See redis [GETSET](https://redis.io/commands/getset) command.
+    pub async fn getset_string<T: RedisArg>(&mut self, key: &str, value: T) -> RedisStringResult {
+        self.run_key_command_string_response("GETSET", vec![key, &value.to_string()], key, None)
+            .await
+    }
+
+    /// See redis [DEL](https://redis.io/commands/del) command.
+    pub async fn del(&mut self, key: &str) -> RedisEmptyResult {
+        self.run_command_empty_response("DEL", vec![key]).await
+    }
+
+    /// See redis [EXISTS](https://redis.io/commands/exists) command.
+    pub async fn exists(&mut self, key: &str) -> RedisBoolResult {
+        self.run_command_bool_response("EXISTS", vec![key]).await
+    }
+
+    /// See redis [EXPIRE](https://redis.io/commands/expire) command.
+    pub async fn expire(&mut self, key: &str, seconds: usize) -> RedisEmptyResult {
+        self.run_command_empty_response("EXPIRE", vec![key, &*seconds.to_string()])
+            .await
+    }
+
+    /// See redis [PEXPIRE](https://redis.io/commands/pexpire) command.
+    pub async fn pexpire(&mut self, key: &str, millies: usize) -> RedisEmptyResult {
+        self.run_command_empty_response("PEXPIRE", vec![key, &*millies.to_string()])
+            .await
+    }
+
+    /// See redis [PERSIST](https://redis.io/commands/persist) command.
+    pub async fn persist(&mut self, key: &str) -> RedisEmptyResult {
+        self.run_command_empty_response("PERSIST", vec![key]).await
+    }
+
+    /// See redis [RENAME](https://redis.io/commands/rename) command.
+    pub async fn rename(&mut self, key: &str, new_key: &str) -> RedisEmptyResult {
+        self.run_command_empty_response("RENAME", vec![key, new_key])
+            .await
+    }
+
+    /// See redis [RENAMENX](https://redis.io/commands/renamenx) command.
+    pub async fn renamenx(&mut self, key: &str, new_key: &str) -> RedisEmptyResult {
+        self.run_command_empty_response("RENAMENX", vec![key, new_key])
+            .await
+    }
+
+    /// See redis [APPEND](https://redis.io/commands/append) command.
+    pub async fn append(&mut self, key: &str, value: &str) -> RedisEmptyResult {
+        self.run_command_empty_response("APPEND", vec![key, value])
+            .await
+    }
+
+    /// See redis [INCR](https://redis.io/commands/incr) command.
+    pub async fn incr(&mut self, key: &str) -> RedisResult<i64> {
+        self.run_command::<i64>("INCR", vec![key]).await
+    }
+
+    /// See redis [INCRBY](https://redis.io/commands/incrby) command.
+    pub async fn incrby<T: RedisArg>(&mut self, key: &str, value: T) -> RedisResult<i64> {
+        self.run_command::<i64>("INCRBY", vec![key, &*value.to_string()])
+            .await
+    }
+
+    /// See redis [INCRBYFLOAT](https://redis.io/commands/incrbyfloat) command.
+    pub async fn incrbyfloat<T: RedisArg>(&mut self, key: &str, value: T) -> RedisResult<f64> {
+        self.run_command::<f64>("INCRBYFLOAT", vec![key, &*value.to_string()])
+            .await
+    }
+
+    /// See redis [STRLEN](https://redis.io/commands/strlen) command.
+    pub async fn strlen(&mut self, key: &str) -> RedisResult<i32> {
+        self.run_command::<i32>("STRLEN", vec![key]).await
+    }
+
+    /// See redis [KEYS](https://redis.io/commands/keys) command.
+    pub async fn keys(&mut self, pattern: &str) -> RedisResult<Vec<String>> {
+        self.run_command::<Vec<String>>("KEYS", vec![pattern]).await
+    }
+
+    /// See redis [HGET](https://redis.io/commands/hget) command.
+    pub async fn hget<T: FromStr>(
+        self: &mut AsyncClient,
+        key: &str,
+        field: &str,
+    ) -> RedisResult<T> {
+        self.run_key_command_from_string_response("HGET", vec![key, field], key, Some(field))
+            .await
+    }
+
+    /// See redis [HGET](https://redis.io/commands/hget) command.
+    pub async fn hget_string(self: &mut AsyncClient, key: &str, field: &str) -> RedisStringResult {
+        self.run_key_command_string_response("HGET", vec![key, field], key, Some(field))
+            .await
+    }
+
+    /// See redis [HGETALL](https://redis.io/commands/hgetall) command.
+    pub async fn hgetall(
+        self: &mut AsyncClient,
+        key: &str,
+    ) -> RedisResult<HashMap<String, String>> {
+        self.run_command::<HashMap<String, String>>("HGETALL", vec![key])
+            .await
+    }
+
+    /// See redis [HSET](https://redis.io/commands/hset) command.
+    pub async fn hset<T: RedisArg>(
+        self: &mut AsyncClient,
+        key: &str,
+        field: &str,
+        value: T,
+    ) -> RedisEmptyResult {
+        self.run_command_empty_response("HSET", vec![key, field, &value.to_string()])
+            .await
+    }
+
+    /// See redis [HSETNX](https://redis.io/commands/hsetnx) command.
+    pub async fn hsetnx<T: RedisArg>(
+        self: &mut AsyncClient,
+        key: &str,
+        field: &str,
+        value: T,
+    ) -> RedisEmptyResult {
+        self.run_command_empty_response("HSETNX", vec![key, field, &value.to_string()])
+            .await
+    }
+
+    /// See redis [HDEL](https://redis.io/commands/hdel) command.
+    pub async fn hdel(self: &mut AsyncClient, key: &str, field: &str) -> RedisEmptyResult {
+        self.run_command_empty_response("HDEL", vec![key, field])
+            .await
+    }
+
+    /// See redis [HEXISTS](https://redis.io/commands/hexists) command.
+    pub async fn hexists(self: &mut AsyncClient, key: &str, field: &str) -> RedisBoolResult {
+        self.run_command_bool_response("HEXISTS", vec![key, field])
+            .await
+    }
+
+    /// See redis [HKEYS](https://redis.io/commands/hkeys) command.
+    pub async fn hkeys(&mut self, key: &str) -> RedisResult<Vec<String>> {
+        self.run_command::<Vec<String>>("HKEYS", vec![key]).await
+    }
+
+    /// See redis [HVALS](https://redis.io/commands/hvals) command.
+    pub async fn hvals(&mut self, key: &str) -> RedisResult<Vec<String>> {
+        self.run_command::<Vec<String>>("HVALS", vec![key]).await
+    }
+
+    /// See redis [LSET](https://redis.io/commands/lset) command.
+    pub async fn lset<T: RedisArg>(
+        self: &mut AsyncClient,
+        key: &str,
+        index: isize,
+        value: T,
+    ) -> RedisEmptyResult {
+        self.run_command_empty_response("LSET", vec![key, &index.to_string(), &value.to_string()])
+            .await
+    }
+
+    /// See redis [LINDEX](https://redis.io/commands/lindex) command.
+    pub async fn lindex<T: FromStr>(
+        self: &mut AsyncClient,
+        key: &str,
+        index: isize,
+    ) -> RedisResult<T> {
+        self.run_key_command_from_string_response(
+            "LINDEX",
+            vec![key, &index.to_string()],
+            key,
+            None,
+        )
+        .await
+    }
+
+    /// See redis [LINDEX](https://redis.io/commands/lindex) command.
+    pub async fn lindex_string(
+        self: &mut AsyncClient,
+        key: &str,
+        index: isize,
+    ) -> RedisStringResult {
+        self.run_key_command_string_response("LINDEX", vec![key, &index.to_string()], key, None)
+            .await
+    }
+
+    /// See redis [LLEN](https://redis.io/commands/llen) command.
+    pub async fn llen(self: &mut AsyncClient, key: &str) -> RedisResult<i32> {
+        self.run_command::<i32>("LLEN", vec![key]).await
+    }
+
+    /// See redis [LPOP](https://redis.io/commands/lpop) command.
+    pub async fn lpop<T: FromStr>(self: &mut AsyncClient, key: &str) -> RedisResult<T> {
+        self.run_key_command_from_string_response("LPOP", vec![key], key, None)
+            .await
+    }
+
+    /// See redis [LPUSH](https://redis.io/commands/lpush) command.
+    pub async fn lpush<T: RedisArg>(
+        self: &mut AsyncClient,
+        key: &str,
+        value: T,
+    ) -> RedisEmptyResult {
+        self.run_command_empty_response("LPUSH", vec![key, &value.to_string()])
+            .await
+    }
+
+    /// See redis [LPUSHX](https://redis.io/commands/lpushx) command.
+    pub async fn lpushx<T: RedisArg>(
+        self: &mut AsyncClient,
+        key: &str,
+        value: T,
+    ) -> RedisEmptyResult {
+        self.run_command_empty_response("LPUSHX", vec![key, &value.to_string()])
+            .await
+    }
+
+    /// See redis [LRANGE](https://redis.io/commands/lrange) command.
+    pub async fn lrange(
+        self: &mut AsyncClient,
+        key: &str,
+        start: isize,
+        stop: isize,
+    ) -> RedisResult<Vec<String>> {
+        self.run_command::<Vec<String>>("LRANGE", vec![key, &start.to_string(), &stop.to_string()])
+            .await
+    }
+
+    /// See redis [LREM](https://redis.io/commands/lrem) command.
+    pub async fn lrem<T: RedisArg>(
+        self: &mut AsyncClient,
+        key: &str,
+        count: isize,
+        value: T,
+    ) -> RedisEmptyResult {
+        self.run_command_empty_response("LREM", vec![key, &count.to_string(), &value.to_string()])
+            .await
+    }
+
+    /// See redis [LTRIM](https://redis.io/commands/ltrim) command.
+    pub async fn ltrim(
+        self: &mut AsyncClient,
+        key: &str,
+        start: isize,
+        stop: isize,
+    ) -> RedisEmptyResult {
+        self.run_command_empty_response("LTRIM", vec![key, &start.to_string(), &stop.to_string()])
+            .await
+    }
+
+    /// See redis [RPOP](https://redis.io/commands/rpop) command.
+    pub async fn rpop<T: FromStr>(self: &mut AsyncClient, key: &str) -> RedisResult<T> {
+        self.run_key_command_from_string_response("RPOP", vec![key], key, None)
+            .await
+    }
+
+    /// See redis [RPUSH](https://redis.io/commands/rpush) command.
+    pub async fn rpush<T: RedisArg>(
+        self: &mut AsyncClient,
+        key: &str,
+        value: T,
+    ) -> RedisEmptyResult {
+        self.run_command_empty_response("RPUSH", vec![key, &value.to_string()])
+            .await
+    }
+
+    /// See redis [RPUSHX](https://redis.io/commands/rpushx) command.
+    pub async fn rpushx<T: RedisArg>(
+        self: &mut AsyncClient,
+        key: &str,
+        value: T,
+    ) -> RedisEmptyResult {
+        self.run_command_empty_response("RPUSHX", vec![key, &value.to_string()])
+            .await
+    }
+
+    /// See redis [SADD](https://redis.io/commands/sadd) command.
+    pub async fn sadd(self: &mut AsyncClient, key: &str, member: &str) -> RedisResult<i32> {
+        self.run_command::<i32>("SADD", vec![key, member]).await
+    }
+
+    /// See redis [SCARD](https://redis.io/commands/scard) command.
+    pub async fn scard(self: &mut AsyncClient, key: &str) -> RedisResult<i32> {
+        self.run_command::<i32>("SCARD", vec![key]).await
+    }
+
+    /// See redis [SDIFF](https://redis.io/commands/sdiff) command.
+    pub async fn sdiff(self: &mut AsyncClient, keys: Vec<&str>) -> RedisResult<Vec<String>> {
+        self.run_command::<Vec<String>>("SDIFF", keys).await
+    }
+
+    /// See redis [SISMEMBER](https://redis.io/commands/sismember) command.
+    pub async fn sismember(self: &mut AsyncClient, key: &str, member: &str) -> RedisBoolResult {
+        self.run_command("SISMEMBER", vec![key, member]).await
+    }
+
+    /// See redis [SMEMBERS](https://redis.io/commands/smembers) command.
+    pub async fn smembers(self: &mut AsyncClient, key: &str) -> RedisResult<Vec<String>> {
+        self.run_command::<Vec<String>>("SMEMBERS", vec![key]).await
+    }
+
+    /// See redis [SMOVE](https://redis.io/commands/smove) command.
+    pub async fn smove(
+        self: &mut AsyncClient,
+        source_key: &str,
+        destination_key: &str,
+        member: &str,
+    ) -> RedisEmptyResult {
+        self.run_command("SMOVE", vec![source_key, destination_key, member])
+            .await
+    }
+
+    /// See redis [SREM](https://redis.io/commands/srem) command.
+    pub async fn srem(self: &mut AsyncClient, key: &str, member: &str) -> RedisEmptyResult {
+        self.run_command("SREM", vec![key, member]).await
+    }
+
+    /// See redis [ZADD](https://redis.io/commands/zadd) command.
+    pub async fn zadd<S: RedisArg>(
+        self: &mut AsyncClient,
+        key: &str,
+        score: S,
+        member: &str,
+    ) -> RedisResult<i32> {
+        self.run_command("ZADD", vec![key, &score.to_string(), member])
+            .await
+    }
+
+    /// See redis [ZADD](https://redis.io/commands/zadd) command.<br>
+    /// Adds/updates all provided (score, member) pairs based on the provided options.
+    pub async fn zadd_with_options<S: RedisArg>(
+        self: &mut AsyncClient,
+        key: &str,
+        options: ZAddOptions,
+        members: Vec<(S, &str)>,
+    ) -> RedisResult<i32> {
+        let scores: Vec<String> = members.iter().map(|(score, _)| score.to_string()).collect();
+
+        let mut args = vec![key];
+        args.extend(create_zadd_options_args(&options));
+        for (index, (_, member)) in members.iter().enumerate() {
+            args.push(&scores[index]);
+            args.push(member);
+        }
+
+        self.run_command("ZADD", args).await
+    }
+
+    /// See redis [ZADD](https://redis.io/commands/zadd) command (with the INCR option).<br>
+    /// Returns the new member score or None in case the member was not updated due to the provided options.
+    pub async fn zadd_incr<S: RedisArg>(
+        self: &mut AsyncClient,
+        key: &str,
+        options: ZAddOptions,
+        increment: S,
+        member: &str,
+    ) -> RedisResult<Option<f64>> {
+        let increment_string = increment.to_string();
+
+        let mut args = vec![key];
+        args.extend(create_zadd_options_args(&options));
+        args.push("INCR");
+        args.push(&increment_string);
+        args.push(member);
+
+        self.run_command("ZADD", args).await
+    }
+
+    /// See redis [ZCARD](https://redis.io/commands/zcard) command.
+    pub async fn zcard(self: &mut AsyncClient, key: &str) -> RedisResult<i32> {
+        self.run_command("ZCARD", vec![key]).await
+    }
+
+    /// See redis [ZCOUNT](https://redis.io/commands/zcount) command.
+    pub async fn zcount<M: RedisArg, N: RedisArg>(
+        self: &mut AsyncClient,
+        key: &str,
+        min: M,
+        max: N,
+    ) -> RedisResult<i32> {
+        self.run_command("ZCOUNT", vec![key, &min.to_string(), &max.to_string()])
+            .await
+    }
+
+    /// See redis [ZINCRBY](https://redis.io/commands/zincrby) command.
+    pub async fn zincrby<S: RedisArg>(
+        self: &mut AsyncClient,
+        key: &str,
+        increment: S,
+        member: &str,
+    ) -> RedisResult<f64> {
+        self.run_command("ZINCRBY", vec![key, &increment.to_string(), member])
+            .await
+    }
+
+    /// See redis [ZSCORE](https://redis.io/commands/zscore) command.
+    pub async fn zscore(
+        self: &mut AsyncClient,
+        key: &str,
+        member: &str,
+    ) -> RedisResult<Option<f64>> {
+        self.run_command("ZSCORE", vec![key, member]).await
+    }
+
+    /// See redis [ZRANK](https://redis.io/commands/zrank) command.
+    pub async fn zrank(
+        self: &mut AsyncClient,
+        key: &str,
+        member: &str,
+    ) -> RedisResult<Option<i64>> {
+        self.run_command("ZRANK", vec![key, member]).await
+    }
+
+    /// See redis [ZREVRANK](https://redis.io/commands/zrevrank) command.
+    pub async fn zrevrank(
+        self: &mut AsyncClient,
+        key: &str,
+        member: &str,
+    ) -> RedisResult<Option<i64>> {
+        self.run_command("ZREVRANK", vec![key, member]).await
+    }
+
+    /// See redis [ZRANGE](https://redis.io/commands/zrange) command.
+    pub async fn zrange(
+        self: &mut AsyncClient,
+        key: &str,
+        start: isize,
+        stop: isize,
+    ) -> RedisResult<Vec<String>> {
+        self.run_command::<Vec<String>>("ZRANGE", vec![key, &start.to_string(), &stop.to_string()])
+            .await
+    }
+
+    /// See redis [ZRANGE](https://redis.io/commands/zrange) command (with the WITHSCORES option).<br>
+    /// Returns the (member, score) pairs.
+    pub async fn zrange_withscores(
+        self: &mut AsyncClient,
+        key: &str,
+        start: isize,
+        stop: isize,
+    ) -> RedisResult<Vec<(String, f64)>> {
+        self.run_command(
+            "ZRANGE",
+            vec![key, &start.to_string(), &stop.to_string(), "WITHSCORES"],
+        )
+        .await
+    }
+
+    /// See redis [ZRANGEBYSCORE](https://redis.io/commands/zrangebyscore) command.
+    pub async fn zrangebyscore<M: RedisArg, N: RedisArg>(
+        self: &mut AsyncClient,
+        key: &str,
+        min: M,
+        max: N,
+        limit: Option<(isize, isize)>,
+    ) -> RedisResult<Vec<String>> {
+        let min_string = min.to_string();
+        let max_string = max.to_string();
+        let limit_args = create_limit_args(limit);
+
+        let mut args = vec![key, &min_string, &max_string];
+        args.extend(limit_args.iter().map(|arg| arg.as_str()));
+
+        self.run_command("ZRANGEBYSCORE", args).await
+    }
+
+    /// See redis [ZRANGEBYLEX](https://redis.io/commands/zrangebylex) command.
+    pub async fn zrangebylex(
+        self: &mut AsyncClient,
+        key: &str,
+        min: &str,
+        max: &str,
+        limit: Option<(isize, isize)>,
+    ) -> RedisResult<Vec<String>> {
+        let limit_args = create_limit_args(limit);
+
+        let mut args = vec![key, min, max];
+        args.extend(limit_args.iter().map(|arg| arg.as_str()));
+
+        self.run_command("ZRANGEBYLEX", args).await
+    }
+
+    /// See redis [ZREM](https://redis.io/commands/zrem) command.
+    pub async fn zrem(self: &mut AsyncClient, key: &str, member: &str) -> RedisResult<i32> {
+        self.run_command("ZREM", vec![key, member]).await
+    }
+
+    /// See redis [ZREMRANGEBYSCORE](https://redis.io/commands/zremrangebyscore) command.
+    pub async fn zremrangebyscore<M: RedisArg, N: RedisArg>(
+        self: &mut AsyncClient,
+        key: &str,
+        min: M,
+        max: N,
+    ) -> RedisResult<i32> {
+        self.run_command(
+            "ZREMRANGEBYSCORE",
+            vec![key, &min.to_string(), &max.to_string()],
+        )
+        .await
+    }
+
+    /// See redis [ZPOPMIN](https://redis.io/commands/zpopmin) command.<br>
+    /// Returns the removed (member, score) pairs.
+    pub async fn zpopmin(
+        self: &mut AsyncClient,
+        key: &str,
+        count: usize,
+    ) -> RedisResult<Vec<(String, f64)>> {
+        self.run_command("ZPOPMIN", vec![key, &count.to_string()])
+            .await
+    }
+
+    /// See redis [ZPOPMAX](https://redis.io/commands/zpopmax) command.<br>
+    /// Returns the removed (member, score) pairs.
+    pub async fn zpopmax(
+        self: &mut AsyncClient,
+        key: &str,
+        count: usize,
+    ) -> RedisResult<Vec<(String, f64)>> {
+        self.run_command("ZPOPMAX", vec![key, &count.to_string()])
+            .await
+    }
+
+    /// See redis [BZPOPMIN](https://redis.io/commands/bzpopmin) command.<br>
+    /// Returns the (key, member, score) of the removed member or None in case the timeout was reached.<br>
+    /// The timeout should be lower than the client read timeout (if defined).
+    pub async fn bzpopmin(
+        self: &mut AsyncClient,
+        keys: Vec<&str>,
+        timeout: Duration,
+    ) -> RedisResult<Option<(String, String, f64)>> {
+        let timeout_string = timeout.as_secs_f64().to_string();
+
+        let mut args = keys;
+        args.push(&timeout_string);
+
+        self.run_command("BZPOPMIN", args).await
+    }
+
+    /// See redis [ZUNIONSTORE](https://redis.io/commands/zunionstore) command.<br>
+    /// Returns the amount of members in the destination sorted set.
+    pub async fn zunionstore(
+        self: &mut AsyncClient,
+        destination: &str,
+        keys: Vec<&str>,
+        weights: Option<Vec<f64>>,
+        aggregate: Option<Aggregate>,
+    ) -> RedisResult<i32> {
+        let store_args = create_store_args(destination, keys, weights, aggregate);
+
+        self.run_command(
+            "ZUNIONSTORE",
+            store_args.iter().map(|arg| arg.as_str()).collect(),
+        )
+        .await
+    }
+
+    /// See redis [ZINTERSTORE](https://redis.io/commands/zinterstore) command.<br>
+    /// Returns the amount of members in the destination sorted set.
+    pub async fn zinterstore(
+        self: &mut AsyncClient,
+        destination: &str,
+        keys: Vec<&str>,
+        weights: Option<Vec<f64>>,
+        aggregate: Option<Aggregate>,
+    ) -> RedisResult<i32> {
+        let store_args = create_store_args(destination, keys, weights, aggregate);
+
+        self.run_command(
+            "ZINTERSTORE",
+            store_args.iter().map(|arg| arg.as_str()).collect(),
+        )
+        .await
+    }
+}
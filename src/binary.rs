@@ -0,0 +1,207 @@
+//! # binary
+//!
+//! Defines the binary safe variants of the redis commands exposed by the redis client.
+//!
+
+#[cfg(test)]
+#[path = "./binary_test.rs"]
+mod binary_test;
+
+use crate::client::Client;
+use crate::types::{RedisBoolResult, RedisEmptyResult, RedisResult};
+use std::collections::HashMap;
+
+/// Defines the binary safe commands exposed by the redis client.<br>
+/// Values are passed and returned as is, without any text conversion.
+impl Client {
+    /// See redis [SET](https://redis.io/commands/set) command.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # let mut client = simple_redis::create("redis://127.0.0.1:6379/").unwrap();
+    /// match client.set_binary("my_key", &[0, 159, 146, 150]) {
+    ///     Err(error) => println!("Unable to set value in Redis: {}", error),
+    ///     _ => println!("Value set in Redis")
+    /// }
+    /// ```
+    pub fn set_binary(self: &mut Client, key: &str, value: &[u8]) -> RedisEmptyResult {
+        self.run_binary_command("SET", vec![key.as_bytes(), value])
+    }
+
+    /// See redis [SETEX](https://redis.io/commands/setex) command.
+    pub fn setex_binary(
+        self: &mut Client,
+        key: &str,
+        value: &[u8],
+        seconds: usize,
+    ) -> RedisEmptyResult {
+        let seconds_string = seconds.to_string();
+
+        self.run_binary_command(
+            "SETEX",
+            vec![key.as_bytes(), seconds_string.as_bytes(), value],
+        )
+    }
+
+    /// See redis [SETNX](https://redis.io/commands/setnx) command.
+    pub fn setnx_binary(self: &mut Client, key: &str, value: &[u8]) -> RedisEmptyResult {
+        self.run_binary_command("SETNX", vec![key.as_bytes(), value])
+    }
+
+    /// See redis [GET](https://redis.io/commands/get) command.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # let mut client = simple_redis::create("redis://127.0.0.1:6379/").unwrap();
+    /// # client.set_binary("my_key", &[0, 159, 146, 150]).unwrap();
+    /// match client.get_binary("my_key") {
+    ///     Ok(value) => println!("Read {} bytes from Redis", value.len()),
+    ///     Err(error) => println!("Unable to get value from Redis: {}", error)
+    /// }
+    /// ```
+    pub fn get_binary(self: &mut Client, key: &str) -> RedisResult<Vec<u8>> {
+        self.run_key_command_binary_response("GET", vec![key.as_bytes()], key, None)
+    }
+
+    /// See redis [GETSET](https://redis.io/commands/getset) command.
+    pub fn getset_binary(self: &mut Client, key: &str, value: &[u8]) -> RedisResult<Vec<u8>> {
+        self.run_key_command_binary_response("GETSET", vec![key.as_bytes(), value], key, None)
+    }
+
+    /// See redis [APPEND](https://redis.io/commands/append) command.
+    pub fn append_binary(self: &mut Client, key: &str, value: &[u8]) -> RedisEmptyResult {
+        self.run_binary_command("APPEND", vec![key.as_bytes(), value])
+    }
+
+    /// See redis [HGET](https://redis.io/commands/hget) command.
+    pub fn hget_binary(self: &mut Client, key: &str, field: &str) -> RedisResult<Vec<u8>> {
+        self.run_key_command_binary_response(
+            "HGET",
+            vec![key.as_bytes(), field.as_bytes()],
+            key,
+            Some(field),
+        )
+    }
+
+    /// See redis [HGETALL](https://redis.io/commands/hgetall) command.
+    pub fn hgetall_binary(self: &mut Client, key: &str) -> RedisResult<HashMap<String, Vec<u8>>> {
+        self.run_binary_command("HGETALL", vec![key.as_bytes()])
+    }
+
+    /// See redis [HSET](https://redis.io/commands/hset) command.
+    pub fn hset_binary(
+        self: &mut Client,
+        key: &str,
+        field: &str,
+        value: &[u8],
+    ) -> RedisEmptyResult {
+        self.run_binary_command("HSET", vec![key.as_bytes(), field.as_bytes(), value])
+    }
+
+    /// See redis [HSETNX](https://redis.io/commands/hsetnx) command.
+    pub fn hsetnx_binary(
+        self: &mut Client,
+        key: &str,
+        field: &str,
+        value: &[u8],
+    ) -> RedisEmptyResult {
+        self.run_binary_command("HSETNX", vec![key.as_bytes(), field.as_bytes(), value])
+    }
+
+    /// See redis [HVALS](https://redis.io/commands/hvals) command.
+    pub fn hvals_binary(self: &mut Client, key: &str) -> RedisResult<Vec<Vec<u8>>> {
+        self.run_binary_command("HVALS", vec![key.as_bytes()])
+    }
+
+    /// See redis [LINDEX](https://redis.io/commands/lindex) command.
+    pub fn lindex_binary(self: &mut Client, key: &str, index: isize) -> RedisResult<Vec<u8>> {
+        let index_string = index.to_string();
+
+        self.run_key_command_binary_response(
+            "LINDEX",
+            vec![key.as_bytes(), index_string.as_bytes()],
+            key,
+            None,
+        )
+    }
+
+    /// See redis [LPOP](https://redis.io/commands/lpop) command.
+    pub fn lpop_binary(self: &mut Client, key: &str) -> RedisResult<Vec<u8>> {
+        self.run_key_command_binary_response("LPOP", vec![key.as_bytes()], key, None)
+    }
+
+    /// See redis [LPUSH](https://redis.io/commands/lpush) command.
+    pub fn lpush_binary(self: &mut Client, key: &str, value: &[u8]) -> RedisEmptyResult {
+        self.run_binary_command("LPUSH", vec![key.as_bytes(), value])
+    }
+
+    /// See redis [LRANGE](https://redis.io/commands/lrange) command.
+    pub fn lrange_binary(
+        self: &mut Client,
+        key: &str,
+        start: isize,
+        stop: isize,
+    ) -> RedisResult<Vec<Vec<u8>>> {
+        let start_string = start.to_string();
+        let stop_string = stop.to_string();
+
+        self.run_binary_command(
+            "LRANGE",
+            vec![
+                key.as_bytes(),
+                start_string.as_bytes(),
+                stop_string.as_bytes(),
+            ],
+        )
+    }
+
+    /// See redis [LSET](https://redis.io/commands/lset) command.
+    pub fn lset_binary(
+        self: &mut Client,
+        key: &str,
+        index: isize,
+        value: &[u8],
+    ) -> RedisEmptyResult {
+        let index_string = index.to_string();
+
+        self.run_binary_command("LSET", vec![key.as_bytes(), index_string.as_bytes(), value])
+    }
+
+    /// See redis [RPOP](https://redis.io/commands/rpop) command.
+    pub fn rpop_binary(self: &mut Client, key: &str) -> RedisResult<Vec<u8>> {
+        self.run_key_command_binary_response("RPOP", vec![key.as_bytes()], key, None)
+    }
+
+    /// See redis [RPUSH](https://redis.io/commands/rpush) command.
+    pub fn rpush_binary(self: &mut Client, key: &str, value: &[u8]) -> RedisEmptyResult {
+        self.run_binary_command("RPUSH", vec![key.as_bytes(), value])
+    }
+
+    /// See redis [SADD](https://redis.io/commands/sadd) command.
+    pub fn sadd_binary(self: &mut Client, key: &str, member: &[u8]) -> RedisResult<i32> {
+        self.run_binary_command("SADD", vec![key.as_bytes(), member])
+    }
+
+    /// See redis [SISMEMBER](https://redis.io/commands/sismember) command.
+    pub fn sismember_binary(self: &mut Client, key: &str, member: &[u8]) -> RedisBoolResult {
+        self.run_binary_command("SISMEMBER", vec![key.as_bytes(), member])
+    }
+
+    /// See redis [SMEMBERS](https://redis.io/commands/smembers) command.
+    pub fn smembers_binary(self: &mut Client, key: &str) -> RedisResult<Vec<Vec<u8>>> {
+        self.run_binary_command("SMEMBERS", vec![key.as_bytes()])
+    }
+
+    /// See redis [SREM](https://redis.io/commands/srem) command.
+    pub fn srem_binary(self: &mut Client, key: &str, member: &[u8]) -> RedisEmptyResult {
+        self.run_binary_command("SREM", vec![key.as_bytes(), member])
+    }
+
+    /// See redis [PUBLISH](https://redis.io/commands/publish) command.<br>
+    /// The binary payload can be read by subscribers via the message `get_payload_bytes` function.
+    pub fn publish_binary(self: &mut Client, channel: &str, message: &[u8]) -> RedisEmptyResult {
+        self.run_binary_command("PUBLISH", vec![channel.as_bytes(), message])
+    }
+}
@@ -0,0 +1,25 @@
+use crate::client;
+
+#[test]
+fn set_get_binary() {
+    let mut client = client::create("redis://127.0.0.1:6379/").unwrap();
+
+    let data = vec![0, 159, 146, 150, 255];
+    client.set_binary("set_get_binary", &data).unwrap();
+
+    let value = client.get_binary("set_get_binary").unwrap();
+    assert_eq!(value, data);
+}
+
+#[test]
+fn get_binary_not_found() {
+    let mut client = client::create("redis://127.0.0.1:6379/").unwrap();
+
+    client.del("get_binary_not_found").unwrap();
+
+    let result = client.get_binary("get_binary_not_found");
+    assert!(matches!(
+        result,
+        Err(crate::types::RedisError::NotFound { .. })
+    ));
+}
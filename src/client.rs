@@ -7,39 +7,212 @@
 #[path = "./client_test.rs"]
 mod client_test;
 
+#[cfg(feature = "async")]
+use crate::async_client::{self, AsyncClient};
 use crate::connection;
+use crate::replica;
+use crate::sentinel;
 use crate::subscriber;
 use crate::types::{
-    Interrupts, Message, RedisBoolResult, RedisEmptyResult, RedisError, RedisResult,
-    RedisStringResult,
+    create_not_found_error, create_parse_error, from_redis_error, HealthCheck, Interrupts, Message,
+    ReconnectPolicy, RedisBoolResult, RedisEmptyResult, RedisError, RedisResult, RedisStringResult,
+    ReplicaSelection, SubscriptionEvent, SubscriptionHandle,
 };
 use std::str::FromStr;
+use std::time::Duration;
 
 /// The redis client which enables to invoke redis operations.
 pub struct Client {
-    /// Internal redis client
-    client: redis::Client,
+    /// The redis server to which connections are opened
+    server: connection::Server,
     /// Holds the current redis connection
     connection: connection::Connection,
     /// Internal subscriber
     subscriber: subscriber::Subscriber,
+    /// The replica servers used for read only commands
+    replicas: replica::Replicas,
+    /// True while a transaction is being built, in which case all commands are invoked on the primary connection
+    /// holding the watched keys
+    pub(crate) watching: bool,
+    /// Options applied on every opened connection
+    options: connection::ConnectionOptions,
+}
+
+#[derive(Debug, Clone)]
+/// Enables to configure and construct a new redis client.
+///
+/// # Example
+///
+/// ```
+/// # use std::time::Duration;
+/// match simple_redis::ClientBuilder::new("redis://127.0.0.1:6379/")
+///     .connect_timeout(Duration::from_secs(5))
+///     .read_timeout(Duration::from_secs(10))
+///     .write_timeout(Duration::from_secs(10))
+///     .client_name("my_service")
+///     .db(1)
+///     .build()
+/// {
+///     Ok(client) => println!("Created Redis Client"),
+///     Err(error) => println!("Unable to create Redis client: {}", error)
+/// }
+/// ```
+pub struct ClientBuilder {
+    /// The redis connection string
+    connection_string: String,
+    /// Overrides the database index defined in the connection string
+    db: Option<i64>,
+    /// Overrides the username defined in the connection string
+    username: Option<String>,
+    /// Overrides the password defined in the connection string
+    password: Option<String>,
+    /// The connection strings of the replica servers used for read only commands
+    replicas: Vec<String>,
+    /// Defines which replica is used for the next read only command
+    replica_selection: ReplicaSelection,
+    /// The time to wait before using a failed replica again
+    replica_retry_interval: Duration,
+    /// The PEM encoded CA certificates used to verify the server certificate
+    #[cfg(any(feature = "tls-rustls", feature = "tls-native-tls"))]
+    tls_ca_certificates: Option<Vec<u8>>,
+    /// The PEM encoded client certificate and private key used for mutual TLS
+    #[cfg(any(feature = "tls-rustls", feature = "tls-native-tls"))]
+    tls_client_certificate: Option<(Vec<u8>, Vec<u8>)>,
+    /// Options applied on every opened connection
+    options: connection::ConnectionOptions,
+}
+
+/// The default time to wait before using a failed replica again
+const DEFAULT_REPLICA_RETRY_INTERVAL: Duration = Duration::from_secs(5);
+
+/// Commands which can be safely invoked again in case the connection was lost
+/// while they were running.
+const IDEMPOTENT_COMMANDS: &[&str] = &[
+    "DEL",
+    "ECHO",
+    "EXISTS",
+    "EXPIRE",
+    "FCALL_RO",
+    "GET",
+    "HDEL",
+    "HEXISTS",
+    "HGET",
+    "HGETALL",
+    "HKEYS",
+    "HLEN",
+    "HMGET",
+    "HSET",
+    "HVALS",
+    "KEYS",
+    "LINDEX",
+    "LLEN",
+    "LRANGE",
+    "LSET",
+    "LTRIM",
+    "MGET",
+    "MSET",
+    "PERSIST",
+    "PEXPIRE",
+    "PING",
+    "PTTL",
+    "SADD",
+    "SCARD",
+    "SDIFF",
+    "SET",
+    "SETEX",
+    "SISMEMBER",
+    "SMEMBERS",
+    "SREM",
+    "STRLEN",
+    "TTL",
+    "TYPE",
+    "XACK",
+    "XLEN",
+    "XPENDING",
+    "XRANGE",
+    "XREAD",
+    "XREVRANGE",
+    "ZCARD",
+    "ZCOUNT",
+    "ZRANGE",
+    "ZRANGEBYLEX",
+    "ZRANGEBYSCORE",
+    "ZRANK",
+    "ZREM",
+    "ZREVRANK",
+    "ZSCORE",
+];
+
+/// Commands which do not modify any data and can be invoked on a replica.
+const READ_ONLY_COMMANDS: &[&str] = &[
+    "EXISTS",
+    "FCALL_RO",
+    "GET",
+    "GETRANGE",
+    "HEXISTS",
+    "HGET",
+    "HGETALL",
+    "HKEYS",
+    "HLEN",
+    "HMGET",
+    "HVALS",
+    "KEYS",
+    "LINDEX",
+    "LLEN",
+    "LRANGE",
+    "MGET",
+    "PTTL",
+    "SCARD",
+    "SDIFF",
+    "SINTER",
+    "SISMEMBER",
+    "SMEMBERS",
+    "STRLEN",
+    "SUNION",
+    "TTL",
+    "TYPE",
+    "XLEN",
+    "XRANGE",
+    "XREVRANGE",
+    "ZCARD",
+    "ZCOUNT",
+    "ZRANGE",
+    "ZRANGEBYLEX",
+    "ZRANGEBYSCORE",
+    "ZRANK",
+    "ZREVRANK",
+    "ZSCORE",
+];
+
+/// Returns true if the provided command can be safely invoked more than once.
+pub(crate) fn is_idempotent_command(command: &str) -> bool {
+    let command = command.to_uppercase();
+
+    IDEMPOTENT_COMMANDS.contains(&command.as_str())
+}
+
+/// Returns true if the provided command does not modify any data.
+pub(crate) fn is_read_only_command(command: &str) -> bool {
+    let command = command.to_uppercase();
+
+    READ_ONLY_COMMANDS.contains(&command.as_str())
 }
 
 fn run_command_on_connection<T: redis::FromRedisValue>(
-    connection: &mut redis::Connection,
+    connection: &mut dyn redis::ConnectionLike,
     command: &str,
-    args: Vec<&str>,
+    args: &[&[u8]],
 ) -> RedisResult<T> {
     let mut cmd = redis::cmd(command);
 
     for arg in args {
-        cmd.arg(arg);
+        cmd.arg(*arg);
     }
 
     let result: redis::RedisResult<T> = cmd.query(connection);
 
     match result {
-        Err(error) => Err(RedisError::RedisError(error)),
+        Err(error) => Err(from_redis_error::<T>(error)),
         Ok(output) => Ok(output),
     }
 }
@@ -52,6 +225,46 @@ impl Client {
         self.connection.is_connection_open()
     }
 
+    /// Sets the policy used to reopen the connection in case it was lost.
+    ///
+    /// # Arguments
+    ///
+    /// * `policy` - The reconnect policy
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use std::time::Duration;
+    /// # let mut client = simple_redis::create("redis://127.0.0.1:6379/").unwrap();
+    /// let mut policy = simple_redis::ReconnectPolicy::new();
+    /// policy.max_attempts = 10;
+    /// policy.max_elapsed_time = Some(Duration::from_secs(30));
+    ///
+    /// client.set_reconnect_policy(policy);
+    /// ```
+    pub fn set_reconnect_policy(self: &mut Client, policy: ReconnectPolicy) {
+        self.options.reconnect_policy = policy;
+    }
+
+    /// Sets when the connection is verified (via PING) before invoking a command.<br>
+    /// By default, the connection is verified before every command which doubles the round-trips to the
+    /// redis server.
+    ///
+    /// # Arguments
+    ///
+    /// * `health_check` - The health check policy
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use std::time::Duration;
+    /// # let mut client = simple_redis::create("redis://127.0.0.1:6379/").unwrap();
+    /// client.set_health_check(simple_redis::HealthCheck::Idle(Duration::from_secs(30)));
+    /// ```
+    pub fn set_health_check(self: &mut Client, health_check: HealthCheck) {
+        self.options.health_check = health_check;
+    }
+
     /// Closes the internal connection to redis.<br>
     /// The client can still be reused and any invocation of other operations after this call,
     /// will reopen the connection.<br>
@@ -74,6 +287,8 @@ impl Client {
             Ok(())
         };
 
+        self.replicas.close();
+
         if result.is_ok() {
             result = self.unsubscribe_all();
         }
@@ -85,6 +300,10 @@ impl Client {
     /// response.<br>
     /// This function ensures that we have a valid connection and it is used internally by all other exposed
     /// commands.<br>
+    /// In case the connection was lost while invoking an idempotent command (for example `GET`), the connection
+    /// is reopened based on the reconnect policy and the command is invoked again.<br>
+    /// In case replicas are defined, read only commands (for example `GET`) are invoked on an available replica
+    /// and only if no replica is available, they are invoked on the primary server.<br>
     /// This function is also public to enable invoking operations that are not directly exposed by the client.
     ///
     /// # Arguments
@@ -106,22 +325,142 @@ impl Client {
         command: &str,
         args: Vec<&str>,
     ) -> RedisResult<T> {
-        match self.connection.get_redis_connection(&self.client) {
-            Ok(connection) => run_command_on_connection::<T>(connection, command, args),
+        self.run_binary_command(command, args.iter().map(|arg| arg.as_bytes()).collect())
+    }
+
+    /// Invokes the requested command with the provided binary arguments and returns the operation response.<br>
+    /// Unlike run_command, the arguments are not limited to text, enabling to pass any binary data as is.
+    ///
+    /// # Arguments
+    ///
+    /// * `command` - The Redis command, for example: `SET`
+    /// * `args` - Vector of binary arguments for the given command
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # let mut client = simple_redis::create("redis://127.0.0.1:6379/").unwrap();
+    /// let data: Vec<u8> = vec![0, 159, 146, 150];
+    /// client.run_binary_command::<()>("SET", vec![b"my_binary_key", data.as_slice()]).unwrap();
+    ///
+    /// match client.run_binary_command::<Vec<u8>>("GET", vec![b"my_binary_key"]) {
+    ///     Ok(value) => assert_eq!(value, data),
+    ///     _ => panic!("test error"),
+    /// }
+    /// ```
+    pub fn run_binary_command<T: redis::FromRedisValue>(
+        self: &mut Client,
+        command: &str,
+        args: Vec<&[u8]>,
+    ) -> RedisResult<T> {
+        let mut run = |connection: &mut dyn redis::ConnectionLike| {
+            run_command_on_connection::<T>(connection, command, &args)
+        };
+
+        // reads made while building a transaction must see the watched keys on the primary
+        if is_read_only_command(command) && !self.watching {
+            if let Some(result) = self.replicas.run(&mut run) {
+                return result;
+            }
+        }
+
+        self.run_on_connection(is_idempotent_command(command), run)
+    }
+
+    /// Invokes the provided function with a valid connection.<br>
+    /// In case the connection was lost during the invocation, the connection is closed and if the
+    /// operation is idempotent, it is invoked again on a new connection.<br>
+    /// In case a sentinel resolved server rejected the operation since it is no longer the primary, the primary
+    /// is resolved again and the operation is invoked again on a new connection.
+    pub(crate) fn run_on_connection<T, F>(
+        self: &mut Client,
+        idempotent: bool,
+        mut run: F,
+    ) -> RedisResult<T>
+    where
+        F: FnMut(&mut dyn redis::ConnectionLike) -> RedisResult<T>,
+    {
+        let result = self.run_on_connection_once(&mut run);
+
+        match result {
+            // the resolved server is no longer the primary (for example after a sentinel failover), the command was
+            // rejected so it can be invoked again once the primary is resolved again
+            Err(RedisError::ServerError { ref code, .. })
+                if code == "READONLY" && self.server.is_resolved() =>
+            {
+                self.connection.close();
+                self.run_on_connection_once(&mut run)
+            }
+            Err(ref error) if error.is_connection_error() => {
+                self.connection.close();
+
+                if idempotent && self.options.reconnect_policy.retry_idempotent_commands {
+                    self.run_on_connection_once(&mut run)
+                } else {
+                    result
+                }
+            }
+            _ => result,
+        }
+    }
+
+    /// Returns a valid connection and a number identifying it, which changes whenever a new connection
+    /// is opened.<br>
+    /// Unlike run_on_connection, nothing is retried in case the connection is lost while being used.
+    pub(crate) fn get_redis_connection(
+        self: &mut Client,
+    ) -> RedisResult<(&mut dyn redis::ConnectionLike, u64)> {
+        self.connection
+            .get_redis_connection_with_generation(&self.server, &self.options)
+    }
+
+    /// Drops the current connection in case the provided result holds a connection error.
+    pub(crate) fn close_on_connection_error<T>(self: &mut Client, result: &RedisResult<T>) {
+        if let Err(ref error) = result {
+            if error.is_connection_error() {
+                self.connection.close();
+            }
+        }
+    }
+
+    fn run_on_connection_once<T, F>(self: &mut Client, run: &mut F) -> RedisResult<T>
+    where
+        F: FnMut(&mut dyn redis::ConnectionLike) -> RedisResult<T>,
+    {
+        match self
+            .connection
+            .get_redis_connection(&self.server, &self.options)
+        {
+            Ok(connection) => run(connection),
             Err(error) => Err(error),
         }
     }
 
-    /// invokes the run_command and returns typed result
+    /// invokes the run_command and returns typed result.<br>
+    /// In case the value can not be parsed, a parse error is returned for the first argument (the key).
     pub fn run_command_from_string_response<T: FromStr>(
         self: &mut Client,
         command: &str,
         args: Vec<&str>,
     ) -> RedisResult<T> {
-        match self.run_command::<String>(command, args) {
+        let key = args.first().map(|key| key.to_string()).unwrap_or_default();
+
+        self.run_key_command_from_string_response(command, args, &key, None)
+    }
+
+    /// invokes the run_command and returns typed result.<br>
+    /// In case no value is returned or it can not be parsed, the error holds the provided key and field.
+    pub(crate) fn run_key_command_from_string_response<T: FromStr>(
+        self: &mut Client,
+        command: &str,
+        args: Vec<&str>,
+        key: &str,
+        field: Option<&str>,
+    ) -> RedisResult<T> {
+        match self.run_key_command_string_response(command, args, key, field) {
             Ok(value) => match T::from_str(&value) {
                 Ok(typed_value) => Ok(typed_value),
-                _ => Err(RedisError::Description("Unable to parse output value.")),
+                _ => Err(create_parse_error::<T>(Some(key), field, Some(value))),
             },
             Err(error) => Err(error),
         }
@@ -136,13 +475,63 @@ impl Client {
         self.run_command(command, args)
     }
 
-    /// invokes the run_command but returns string result
+    /// invokes the run_command but returns string result.<br>
+    /// In case no value is returned, a not found error is returned for the first argument (the key).
     pub fn run_command_string_response(
         self: &mut Client,
         command: &str,
         args: Vec<&str>,
     ) -> RedisStringResult {
-        self.run_command(command, args)
+        let key = args.first().map(|key| key.to_string()).unwrap_or_default();
+
+        self.run_key_command_string_response(command, args, &key, None)
+    }
+
+    /// invokes the run_command but returns string result.<br>
+    /// In case no value is returned, a not found error is returned for the provided key and field.
+    pub(crate) fn run_key_command_string_response(
+        self: &mut Client,
+        command: &str,
+        args: Vec<&str>,
+        key: &str,
+        field: Option<&str>,
+    ) -> RedisStringResult {
+        match self.run_command::<Option<String>>(command, args) {
+            Ok(Some(value)) => Ok(value),
+            Ok(None) => Err(create_not_found_error(key, field)),
+            Err(error) => Err(error),
+        }
+    }
+
+    /// invokes the run_binary_command but returns binary result.<br>
+    /// In case no value is returned, a not found error is returned for the first argument (the key).
+    pub fn run_command_binary_response(
+        self: &mut Client,
+        command: &str,
+        args: Vec<&[u8]>,
+    ) -> RedisResult<Vec<u8>> {
+        let key = args
+            .first()
+            .map(|key| String::from_utf8_lossy(key).to_string())
+            .unwrap_or_default();
+
+        self.run_key_command_binary_response(command, args, &key, None)
+    }
+
+    /// invokes the run_binary_command but returns binary result.<br>
+    /// In case no value is returned, a not found error is returned for the provided key and field.
+    pub(crate) fn run_key_command_binary_response(
+        self: &mut Client,
+        command: &str,
+        args: Vec<&[u8]>,
+        key: &str,
+        field: Option<&str>,
+    ) -> RedisResult<Vec<u8>> {
+        match self.run_binary_command::<Option<Vec<u8>>>(command, args) {
+            Ok(Some(value)) => Ok(value),
+            Ok(None) => Err(create_not_found_error(key, field)),
+            Err(error) => Err(error),
+        }
     }
 
     /// invokes the run_command but returns bool result
@@ -213,6 +602,56 @@ impl Client {
         self.subscriber.unsubscribe_all()
     }
 
+    /// Returns a handle which enables to subscribe and unsubscribe while messages are being fetched, without
+    /// reopening the subscriptions connection.<br>
+    /// The handle can be used from within the fetch_messages callbacks or from other threads.<br>
+    /// While messages are being fetched, requested changes are applied within about 100 milliseconds.<br>
+    /// Subscribers started via messages or spawn_subscriber have their own handle, which is returned by them.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// # use simple_redis::Interrupts;
+    /// # let mut client = simple_redis::create("redis://127.0.0.1:6379/").unwrap();
+    /// client.subscribe("control");
+    /// let handle = client.subscription_handle();
+    ///
+    /// client.fetch_messages(
+    ///     &mut |message: simple_redis::Message| -> bool {
+    ///         let payload : String = message.get_payload().unwrap();
+    ///
+    ///         if message.get_channel_name() == "control" {
+    ///             // start listening to the requested channel
+    ///             handle.subscribe(&payload);
+    ///         } else {
+    ///             println!("Got message: {}", payload);
+    ///         }
+    ///
+    ///         false
+    ///     },
+    ///     &mut || -> Interrupts { Interrupts::new() },
+    /// ).unwrap();
+    /// ```
+    pub fn subscription_handle(self: &Client) -> SubscriptionHandle {
+        self.subscriber.get_handle()
+    }
+
+    /// Returns the redis server, connection options and a copy of the subscriber, enabling to
+    /// fetch messages on another thread.
+    pub(crate) fn detach_subscriber(
+        self: &Client,
+    ) -> (
+        connection::Server,
+        connection::ConnectionOptions,
+        subscriber::Subscriber,
+    ) {
+        (
+            self.server.clone(),
+            self.options.clone(),
+            self.subscriber.detach(),
+        )
+    }
+
     /// Fetches the messages from any of the subscribed channels and invokes the provided
     /// on_message handler.<br>
     /// This function will return an error in case no subscriptions are defined.<br>
@@ -248,13 +687,72 @@ impl Client {
         on_message: &mut dyn FnMut(Message) -> bool,
         poll_interrupts: &mut dyn FnMut() -> Interrupts,
     ) -> RedisEmptyResult {
-        self.subscriber
-            .fetch_messages(&self.client, on_message, poll_interrupts)
+        self.fetch_messages_with_events(on_message, &mut |_| (), poll_interrupts)
+    }
+
+    /// Fetches the messages from any of the subscribed channels and invokes the provided
+    /// on_message handler (see fetch_messages).<br>
+    /// In case the connection is lost, it is reopened based on the reconnect policy and all subscriptions
+    /// are issued again.<br>
+    /// The on_event handler is notified once the connection is lost and once it is reopened, enabling to
+    /// resync any state which may be affected by messages published in between.
+    ///
+    /// # Arguments
+    ///
+    /// * `on_message` - Invoked on each read message. If returns true, the fetching will stop.
+    /// * `on_event` - Invoked when the connection is lost and when it is reopened.
+    /// * `poll_interrupts` - Returns the interrupts struct, enabling to modify the fetching.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// # use simple_redis::types::SubscriptionEvent;
+    /// # use simple_redis::Interrupts;
+    /// # let mut client = simple_redis::create("redis://127.0.0.1:6379/").unwrap();
+    /// let mut policy = simple_redis::ReconnectPolicy::new();
+    /// policy.max_attempts = 10;
+    /// client.set_reconnect_policy(policy);
+    ///
+    /// client.subscribe("important_notifications");
+    ///
+    /// client.fetch_messages_with_events(
+    ///     &mut |message: simple_redis::Message| -> bool {
+    ///         let payload : String = message.get_payload().unwrap();
+    ///         println!("Got message: {}", payload);
+    ///
+    ///         // continue fetching
+    ///         false
+    ///     },
+    ///     &mut |event: SubscriptionEvent| match event {
+    ///         SubscriptionEvent::Disconnected(error) => println!("Connection lost: {}", error),
+    ///         SubscriptionEvent::Reconnected => println!("Reconnected, messages may have been missed"),
+    ///     },
+    ///     &mut || -> Interrupts { Interrupts::new() },
+    /// ).unwrap();
+    /// ```
+    pub fn fetch_messages_with_events(
+        self: &mut Client,
+        on_message: &mut dyn FnMut(Message) -> bool,
+        on_event: &mut dyn FnMut(SubscriptionEvent),
+        poll_interrupts: &mut dyn FnMut() -> Interrupts,
+    ) -> RedisEmptyResult {
+        self.subscriber.fetch_messages(
+            &self.server,
+            &self.options,
+            on_message,
+            on_event,
+            poll_interrupts,
+        )
     }
 }
 
 /// Constructs a new redis client.<br>
-/// The redis connection string must be in the following format: `redis://[:<passwd>@]<hostname>[:port][/<db>]`
+/// The redis connection string must be in the following format: `redis://[:<passwd>@]<hostname>[:port][/<db>]`<br>
+/// Sentinel managed masters are defined in the following format:
+/// `redis+sentinel://[:<passwd>@]<hostname>[:port][,<hostname>[:port]...]/<master name>[/<db>]`<br>
+/// TLS connections are defined via the `rediss://` scheme (requires the `tls` feature).<br>
+/// Unix socket connections are defined in the following format: `unix://<path>[?db=<db>&pass=<passwd>]` (or with
+/// the `redis+unix://` scheme).
 ///
 /// # Arguments
 ///
@@ -272,19 +770,344 @@ impl Client {
 /// }
 /// ```
 pub fn create(connection_string: &str) -> Result<Client, RedisError> {
-    match redis::Client::open(connection_string) {
-        Ok(redis_client) => {
-            let redis_connection = connection::create();
-            let redis_pubsub = subscriber::create();
-
-            let client = Client {
-                client: redis_client,
-                connection: redis_connection,
-                subscriber: redis_pubsub,
+    ClientBuilder::new(connection_string).build()
+}
+
+/// Constructs a new redis client which runs the commands on the provided cluster, while the subscriptions are
+/// made on the server defined by the builder.
+#[cfg(feature = "cluster")]
+pub(crate) fn create_cluster_client(
+    builder: &ClientBuilder,
+    cluster_client: redis::cluster::ClusterClient,
+    options: connection::ConnectionOptions,
+) -> Result<Client, RedisError> {
+    let server = builder.create_server()?;
+
+    Ok(Client {
+        server,
+        connection: connection::create_cluster(cluster_client),
+        subscriber: subscriber::create(),
+        replicas: replica::create(
+            vec![],
+            ReplicaSelection::default(),
+            DEFAULT_REPLICA_RETRY_INTERVAL,
+            &options,
+        ),
+        watching: false,
+        options,
+    })
+}
+
+impl ClientBuilder {
+    /// Returns a new builder for the provided connection string.
+    ///
+    /// # Arguments
+    ///
+    /// * `connection_string` - The connection string in the format of: `redis://[:<passwd>@]<hostname>[:port][/<db>]`
+    pub fn new(connection_string: &str) -> ClientBuilder {
+        ClientBuilder {
+            connection_string: connection_string.to_string(),
+            db: None,
+            username: None,
+            password: None,
+            replicas: vec![],
+            replica_selection: ReplicaSelection::default(),
+            replica_retry_interval: DEFAULT_REPLICA_RETRY_INTERVAL,
+            #[cfg(any(feature = "tls-rustls", feature = "tls-native-tls"))]
+            tls_ca_certificates: None,
+            #[cfg(any(feature = "tls-rustls", feature = "tls-native-tls"))]
+            tls_client_certificate: None,
+            options: Default::default(),
+        }
+    }
+
+    /// Returns a new builder for a sentinel managed master.<br>
+    /// The current primary server address is resolved via the sentinels whenever a new connection is opened.
+    ///
+    /// # Arguments
+    ///
+    /// * `sentinels` - The sentinel addresses in the format of: `<hostname>[:port]`
+    /// * `master_name` - The master name as monitored by the sentinels
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// match simple_redis::ClientBuilder::sentinel(vec!["127.0.0.1:26379", "127.0.0.1:26380"], "mymaster")
+    ///     .password("secret")
+    ///     .build()
+    /// {
+    ///     Ok(client) => println!("Created Redis Client"),
+    ///     Err(error) => println!("Unable to create Redis client: {}", error)
+    /// }
+    /// ```
+    pub fn sentinel(sentinels: Vec<&str>, master_name: &str) -> ClientBuilder {
+        ClientBuilder::new(&format!(
+            "{}{}/{}",
+            sentinel::SENTINEL_SCHEME,
+            sentinels.join(","),
+            master_name
+        ))
+    }
+
+    /// Sets the max time to wait while connecting to the redis server.
+    pub fn connect_timeout(mut self, timeout: Duration) -> ClientBuilder {
+        self.options.connect_timeout = Some(timeout);
+        self
+    }
+
+    /// Sets the max time to wait for a command response.
+    pub fn read_timeout(mut self, timeout: Duration) -> ClientBuilder {
+        self.options.read_timeout = Some(timeout);
+        self
+    }
+
+    /// Sets the max time to wait while sending a command.<br>
+    /// The async client has a single response timeout, which is the sum of the read and write timeouts.
+    pub fn write_timeout(mut self, timeout: Duration) -> ClientBuilder {
+        self.options.write_timeout = Some(timeout);
+        self
+    }
+
+    /// Sets the client name (see redis [CLIENT SETNAME](https://redis.io/commands/client-setname) command)
+    /// which is set on every new connection.
+    pub fn client_name(mut self, name: &str) -> ClientBuilder {
+        self.options.client_name = Some(name.to_string());
+        self
+    }
+
+    /// Sets the database index, overriding the one defined in the connection string.
+    pub fn db(mut self, db: i64) -> ClientBuilder {
+        self.db = Some(db);
+        self
+    }
+
+    /// Sets the username, overriding the one defined in the connection string.
+    pub fn username(mut self, username: &str) -> ClientBuilder {
+        self.username = Some(username.to_string());
+        self
+    }
+
+    /// Sets the password, overriding the one defined in the connection string.
+    pub fn password(mut self, password: &str) -> ClientBuilder {
+        self.password = Some(password.to_string());
+        self
+    }
+
+    /// Adds a replica server on which read only commands (for example `GET`, `HGETALL`, `LRANGE`) are
+    /// invoked, while all other commands are invoked on the primary server.<br>
+    /// In case no replica is available, read only commands are invoked on the primary server.<br>
+    /// Since the replication is asynchronous, recently written values may not be returned.<br>
+    /// The database and credentials overrides are applied on the replica servers as well.
+    ///
+    /// # Arguments
+    ///
+    /// * `connection_string` - The replica connection string in the format of: `redis://[:<passwd>@]<hostname>[:port][/<db>]`
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// match simple_redis::ClientBuilder::new("redis://127.0.0.1:6379/")
+    ///     .replica("redis://127.0.0.1:6380/")
+    ///     .replica("redis://127.0.0.1:6381/")
+    ///     .replica_selection(simple_redis::ReplicaSelection::LeastLatency)
+    ///     .build()
+    /// {
+    ///     Ok(client) => println!("Created Redis Client"),
+    ///     Err(error) => println!("Unable to create Redis client: {}", error)
+    /// }
+    /// ```
+    pub fn replica(mut self, connection_string: &str) -> ClientBuilder {
+        self.replicas.push(connection_string.to_string());
+        self
+    }
+
+    /// Sets how read only commands are distributed among the replicas (by default, round robin).
+    pub fn replica_selection(mut self, selection: ReplicaSelection) -> ClientBuilder {
+        self.replica_selection = selection;
+        self
+    }
+
+    /// Sets the time to wait before using a replica again, once it failed to invoke a command (by default,
+    /// 5 seconds).
+    pub fn replica_retry_interval(mut self, interval: Duration) -> ClientBuilder {
+        self.replica_retry_interval = interval;
+        self
+    }
+
+    /// Sets the CA certificates (PEM encoded, may hold a bundle of several certificates) used to verify the
+    /// server certificate of `rediss://` connections, instead of the system trust store.<br>
+    /// Custom certificates are only supported by the `tls-rustls` feature, so with the `tls-native-tls` feature
+    /// alone, building the client returns an invalid input error.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// let ca_certificates = std::fs::read("./certs/ca.crt").unwrap();
+    ///
+    /// match simple_redis::ClientBuilder::new("rediss://redis.example.com:6380/")
+    ///     .tls_ca_certificates(&ca_certificates)
+    ///     .build()
+    /// {
+    ///     Ok(client) => println!("Created Redis Client"),
+    ///     Err(error) => println!("Unable to create Redis client: {}", error)
+    /// }
+    /// ```
+    #[cfg(any(feature = "tls-rustls", feature = "tls-native-tls"))]
+    pub fn tls_ca_certificates(mut self, pem: &[u8]) -> ClientBuilder {
+        self.tls_ca_certificates = Some(pem.to_vec());
+        self
+    }
+
+    /// Sets the client certificate and private key (both PEM encoded) presented to the server for mutual TLS
+    /// authentication of `rediss://` connections.<br>
+    /// Custom certificates are only supported by the `tls-rustls` feature, so with the `tls-native-tls` feature
+    /// alone, building the client returns an invalid input error.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// let certificate = std::fs::read("./certs/client.crt").unwrap();
+    /// let key = std::fs::read("./certs/client.key").unwrap();
+    ///
+    /// match simple_redis::ClientBuilder::new("rediss://redis.example.com:6380/")
+    ///     .tls_client_certificate(&certificate, &key)
+    ///     .build()
+    /// {
+    ///     Ok(client) => println!("Created Redis Client"),
+    ///     Err(error) => println!("Unable to create Redis client: {}", error)
+    /// }
+    /// ```
+    #[cfg(any(feature = "tls-rustls", feature = "tls-native-tls"))]
+    pub fn tls_client_certificate(mut self, certificate: &[u8], key: &[u8]) -> ClientBuilder {
+        self.tls_client_certificate = Some((certificate.to_vec(), key.to_vec()));
+        self
+    }
+
+    /// Sets the policy used to reopen the connection in case it was lost.
+    pub fn reconnect_policy(mut self, policy: ReconnectPolicy) -> ClientBuilder {
+        self.options.reconnect_policy = policy;
+        self
+    }
+
+    /// Sets when the connection is verified (via PING) before invoking a command.
+    pub fn health_check(mut self, health_check: HealthCheck) -> ClientBuilder {
+        self.options.health_check = health_check;
+        self
+    }
+
+    /// Constructs a new redis client based on the builder configuration.<br>
+    /// No connection is opened at this point.
+    pub fn build(&self) -> Result<Client, RedisError> {
+        let server = self.create_server()?;
+
+        let mut replica_servers = vec![];
+        for replica in &self.replicas {
+            let redis_client = self.create_redis_client(replica)?;
+            replica_servers.push(connection::Server::Fixed(redis_client));
+        }
+
+        let client = Client {
+            server,
+            connection: connection::create(),
+            subscriber: subscriber::create(),
+            replicas: replica::create(
+                replica_servers,
+                self.replica_selection,
+                self.replica_retry_interval,
+                &self.options,
+            ),
+            watching: false,
+            options: self.options.clone(),
+        };
+
+        Ok(client)
+    }
+
+    /// Constructs a new async redis client based on the builder configuration (requires the `async` feature).<br>
+    /// No connection is opened at this point.<br>
+    /// Sentinel managed masters are not supported by the async client, in which case an invalid input error is
+    /// returned.
+    #[cfg(feature = "async")]
+    pub fn build_async(&self) -> Result<AsyncClient, RedisError> {
+        if sentinel::is_sentinel_connection_string(&self.connection_string) {
+            return Err(RedisError::InvalidInput(
+                "Sentinel connection strings are not supported by the async client.",
+            ));
+        }
+
+        let redis_client = self.create_redis_client(&self.connection_string)?;
+
+        Ok(async_client::create_from_redis_client(
+            redis_client,
+            self.options.clone(),
+        ))
+    }
+
+    fn create_server(&self) -> Result<connection::Server, RedisError> {
+        if sentinel::is_sentinel_connection_string(&self.connection_string) {
+            let mut sentinel = sentinel::parse(&self.connection_string)?;
+
+            if let Some(db) = self.db {
+                sentinel.redis.db = db;
+            }
+            if self.username.is_some() {
+                sentinel.redis.username.clone_from(&self.username);
+            }
+            if self.password.is_some() {
+                sentinel.redis.password.clone_from(&self.password);
+            }
+
+            Ok(connection::Server::Sentinel(sentinel))
+        } else {
+            let redis_client = self.create_redis_client(&self.connection_string)?;
+            Ok(connection::Server::Fixed(redis_client))
+        }
+    }
+
+    /// Creates a redis client for the provided connection string, applying the database and credentials overrides.
+    fn create_redis_client(&self, connection_string: &str) -> Result<redis::Client, RedisError> {
+        let mut connection_info =
+            redis::IntoConnectionInfo::into_connection_info(connection_string)?;
+
+        if let Some(db) = self.db {
+            connection_info.redis.db = db;
+        }
+        if self.username.is_some() {
+            connection_info.redis.username.clone_from(&self.username);
+        }
+        if self.password.is_some() {
+            connection_info.redis.password.clone_from(&self.password);
+        }
+
+        #[cfg(all(feature = "tls-native-tls", not(feature = "tls-rustls")))]
+        if self.tls_ca_certificates.is_some() || self.tls_client_certificate.is_some() {
+            return Err(RedisError::InvalidInput(
+                "Custom TLS certificates are only supported with the tls-rustls feature.",
+            ));
+        }
+
+        #[cfg(feature = "tls-rustls")]
+        if self.tls_ca_certificates.is_some() || self.tls_client_certificate.is_some() {
+            let certificates = redis::TlsCertificates {
+                client_tls: self
+                    .tls_client_certificate
+                    .as_ref()
+                    .map(|(certificate, key)| redis::ClientTlsConfig {
+                        client_cert: certificate.clone(),
+                        client_key: key.clone(),
+                    }),
+                root_cert: self.tls_ca_certificates.clone(),
+            };
+
+            return match redis::Client::build_with_tls(connection_info, certificates) {
+                Ok(redis_client) => Ok(redis_client),
+                Err(error) => Err(error.into()),
             };
+        }
 
-            Ok(client)
+        match redis::Client::open(connection_info) {
+            Ok(redis_client) => Ok(redis_client),
+            Err(error) => Err(error.into()),
         }
-        Err(error) => Err(RedisError::RedisError(error)),
     }
 }
@@ -1,5 +1,14 @@
 use super::*;
 
+fn get_redis_info(client: &Client) -> redis::RedisConnectionInfo {
+    match client.server {
+        connection::Server::Fixed(ref redis_client) => {
+            redis_client.get_connection_info().redis.clone()
+        }
+        connection::Server::Sentinel(ref sentinel) => sentinel.redis.clone(),
+    }
+}
+
 #[test]
 fn create_invalid_url() {
     let result = create("test/bad/url");
@@ -12,6 +21,110 @@ fn create_valid_url() {
     assert!(!client.is_connection_open());
 }
 
+#[test]
+fn is_idempotent_command_read() {
+    assert!(is_idempotent_command("GET"));
+    assert!(is_idempotent_command("hgetall"));
+}
+
+#[test]
+fn is_idempotent_command_write() {
+    assert!(!is_idempotent_command("INCR"));
+    assert!(!is_idempotent_command("lpush"));
+    assert!(!is_idempotent_command("PUBLISH"));
+}
+
+#[test]
+fn is_read_only_command_read() {
+    assert!(is_read_only_command("GET"));
+    assert!(is_read_only_command("hgetall"));
+    assert!(is_read_only_command("LRANGE"));
+    assert!(is_read_only_command("smembers"));
+    assert!(is_read_only_command("ZRANGE"));
+}
+
+#[test]
+fn is_read_only_command_write() {
+    assert!(!is_read_only_command("SET"));
+    assert!(!is_read_only_command("hset"));
+    assert!(!is_read_only_command("LPUSH"));
+    assert!(!is_read_only_command("EVAL"));
+}
+
+#[test]
+fn builder_replicas_invalid() {
+    let result = ClientBuilder::new("redis://127.0.0.1:6379/")
+        .replica("test/bad/url")
+        .build();
+
+    assert!(result.is_err());
+}
+
+#[test]
+#[cfg(not(any(feature = "tls-rustls", feature = "tls-native-tls")))]
+fn create_tls_without_feature() {
+    let result = create("rediss://127.0.0.1:6380/");
+    assert!(result.is_err());
+}
+
+#[test]
+#[cfg(feature = "tls-rustls")]
+fn builder_tls_ca_certificates() {
+    let client = ClientBuilder::new("rediss://127.0.0.1:6380/")
+        .tls_ca_certificates(b"")
+        .build()
+        .unwrap();
+
+    match client.server {
+        connection::Server::Fixed(ref redis_client) => {
+            match redis_client.get_connection_info().addr {
+                redis::ConnectionAddr::TcpTls { ref host, port, .. } => {
+                    assert_eq!(host, "127.0.0.1");
+                    assert_eq!(port, 6380);
+                }
+                _ => panic!("test error"),
+            }
+        }
+        _ => panic!("test error"),
+    }
+}
+
+#[test]
+#[cfg(feature = "tls-rustls")]
+fn builder_tls_without_rediss() {
+    let result = ClientBuilder::new("redis://127.0.0.1:6379/")
+        .tls_ca_certificates(b"")
+        .build();
+
+    assert!(result.is_err());
+}
+
+#[test]
+#[cfg(feature = "tls-rustls")]
+fn builder_tls_invalid_client_key() {
+    let result = ClientBuilder::new("rediss://127.0.0.1:6380/")
+        .tls_client_certificate(b"", b"invalid key")
+        .build();
+
+    assert!(result.is_err());
+}
+
+#[test]
+#[cfg(all(feature = "tls-native-tls", not(feature = "tls-rustls")))]
+fn builder_tls_certificates_native_tls() {
+    let result = ClientBuilder::new("rediss://127.0.0.1:6380/")
+        .tls_ca_certificates(b"")
+        .build();
+
+    match result {
+        Err(RedisError::InvalidInput(description)) => assert_eq!(
+            description,
+            "Custom TLS certificates are only supported with the tls-rustls feature."
+        ),
+        _ => panic!("test error"),
+    }
+}
+
 #[test]
 fn run_command() {
     let mut client = create("redis://127.0.0.1:6379/").unwrap();
@@ -40,3 +153,106 @@ fn run_command_typed_response() {
         .unwrap();
     assert_eq!(value, "my_value");
 }
+
+#[test]
+fn builder_invalid_url() {
+    let result = ClientBuilder::new("test/bad/url").build();
+    assert!(result.is_err());
+}
+
+#[test]
+fn builder_defaults() {
+    let mut client = ClientBuilder::new("redis://:secret@127.0.0.1:6379/2")
+        .build()
+        .unwrap();
+    assert!(!client.is_connection_open());
+
+    let connection_info = get_redis_info(&client);
+    assert_eq!(connection_info.db, 2);
+    assert_eq!(connection_info.password, Some("secret".to_string()));
+    assert!(client.options.connect_timeout.is_none());
+    assert!(client.options.client_name.is_none());
+}
+
+#[test]
+fn builder_overrides() {
+    let client = ClientBuilder::new("redis://:secret@127.0.0.1:6379/2")
+        .connect_timeout(Duration::from_secs(1))
+        .read_timeout(Duration::from_secs(2))
+        .write_timeout(Duration::from_secs(3))
+        .client_name("test_client")
+        .db(5)
+        .username("test_user")
+        .password("test_password")
+        .health_check(HealthCheck::Never)
+        .build()
+        .unwrap();
+
+    let connection_info = get_redis_info(&client);
+    assert_eq!(connection_info.db, 5);
+    assert_eq!(connection_info.username, Some("test_user".to_string()));
+    assert_eq!(connection_info.password, Some("test_password".to_string()));
+    assert_eq!(client.options.connect_timeout, Some(Duration::from_secs(1)));
+    assert_eq!(client.options.read_timeout, Some(Duration::from_secs(2)));
+    assert_eq!(client.options.write_timeout, Some(Duration::from_secs(3)));
+    assert_eq!(client.options.client_name, Some("test_client".to_string()));
+    assert_eq!(client.options.health_check, HealthCheck::Never);
+}
+
+#[test]
+fn builder_sentinel_overrides() {
+    let client = ClientBuilder::sentinel(vec!["127.0.0.1:26379", "127.0.0.1"], "mymaster")
+        .db(5)
+        .username("test_user")
+        .password("test_password")
+        .build()
+        .unwrap();
+
+    assert!(client.server.is_resolved());
+    let connection_info = get_redis_info(&client);
+    assert_eq!(connection_info.db, 5);
+    assert_eq!(connection_info.username, Some("test_user".to_string()));
+    assert_eq!(connection_info.password, Some("test_password".to_string()));
+}
+
+#[test]
+#[cfg(unix)]
+fn create_unix_socket_url() {
+    let client = create("unix:///tmp/redis.sock?db=2&pass=secret").unwrap();
+
+    match client.server {
+        connection::Server::Fixed(ref redis_client) => {
+            assert_eq!(
+                redis_client.get_connection_info().addr,
+                redis::ConnectionAddr::Unix("/tmp/redis.sock".into())
+            );
+        }
+        _ => panic!("test error"),
+    }
+    let connection_info = get_redis_info(&client);
+    assert_eq!(connection_info.db, 2);
+    assert_eq!(connection_info.password, Some("secret".to_string()));
+}
+
+#[test]
+#[cfg(unix)]
+fn builder_unix_socket_overrides() {
+    let client = ClientBuilder::new("redis+unix:///tmp/redis.sock")
+        .db(4)
+        .username("test_user")
+        .password("test_password")
+        .build()
+        .unwrap();
+
+    let connection_info = get_redis_info(&client);
+    assert_eq!(connection_info.db, 4);
+    assert_eq!(connection_info.username, Some("test_user".to_string()));
+    assert_eq!(connection_info.password, Some("test_password".to_string()));
+}
+
+#[test]
+fn builder_sentinel_invalid() {
+    let result = ClientBuilder::sentinel(vec![], "mymaster").build();
+
+    assert!(result.is_err());
+}
@@ -0,0 +1,175 @@
+//! # cluster
+//!
+//! Enables to use the redis client with a redis cluster.<br>
+//! The cluster topology is discovered from the provided nodes and every command is routed to the node owning the
+//! hash slot of the command keys. MOVED and ASK redirections are followed and the topology is refreshed in case
+//! the slots were migrated or a node failed over.
+//!
+
+#[cfg(test)]
+#[path = "./cluster_test.rs"]
+mod cluster_test;
+
+use crate::client::{self, Client};
+use crate::connection::ConnectionOptions;
+use crate::types::{HealthCheck, ReconnectPolicy, RedisError};
+use std::time::Duration;
+
+#[derive(Debug, Clone)]
+/// Enables to configure and construct a new redis client which is connected to a redis cluster.
+///
+/// # Example
+///
+/// ```
+/// # use std::time::Duration;
+/// match simple_redis::ClusterClientBuilder::new(vec![
+///     "redis://127.0.0.1:7000/",
+///     "redis://127.0.0.1:7001/",
+/// ])
+/// .connect_timeout(Duration::from_secs(5))
+/// .read_timeout(Duration::from_secs(10))
+/// .build()
+/// {
+///     Ok(client) => println!("Created Redis Cluster Client"),
+///     Err(error) => println!("Unable to create Redis client: {}", error)
+/// }
+/// ```
+pub struct ClusterClientBuilder {
+    /// The connection strings of the initial cluster nodes
+    nodes: Vec<String>,
+    /// Overrides the username defined in the connection strings
+    username: Option<String>,
+    /// Overrides the password defined in the connection strings
+    password: Option<String>,
+    /// True to route read only commands to the replica nodes
+    read_from_replicas: bool,
+    /// Options applied on every opened connection
+    options: ConnectionOptions,
+}
+
+/// Returns the cluster hash slot (see redis [CLUSTER KEYSLOT](https://redis.io/commands/cluster-keyslot)
+/// command) of the provided key.<br>
+/// In case the key contains a `{hashtag}`, only the hashtag is hashed, enabling to place related keys in the
+/// same slot.
+///
+/// # Example
+///
+/// ```
+/// let slot = simple_redis::cluster::get_key_slot("{user1000}.following");
+/// assert_eq!(slot, simple_redis::cluster::get_key_slot("{user1000}.followers"));
+/// ```
+pub fn get_key_slot(key: &str) -> u16 {
+    redis::cluster_routing::get_slot(key.as_bytes())
+}
+
+impl ClusterClientBuilder {
+    /// Returns a new builder for the provided initial cluster nodes.<br>
+    /// The nodes are only used to discover the cluster topology, so not all the cluster nodes must be provided.
+    ///
+    /// # Arguments
+    ///
+    /// * `nodes` - The nodes connection strings in the format of: `redis://[:<passwd>@]<hostname>[:port]`
+    pub fn new(nodes: Vec<&str>) -> ClusterClientBuilder {
+        ClusterClientBuilder {
+            nodes: nodes.iter().map(|node| node.to_string()).collect(),
+            username: None,
+            password: None,
+            read_from_replicas: false,
+            options: Default::default(),
+        }
+    }
+
+    /// Sets the max time to wait while connecting to each cluster node.
+    pub fn connect_timeout(mut self, timeout: Duration) -> ClusterClientBuilder {
+        self.options.connect_timeout = Some(timeout);
+        self
+    }
+
+    /// Sets the max time to wait for a command response.
+    pub fn read_timeout(mut self, timeout: Duration) -> ClusterClientBuilder {
+        self.options.read_timeout = Some(timeout);
+        self
+    }
+
+    /// Sets the max time to wait while sending a command.
+    pub fn write_timeout(mut self, timeout: Duration) -> ClusterClientBuilder {
+        self.options.write_timeout = Some(timeout);
+        self
+    }
+
+    /// Sets the username, overriding the one defined in the connection strings.
+    pub fn username(mut self, username: &str) -> ClusterClientBuilder {
+        self.username = Some(username.to_string());
+        self
+    }
+
+    /// Sets the password, overriding the one defined in the connection strings.
+    pub fn password(mut self, password: &str) -> ClusterClientBuilder {
+        self.password = Some(password.to_string());
+        self
+    }
+
+    /// Routes read only commands to the replica nodes instead of the primary nodes.<br>
+    /// Since the replication is asynchronous, recently written values may not be returned.
+    pub fn read_from_replicas(mut self) -> ClusterClientBuilder {
+        self.read_from_replicas = true;
+        self
+    }
+
+    /// Sets the policy used to reopen the cluster connections in case they were lost.
+    pub fn reconnect_policy(mut self, policy: ReconnectPolicy) -> ClusterClientBuilder {
+        self.options.reconnect_policy = policy;
+        self
+    }
+
+    /// Sets when the cluster connections are verified (via PING) before invoking a command.
+    pub fn health_check(mut self, health_check: HealthCheck) -> ClusterClientBuilder {
+        self.options.health_check = health_check;
+        self
+    }
+
+    /// Constructs a new redis client based on the builder configuration.<br>
+    /// No connection is opened at this point.<br>
+    /// Subscriptions are made on the first provided node, since published messages are propagated to all the
+    /// cluster nodes.
+    pub fn build(&self) -> Result<Client, RedisError> {
+        let mut builder = redis::cluster::ClusterClientBuilder::new(self.nodes.clone());
+
+        if let Some(ref username) = self.username {
+            builder = builder.username(username.to_string());
+        }
+        if let Some(ref password) = self.password {
+            builder = builder.password(password.to_string());
+        }
+        if self.read_from_replicas {
+            builder = builder.read_from_replicas();
+        }
+        if let Some(timeout) = self.options.connect_timeout {
+            builder = builder.connection_timeout(timeout);
+        }
+        if let Some(timeout) = self.options.read_timeout {
+            builder = builder.response_timeout(timeout);
+        }
+
+        let cluster_client = builder.build()?;
+
+        let mut subscriber_builder = client::ClientBuilder::new(&self.nodes[0]);
+        if let Some(ref username) = self.username {
+            subscriber_builder = subscriber_builder.username(username);
+        }
+        if let Some(ref password) = self.password {
+            subscriber_builder = subscriber_builder.password(password);
+        }
+
+        client::create_cluster_client(&subscriber_builder, cluster_client, self.options.clone())
+    }
+}
+
+/// Constructs a new redis client which is connected to a redis cluster.
+///
+/// # Arguments
+///
+/// * `nodes` - The initial nodes connection strings in the format of: `redis://[:<passwd>@]<hostname>[:port]`
+pub fn create(nodes: Vec<&str>) -> Result<Client, RedisError> {
+    ClusterClientBuilder::new(nodes).build()
+}
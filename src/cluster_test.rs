@@ -0,0 +1,76 @@
+use super::*;
+
+#[test]
+fn get_key_slot_values() {
+    assert_eq!(get_key_slot("foo"), 12182);
+    assert_eq!(get_key_slot("bar"), 5061);
+    assert_eq!(get_key_slot(""), 0);
+}
+
+#[test]
+fn get_key_slot_hashtag() {
+    assert_eq!(
+        get_key_slot("{user1000}.following"),
+        get_key_slot("user1000")
+    );
+    assert_eq!(
+        get_key_slot("{user1000}.following"),
+        get_key_slot("{user1000}.followers")
+    );
+    // empty hashtags are ignored and the entire key is hashed
+    assert_ne!(get_key_slot("{}.following"), get_key_slot("{}.followers"));
+}
+
+#[test]
+fn build_no_nodes() {
+    let result = ClusterClientBuilder::new(vec![]).build();
+
+    assert!(result.is_err());
+}
+
+#[test]
+fn build_invalid_node() {
+    let result = ClusterClientBuilder::new(vec!["invalid"]).build();
+
+    assert!(result.is_err());
+}
+
+#[test]
+fn build_options() {
+    let builder = ClusterClientBuilder::new(vec!["redis://127.0.0.1:7000/"])
+        .connect_timeout(Duration::from_secs(1))
+        .read_timeout(Duration::from_secs(2))
+        .write_timeout(Duration::from_secs(3))
+        .username("user")
+        .password("secret")
+        .read_from_replicas();
+
+    assert_eq!(
+        builder.options.connect_timeout,
+        Some(Duration::from_secs(1))
+    );
+    assert_eq!(builder.options.read_timeout, Some(Duration::from_secs(2)));
+    assert_eq!(builder.options.write_timeout, Some(Duration::from_secs(3)));
+    assert_eq!(builder.username, Some("user".to_string()));
+    assert_eq!(builder.password, Some("secret".to_string()));
+    assert!(builder.read_from_replicas);
+
+    assert!(builder.build().is_ok());
+}
+
+#[test]
+fn run_command_connection_refused() {
+    let mut policy = ReconnectPolicy::new();
+    policy.max_attempts = 1;
+    let mut client = ClusterClientBuilder::new(vec!["redis://127.0.0.1:1/"])
+        .reconnect_policy(policy)
+        .build()
+        .unwrap();
+
+    let result = client.get_string("cluster_key");
+
+    match result {
+        Err(ref error) => assert!(error.is_connection_error()),
+        _ => panic!("Invalid result"),
+    }
+}
@@ -0,0 +1,233 @@
+//! # codec
+//!
+//! Enables storing and reading structured values using pluggable serialization formats.<br>
+//! This module is only available when the `serde` feature is enabled.
+//!
+
+#[cfg(test)]
+#[path = "./codec_test.rs"]
+mod codec_test;
+
+use crate::client::Client;
+use crate::types::{RedisEmptyResult, RedisError, RedisResult};
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+
+/// Defines how structured values are converted to and from the binary values stored in redis.
+pub trait Codec {
+    /// Converts the provided value to its binary representation.
+    fn encode<T: Serialize>(&self, value: &T) -> RedisResult<Vec<u8>>;
+
+    /// Converts the provided binary representation back to a value.
+    fn decode<T: DeserializeOwned>(&self, data: &[u8]) -> RedisResult<T>;
+}
+
+/// Stores values as JSON text.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct JsonCodec;
+
+impl Codec for JsonCodec {
+    fn encode<T: Serialize>(&self, value: &T) -> RedisResult<Vec<u8>> {
+        match serde_json::to_vec(value) {
+            Ok(data) => Ok(data),
+            Err(error) => Err(RedisError::Serialization(error.to_string())),
+        }
+    }
+
+    fn decode<T: DeserializeOwned>(&self, data: &[u8]) -> RedisResult<T> {
+        match serde_json::from_slice(data) {
+            Ok(value) => Ok(value),
+            Err(error) => Err(RedisError::Serialization(error.to_string())),
+        }
+    }
+}
+
+/// Stores values in the MessagePack format (requires the `msgpack` feature).
+#[cfg(feature = "msgpack")]
+#[derive(Debug, Clone, Copy, Default)]
+pub struct MsgPackCodec;
+
+#[cfg(feature = "msgpack")]
+impl Codec for MsgPackCodec {
+    fn encode<T: Serialize>(&self, value: &T) -> RedisResult<Vec<u8>> {
+        match rmp_serde::to_vec_named(value) {
+            Ok(data) => Ok(data),
+            Err(error) => Err(RedisError::Serialization(error.to_string())),
+        }
+    }
+
+    fn decode<T: DeserializeOwned>(&self, data: &[u8]) -> RedisResult<T> {
+        match rmp_serde::from_slice(data) {
+            Ok(value) => Ok(value),
+            Err(error) => Err(RedisError::Serialization(error.to_string())),
+        }
+    }
+}
+
+/// Stores values in the bincode format (requires the `bincode` feature).
+#[cfg(feature = "bincode")]
+#[derive(Debug, Clone, Copy, Default)]
+pub struct BincodeCodec;
+
+#[cfg(feature = "bincode")]
+impl Codec for BincodeCodec {
+    fn encode<T: Serialize>(&self, value: &T) -> RedisResult<Vec<u8>> {
+        match bincode::serialize(value) {
+            Ok(data) => Ok(data),
+            Err(error) => Err(RedisError::Serialization(error.to_string())),
+        }
+    }
+
+    fn decode<T: DeserializeOwned>(&self, data: &[u8]) -> RedisResult<T> {
+        match bincode::deserialize(data) {
+            Ok(value) => Ok(value),
+            Err(error) => Err(RedisError::Serialization(error.to_string())),
+        }
+    }
+}
+
+/// Defines the structured values commands exposed by the redis client.
+impl Client {
+    /// Encodes the provided value using the provided codec and stores it (see redis [SET](https://redis.io/commands/set) command).
+    pub fn set_encoded<C: Codec, T: Serialize>(
+        self: &mut Client,
+        codec: &C,
+        key: &str,
+        value: &T,
+    ) -> RedisEmptyResult {
+        let data = codec.encode(value)?;
+
+        self.set_binary(key, &data)
+    }
+
+    /// Reads the value (see redis [GET](https://redis.io/commands/get) command) and decodes it using the provided codec.
+    pub fn get_encoded<C: Codec, T: DeserializeOwned>(
+        self: &mut Client,
+        codec: &C,
+        key: &str,
+    ) -> RedisResult<T> {
+        let data = self.get_binary(key)?;
+
+        codec.decode(&data)
+    }
+
+    /// Encodes the provided value using the provided codec and stores it in the hash field
+    /// (see redis [HSET](https://redis.io/commands/hset) command).
+    pub fn hset_encoded<C: Codec, T: Serialize>(
+        self: &mut Client,
+        codec: &C,
+        key: &str,
+        field: &str,
+        value: &T,
+    ) -> RedisEmptyResult {
+        let data = codec.encode(value)?;
+
+        self.hset_binary(key, field, &data)
+    }
+
+    /// Reads the hash field value (see redis [HGET](https://redis.io/commands/hget) command) and decodes it using
+    /// the provided codec.
+    pub fn hget_encoded<C: Codec, T: DeserializeOwned>(
+        self: &mut Client,
+        codec: &C,
+        key: &str,
+        field: &str,
+    ) -> RedisResult<T> {
+        let data = self.hget_binary(key, field)?;
+
+        codec.decode(&data)
+    }
+
+    /// Encodes the provided value using the provided codec and pushes it to the head of the list
+    /// (see redis [LPUSH](https://redis.io/commands/lpush) command).
+    pub fn lpush_encoded<C: Codec, T: Serialize>(
+        self: &mut Client,
+        codec: &C,
+        key: &str,
+        value: &T,
+    ) -> RedisEmptyResult {
+        let data = codec.encode(value)?;
+
+        self.lpush_binary(key, &data)
+    }
+
+    /// Reads the list values (see redis [LRANGE](https://redis.io/commands/lrange) command) and decodes them
+    /// using the provided codec.
+    pub fn lrange_encoded<C: Codec, T: DeserializeOwned>(
+        self: &mut Client,
+        codec: &C,
+        key: &str,
+        start: isize,
+        stop: isize,
+    ) -> RedisResult<Vec<T>> {
+        let values = self.lrange_binary(key, start, stop)?;
+
+        let mut output = Vec::with_capacity(values.len());
+        for data in values {
+            output.push(codec.decode(&data)?);
+        }
+
+        Ok(output)
+    }
+
+    /// Stores the provided value as JSON (see set_encoded).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use std::collections::HashMap;
+    /// # let mut client = simple_redis::create("redis://127.0.0.1:6379/").unwrap();
+    /// let mut user = HashMap::new();
+    /// user.insert("name".to_string(), "user1".to_string());
+    ///
+    /// match client.set_json("my_user", &user) {
+    ///     Err(error) => println!("Unable to set value in Redis: {}", error),
+    ///     _ => println!("Value set in Redis")
+    /// }
+    ///
+    /// let stored_user: HashMap<String, String> = client.get_json("my_user").unwrap();
+    /// assert_eq!(stored_user, user);
+    /// ```
+    pub fn set_json<T: Serialize>(self: &mut Client, key: &str, value: &T) -> RedisEmptyResult {
+        self.set_encoded(&JsonCodec, key, value)
+    }
+
+    /// Reads the JSON value (see get_encoded).
+    pub fn get_json<T: DeserializeOwned>(self: &mut Client, key: &str) -> RedisResult<T> {
+        self.get_encoded(&JsonCodec, key)
+    }
+
+    /// Stores the provided value as JSON in the hash field (see hset_encoded).
+    pub fn hset_json<T: Serialize>(
+        self: &mut Client,
+        key: &str,
+        field: &str,
+        value: &T,
+    ) -> RedisEmptyResult {
+        self.hset_encoded(&JsonCodec, key, field, value)
+    }
+
+    /// Reads the JSON hash field value (see hget_encoded).
+    pub fn hget_json<T: DeserializeOwned>(
+        self: &mut Client,
+        key: &str,
+        field: &str,
+    ) -> RedisResult<T> {
+        self.hget_encoded(&JsonCodec, key, field)
+    }
+
+    /// Pushes the provided value as JSON to the head of the list (see lpush_encoded).
+    pub fn lpush_json<T: Serialize>(self: &mut Client, key: &str, value: &T) -> RedisEmptyResult {
+        self.lpush_encoded(&JsonCodec, key, value)
+    }
+
+    /// Reads the JSON list values (see lrange_encoded).
+    pub fn lrange_json<T: DeserializeOwned>(
+        self: &mut Client,
+        key: &str,
+        start: isize,
+        stop: isize,
+    ) -> RedisResult<Vec<T>> {
+        self.lrange_encoded(&JsonCodec, key, start, stop)
+    }
+}
@@ -0,0 +1,57 @@
+use super::*;
+use serde::Deserialize;
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct TestValue {
+    name: String,
+    count: u32,
+    tags: Vec<String>,
+}
+
+fn create_value() -> TestValue {
+    TestValue {
+        name: "test".to_string(),
+        count: 3,
+        tags: vec!["a".to_string(), "b".to_string()],
+    }
+}
+
+#[test]
+fn json_codec_roundtrip() {
+    let data = JsonCodec.encode(&create_value()).unwrap();
+    assert_eq!(
+        String::from_utf8(data.clone()).unwrap(),
+        r#"{"name":"test","count":3,"tags":["a","b"]}"#
+    );
+
+    let value: TestValue = JsonCodec.decode(&data).unwrap();
+    assert_eq!(value, create_value());
+}
+
+#[test]
+fn json_codec_invalid() {
+    let result: RedisResult<TestValue> = JsonCodec.decode(b"{bad");
+
+    assert!(matches!(result, Err(RedisError::Serialization(_))));
+}
+
+#[test]
+#[cfg(feature = "msgpack")]
+fn msgpack_codec_roundtrip() {
+    let data = MsgPackCodec.encode(&create_value()).unwrap();
+
+    let value: TestValue = MsgPackCodec.decode(&data).unwrap();
+    assert_eq!(value, create_value());
+}
+
+#[test]
+#[cfg(feature = "bincode")]
+fn bincode_codec_roundtrip() {
+    let data = BincodeCodec.encode(&create_value()).unwrap();
+
+    let value: TestValue = BincodeCodec.decode(&data).unwrap();
+    assert_eq!(value, create_value());
+
+    let result: RedisResult<TestValue> = BincodeCodec.decode(&[1]);
+    assert!(matches!(result, Err(RedisError::Serialization(_))));
+}
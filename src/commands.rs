@@ -8,9 +8,72 @@
 mod commands_test;
 
 use crate::client::Client;
-use crate::types::{RedisArg, RedisBoolResult, RedisEmptyResult, RedisResult, RedisStringResult};
+use crate::types::{
+    Aggregate, RedisArg, RedisBoolResult, RedisEmptyResult, RedisResult, RedisStringResult,
+    ZAddComparison, ZAddCondition, ZAddOptions,
+};
 use std::collections::HashMap;
 use std::str::FromStr;
+use std::time::Duration;
+
+/// Returns the ZADD options arguments (without the INCR option).
+pub(crate) fn create_zadd_options_args(options: &ZAddOptions) -> Vec<&'static str> {
+    let mut args = vec![];
+
+    match options.condition {
+        Some(ZAddCondition::NotExists) => args.push("NX"),
+        Some(ZAddCondition::Exists) => args.push("XX"),
+        None => (),
+    }
+    match options.comparison {
+        Some(ZAddComparison::GreaterThan) => args.push("GT"),
+        Some(ZAddComparison::LessThan) => args.push("LT"),
+        None => (),
+    }
+    if options.changed {
+        args.push("CH");
+    }
+
+    args
+}
+
+/// Returns the `LIMIT offset count` arguments in case a limit is provided.
+pub(crate) fn create_limit_args(limit: Option<(isize, isize)>) -> Vec<String> {
+    match limit {
+        Some((offset, count)) => vec!["LIMIT".to_string(), offset.to_string(), count.to_string()],
+        None => vec![],
+    }
+}
+
+/// Returns the ZUNIONSTORE/ZINTERSTORE arguments in the form of:
+/// `destination numkeys key [key ...] [WEIGHTS weight [weight ...]] [AGGREGATE SUM|MIN|MAX]`
+pub(crate) fn create_store_args(
+    destination: &str,
+    keys: Vec<&str>,
+    weights: Option<Vec<f64>>,
+    aggregate: Option<Aggregate>,
+) -> Vec<String> {
+    let mut args = vec![destination.to_string(), keys.len().to_string()];
+    for key in keys {
+        args.push(key.to_string());
+    }
+
+    if let Some(weights) = weights {
+        args.push("WEIGHTS".to_string());
+        for weight in weights {
+            args.push(weight.to_string());
+        }
+    }
+
+    match aggregate {
+        Some(Aggregate::Sum) => args.extend(["AGGREGATE".to_string(), "SUM".to_string()]),
+        Some(Aggregate::Min) => args.extend(["AGGREGATE".to_string(), "MIN".to_string()]),
+        Some(Aggregate::Max) => args.extend(["AGGREGATE".to_string(), "MAX".to_string()]),
+        None => (),
+    }
+
+    args
+}
 
 /// Defines the redis commands exposed by the redis client.
 impl Client {
@@ -36,7 +99,7 @@ impl Client {
 
     /// See redis [ECHO](https://redis.io/commands/echo) command.
     pub fn echo(&mut self, value: &str) -> RedisStringResult {
-        self.run_command_string_response("ECHO", vec![value])
+        self.run_command::<String>("ECHO", vec![value])
     }
 
     /// See redis [PUBLISH](https://redis.io/commands/publish) command.
@@ -68,7 +131,7 @@ impl Client {
     /// ```
     ///
     pub fn get<T: FromStr>(self: &mut Client, key: &str) -> RedisResult<T> {
-        self.run_command_from_string_response("GET", vec![key])
+        self.run_key_command_from_string_response("GET", vec![key], key, None)
     }
 
     /// See redis [GET](https://redis.io/commands/get) command.<br>
@@ -85,7 +148,7 @@ impl Client {
     /// ```
     ///
     pub fn get_string(self: &mut Client, key: &str) -> RedisStringResult {
-        self.run_command_string_response("GET", vec![key])
+        self.run_key_command_string_response("GET", vec![key], key, None)
     }
 
     /// See redis [SET](https://redis.io/commands/set) command.
@@ -130,12 +193,17 @@ impl Client {
 
     /// See redis [GETSET](https://redis.io/commands/getset) command.
     pub fn getset<T: RedisArg, V: FromStr>(&mut self, key: &str, value: T) -> RedisResult<V> {
-        self.run_command_from_string_response::<V>("GETSET", vec![key, &value.to_string()])
+        self.run_key_command_from_string_response::<V>(
+            "GETSET",
+            vec![key, &value.to_string()],
+            key,
+            None,
+        )
     }
 
     /// See redis [GETSET](https://redis.io/commands/getset) command.
     pub fn getset_string<T: RedisArg>(&mut self, key: &str, value: T) -> RedisStringResult {
-        self.run_command_string_response("GETSET", vec![key, &value.to_string()])
+        self.run_key_command_string_response("GETSET", vec![key, &value.to_string()], key, None)
     }
 
     /// See redis [DEL](https://redis.io/commands/del) command.
@@ -205,12 +273,12 @@ impl Client {
 
     /// See redis [HGET](https://redis.io/commands/hget) command.
     pub fn hget<T: FromStr>(self: &mut Client, key: &str, field: &str) -> RedisResult<T> {
-        self.run_command_from_string_response("HGET", vec![key, field])
+        self.run_key_command_from_string_response("HGET", vec![key, field], key, Some(field))
     }
 
     /// See redis [HGET](https://redis.io/commands/hget) command.
     pub fn hget_string(self: &mut Client, key: &str, field: &str) -> RedisStringResult {
-        self.run_command_string_response("HGET", vec![key, field])
+        self.run_key_command_string_response("HGET", vec![key, field], key, Some(field))
     }
 
     /// See redis [HGETALL](https://redis.io/commands/hgetall) command.
@@ -286,12 +354,17 @@ impl Client {
 
     /// See redis [HGET](https://redis.io/commands/lindex) command.
     pub fn lindex<T: FromStr>(self: &mut Client, key: &str, index: isize) -> RedisResult<T> {
-        self.run_command_from_string_response("LINDEX", vec![key, &index.to_string()])
+        self.run_key_command_from_string_response(
+            "LINDEX",
+            vec![key, &index.to_string()],
+            key,
+            None,
+        )
     }
 
     /// See redis [HGET](https://redis.io/commands/lindex) command.
     pub fn lindex_string(self: &mut Client, key: &str, index: isize) -> RedisStringResult {
-        self.run_command_string_response("LINDEX", vec![key, &index.to_string()])
+        self.run_key_command_string_response("LINDEX", vec![key, &index.to_string()], key, None)
     }
 
     /// See redis [LLEN](https://redis.io/commands/llen) command.
@@ -301,7 +374,7 @@ impl Client {
 
     /// See redis [LPOP](https://redis.io/commands/lpop) command.
     pub fn lpop<T: FromStr>(self: &mut Client, key: &str) -> RedisResult<T> {
-        self.run_command_from_string_response("LPOP", vec![key])
+        self.run_key_command_from_string_response("LPOP", vec![key], key, None)
     }
 
     /// See redis [LPUSH](https://redis.io/commands/lpush) command.
@@ -341,7 +414,7 @@ impl Client {
 
     /// See redis [RPOP](https://redis.io/commands/rpop) command.
     pub fn rpop<T: FromStr>(self: &mut Client, key: &str) -> RedisResult<T> {
-        self.run_command_from_string_response("RPOP", vec![key])
+        self.run_key_command_from_string_response("RPOP", vec![key], key, None)
     }
 
     /// See redis [RPUSH](https://redis.io/commands/rpush) command.
@@ -395,10 +468,110 @@ impl Client {
     }
 
     /// See redis [ZADD](https://redis.io/commands/zadd) command.
-    pub fn zadd(self: &mut Client, key: &str, score: isize, member: &str) -> RedisResult<i32> {
+    pub fn zadd<S: RedisArg>(
+        self: &mut Client,
+        key: &str,
+        score: S,
+        member: &str,
+    ) -> RedisResult<i32> {
         self.run_command("ZADD", vec![key, &score.to_string(), member])
     }
 
+    /// See redis [ZADD](https://redis.io/commands/zadd) command.<br>
+    /// Adds/updates all provided (score, member) pairs based on the provided options.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # let mut client = simple_redis::create("redis://127.0.0.1:6379/").unwrap();
+    /// let mut options = simple_redis::types::ZAddOptions::new();
+    /// options.comparison = Some(simple_redis::types::ZAddComparison::GreaterThan);
+    /// options.changed = true;
+    ///
+    /// match client.zadd_with_options("leaderboard", options, vec![(10.5, "player1"), (7.0, "player2")]) {
+    ///     Ok(changed) => println!("Changed: {}", changed),
+    ///     Err(error) => println!("Unable to update leaderboard: {}", error),
+    /// }
+    /// ```
+    pub fn zadd_with_options<S: RedisArg>(
+        self: &mut Client,
+        key: &str,
+        options: ZAddOptions,
+        members: Vec<(S, &str)>,
+    ) -> RedisResult<i32> {
+        let scores: Vec<String> = members.iter().map(|(score, _)| score.to_string()).collect();
+
+        let mut args = vec![key];
+        args.extend(create_zadd_options_args(&options));
+        for (index, (_, member)) in members.iter().enumerate() {
+            args.push(&scores[index]);
+            args.push(member);
+        }
+
+        self.run_command("ZADD", args)
+    }
+
+    /// See redis [ZADD](https://redis.io/commands/zadd) command (with the INCR option).<br>
+    /// Returns the new member score or None in case the member was not updated due to the provided options.
+    pub fn zadd_incr<S: RedisArg>(
+        self: &mut Client,
+        key: &str,
+        options: ZAddOptions,
+        increment: S,
+        member: &str,
+    ) -> RedisResult<Option<f64>> {
+        let increment_string = increment.to_string();
+
+        let mut args = vec![key];
+        args.extend(create_zadd_options_args(&options));
+        args.push("INCR");
+        args.push(&increment_string);
+        args.push(member);
+
+        self.run_command("ZADD", args)
+    }
+
+    /// See redis [ZCARD](https://redis.io/commands/zcard) command.
+    pub fn zcard(self: &mut Client, key: &str) -> RedisResult<i32> {
+        self.run_command("ZCARD", vec![key])
+    }
+
+    /// See redis [ZCOUNT](https://redis.io/commands/zcount) command.<br>
+    /// The min/max values can be any score, including exclusive (for example `(1.5`) and infinite (`-inf`/`+inf`) values.
+    pub fn zcount<M: RedisArg, N: RedisArg>(
+        self: &mut Client,
+        key: &str,
+        min: M,
+        max: N,
+    ) -> RedisResult<i32> {
+        self.run_command("ZCOUNT", vec![key, &min.to_string(), &max.to_string()])
+    }
+
+    /// See redis [ZINCRBY](https://redis.io/commands/zincrby) command.
+    pub fn zincrby<S: RedisArg>(
+        self: &mut Client,
+        key: &str,
+        increment: S,
+        member: &str,
+    ) -> RedisResult<f64> {
+        self.run_command("ZINCRBY", vec![key, &increment.to_string(), member])
+    }
+
+    /// See redis [ZSCORE](https://redis.io/commands/zscore) command.
+    pub fn zscore(self: &mut Client, key: &str, member: &str) -> RedisResult<Option<f64>> {
+        self.run_command("ZSCORE", vec![key, member])
+    }
+
+    /// See redis [ZRANK](https://redis.io/commands/zrank) command.
+    pub fn zrank(self: &mut Client, key: &str, member: &str) -> RedisResult<Option<i64>> {
+        self.run_command("ZRANK", vec![key, member])
+    }
+
+    /// See redis [ZREVRANK](https://redis.io/commands/zrevrank) command.
+    pub fn zrevrank(self: &mut Client, key: &str, member: &str) -> RedisResult<Option<i64>> {
+        self.run_command("ZREVRANK", vec![key, member])
+    }
+
     /// See redis [ZRANGE](https://redis.io/commands/zrange) command.
     pub fn zrange(
         self: &mut Client,
@@ -408,4 +581,166 @@ impl Client {
     ) -> RedisResult<Vec<String>> {
         self.run_command::<Vec<String>>("ZRANGE", vec![key, &start.to_string(), &stop.to_string()])
     }
+
+    /// See redis [ZRANGE](https://redis.io/commands/zrange) command (with the WITHSCORES option).<br>
+    /// Returns the (member, score) pairs.
+    pub fn zrange_withscores(
+        self: &mut Client,
+        key: &str,
+        start: isize,
+        stop: isize,
+    ) -> RedisResult<Vec<(String, f64)>> {
+        self.run_command(
+            "ZRANGE",
+            vec![key, &start.to_string(), &stop.to_string(), "WITHSCORES"],
+        )
+    }
+
+    /// See redis [ZRANGEBYSCORE](https://redis.io/commands/zrangebyscore) command.<br>
+    /// The min/max values can be any score, including exclusive (for example `(1.5`) and infinite (`-inf`/`+inf`) values.
+    ///
+    /// # Arguments
+    ///
+    /// * `key` - The sorted set key
+    /// * `min` - The min score
+    /// * `max` - The max score
+    /// * `limit` - If provided, the (offset, count) of the returned members
+    pub fn zrangebyscore<M: RedisArg, N: RedisArg>(
+        self: &mut Client,
+        key: &str,
+        min: M,
+        max: N,
+        limit: Option<(isize, isize)>,
+    ) -> RedisResult<Vec<String>> {
+        let min_string = min.to_string();
+        let max_string = max.to_string();
+        let limit_args = create_limit_args(limit);
+
+        let mut args = vec![key, &min_string, &max_string];
+        args.extend(limit_args.iter().map(|arg| arg.as_str()));
+
+        self.run_command("ZRANGEBYSCORE", args)
+    }
+
+    /// See redis [ZRANGEBYLEX](https://redis.io/commands/zrangebylex) command.
+    ///
+    /// # Arguments
+    ///
+    /// * `key` - The sorted set key
+    /// * `min` - The min value, for example: `[a`, `(a` or `-`
+    /// * `max` - The max value, for example: `[z`, `(z` or `+`
+    /// * `limit` - If provided, the (offset, count) of the returned members
+    pub fn zrangebylex(
+        self: &mut Client,
+        key: &str,
+        min: &str,
+        max: &str,
+        limit: Option<(isize, isize)>,
+    ) -> RedisResult<Vec<String>> {
+        let limit_args = create_limit_args(limit);
+
+        let mut args = vec![key, min, max];
+        args.extend(limit_args.iter().map(|arg| arg.as_str()));
+
+        self.run_command("ZRANGEBYLEX", args)
+    }
+
+    /// See redis [ZREM](https://redis.io/commands/zrem) command.
+    pub fn zrem(self: &mut Client, key: &str, member: &str) -> RedisResult<i32> {
+        self.run_command("ZREM", vec![key, member])
+    }
+
+    /// See redis [ZREMRANGEBYSCORE](https://redis.io/commands/zremrangebyscore) command.
+    pub fn zremrangebyscore<M: RedisArg, N: RedisArg>(
+        self: &mut Client,
+        key: &str,
+        min: M,
+        max: N,
+    ) -> RedisResult<i32> {
+        self.run_command(
+            "ZREMRANGEBYSCORE",
+            vec![key, &min.to_string(), &max.to_string()],
+        )
+    }
+
+    /// See redis [ZPOPMIN](https://redis.io/commands/zpopmin) command.<br>
+    /// Returns the removed (member, score) pairs.
+    pub fn zpopmin(self: &mut Client, key: &str, count: usize) -> RedisResult<Vec<(String, f64)>> {
+        self.run_command("ZPOPMIN", vec![key, &count.to_string()])
+    }
+
+    /// See redis [ZPOPMAX](https://redis.io/commands/zpopmax) command.<br>
+    /// Returns the removed (member, score) pairs.
+    pub fn zpopmax(self: &mut Client, key: &str, count: usize) -> RedisResult<Vec<(String, f64)>> {
+        self.run_command("ZPOPMAX", vec![key, &count.to_string()])
+    }
+
+    /// See redis [BZPOPMIN](https://redis.io/commands/bzpopmin) command.<br>
+    /// Returns the (key, member, score) of the removed member or None in case the timeout was reached.<br>
+    /// The timeout should be lower than the client read timeout (if defined).
+    ///
+    /// # Arguments
+    ///
+    /// * `keys` - The sorted set keys to pop from, checked in the provided order
+    /// * `timeout` - Max time to block, zero will block indefinitely
+    pub fn bzpopmin(
+        self: &mut Client,
+        keys: Vec<&str>,
+        timeout: Duration,
+    ) -> RedisResult<Option<(String, String, f64)>> {
+        let timeout_string = timeout.as_secs_f64().to_string();
+
+        let mut args = keys;
+        args.push(&timeout_string);
+
+        self.run_command("BZPOPMIN", args)
+    }
+
+    /// See redis [ZUNIONSTORE](https://redis.io/commands/zunionstore) command.<br>
+    /// Returns the amount of members in the destination sorted set.
+    ///
+    /// # Arguments
+    ///
+    /// * `destination` - The destination sorted set key
+    /// * `keys` - The source sorted set keys
+    /// * `weights` - If provided, the score multiplication factor of each source sorted set
+    /// * `aggregate` - If provided, defines how scores of the same member are combined
+    pub fn zunionstore(
+        self: &mut Client,
+        destination: &str,
+        keys: Vec<&str>,
+        weights: Option<Vec<f64>>,
+        aggregate: Option<Aggregate>,
+    ) -> RedisResult<i32> {
+        let store_args = create_store_args(destination, keys, weights, aggregate);
+
+        self.run_command(
+            "ZUNIONSTORE",
+            store_args.iter().map(|arg| arg.as_str()).collect(),
+        )
+    }
+
+    /// See redis [ZINTERSTORE](https://redis.io/commands/zinterstore) command.<br>
+    /// Returns the amount of members in the destination sorted set.
+    ///
+    /// # Arguments
+    ///
+    /// * `destination` - The destination sorted set key
+    /// * `keys` - The source sorted set keys
+    /// * `weights` - If provided, the score multiplication factor of each source sorted set
+    /// * `aggregate` - If provided, defines how scores of the same member are combined
+    pub fn zinterstore(
+        self: &mut Client,
+        destination: &str,
+        keys: Vec<&str>,
+        weights: Option<Vec<f64>>,
+        aggregate: Option<Aggregate>,
+    ) -> RedisResult<i32> {
+        let store_args = create_store_args(destination, keys, weights, aggregate);
+
+        self.run_command(
+            "ZINTERSTORE",
+            store_args.iter().map(|arg| arg.as_str()).collect(),
+        )
+    }
 }
@@ -1,4 +1,5 @@
 use crate::client;
+use crate::types::{Aggregate, ZAddComparison, ZAddCondition, ZAddOptions};
 
 #[test]
 fn create_invalid_url() {
@@ -39,3 +40,58 @@ fn set_get() {
     let value = client.get_string("set_get").unwrap();
     assert_eq!(value, "my_value");
 }
+
+#[test]
+fn create_zadd_options_args_default() {
+    let args = super::create_zadd_options_args(&ZAddOptions::new());
+
+    assert!(args.is_empty());
+}
+
+#[test]
+fn create_zadd_options_args_all() {
+    let options = ZAddOptions {
+        condition: Some(ZAddCondition::Exists),
+        comparison: Some(ZAddComparison::GreaterThan),
+        changed: true,
+    };
+    let args = super::create_zadd_options_args(&options);
+
+    assert_eq!(args, vec!["XX", "GT", "CH"]);
+}
+
+#[test]
+fn create_limit_args_values() {
+    assert!(super::create_limit_args(None).is_empty());
+    assert_eq!(
+        super::create_limit_args(Some((5, 10))),
+        vec!["LIMIT", "5", "10"]
+    );
+}
+
+#[test]
+fn create_store_args_values() {
+    let mut args = super::create_store_args("dest", vec!["key1", "key2"], None, None);
+    assert_eq!(args, vec!["dest", "2", "key1", "key2"]);
+
+    args = super::create_store_args(
+        "dest",
+        vec!["key1", "key2"],
+        Some(vec![1.5, 2.0]),
+        Some(Aggregate::Max),
+    );
+    assert_eq!(
+        args,
+        vec![
+            "dest",
+            "2",
+            "key1",
+            "key2",
+            "WEIGHTS",
+            "1.5",
+            "2",
+            "AGGREGATE",
+            "MAX"
+        ]
+    );
+}
@@ -7,62 +7,317 @@
 #[path = "./connection_test.rs"]
 mod connection_test;
 
-use crate::types::{RedisEmptyResult, RedisError, RedisResult};
+use crate::sentinel::Sentinel;
+use crate::types::{HealthCheck, ReconnectPolicy, RedisEmptyResult, RedisError, RedisResult};
+use std::collections::hash_map::RandomState;
+use std::hash::{BuildHasher, Hasher};
+use std::thread;
+use std::time::{Duration, Instant};
 
 /// The redis client which enables to invoke redis operations.
 pub(crate) struct Connection {
     /// Holds the current redis connection
-    connection: Option<redis::Connection>,
+    connection: Option<Box<dyn redis::ConnectionLike + Send>>,
+    /// The last time the connection was provided for invoking a command
+    last_used: Option<Instant>,
+    /// Incremented every time a new connection is opened
+    generation: u64,
+    /// If set, cluster connections are opened instead of single server connections
+    #[cfg(feature = "cluster")]
+    cluster: Option<redis::cluster::ClusterClient>,
+}
+
+#[derive(Debug, Clone, Default)]
+/// Holds the options applied whenever a new connection is opened and maintained.
+pub(crate) struct ConnectionOptions {
+    /// Max time to wait while connecting to the server
+    pub(crate) connect_timeout: Option<Duration>,
+    /// Max time to wait for a command response
+    pub(crate) read_timeout: Option<Duration>,
+    /// Max time to wait while sending a command
+    pub(crate) write_timeout: Option<Duration>,
+    /// The client name set via CLIENT SETNAME on every new connection
+    pub(crate) client_name: Option<String>,
+    /// Defines how to reopen the connection
+    pub(crate) reconnect_policy: ReconnectPolicy,
+    /// Defines when to verify the connection before using it
+    pub(crate) health_check: HealthCheck,
+}
+
+#[derive(Debug, Clone)]
+/// Defines the redis server to which new connections are opened.
+pub(crate) enum Server {
+    /// A redis server with a fixed address
+    Fixed(redis::Client),
+    /// The current primary server of a sentinel managed master, resolved whenever a new connection is opened
+    Sentinel(Sentinel),
+}
+
+impl Server {
+    /// Returns true if the server address is resolved whenever a new connection is opened.
+    pub(crate) fn is_resolved(self: &Server) -> bool {
+        matches!(self, Server::Sentinel(_))
+    }
+}
+
+/// Opens a new redis connection to the provided server and applies all the provided options on it.
+pub(crate) fn open(
+    server: &Server,
+    options: &ConnectionOptions,
+) -> redis::RedisResult<redis::Connection> {
+    match server {
+        Server::Fixed(ref client) => open_client(client, options),
+        Server::Sentinel(ref sentinel) => {
+            let client = sentinel.get_primary_client(options.connect_timeout)?;
+            open_client(&client, options)
+        }
+    }
+}
+
+fn open_client(
+    client: &redis::Client,
+    options: &ConnectionOptions,
+) -> redis::RedisResult<redis::Connection> {
+    let mut redis_connection = match options.connect_timeout {
+        Some(timeout) => client.get_connection_with_timeout(timeout)?,
+        None => client.get_connection()?,
+    };
+
+    redis_connection.set_read_timeout(options.read_timeout)?;
+    redis_connection.set_write_timeout(options.write_timeout)?;
+
+    if let Some(ref client_name) = options.client_name {
+        redis::cmd("CLIENT")
+            .arg("SETNAME")
+            .arg(client_name)
+            .query::<()>(&mut redis_connection)?;
+    }
+
+    Ok(redis_connection)
+}
+
+/// Opens a new redis cluster connection and applies the timeout options on it.<br>
+/// The connect timeout and credentials are defined when creating the cluster client.
+#[cfg(feature = "cluster")]
+pub(crate) fn open_cluster(
+    client: &redis::cluster::ClusterClient,
+    options: &ConnectionOptions,
+) -> redis::RedisResult<redis::cluster::ClusterConnection> {
+    let redis_connection = client.get_connection()?;
+
+    redis_connection.set_read_timeout(options.read_timeout)?;
+    redis_connection.set_write_timeout(options.write_timeout)?;
+
+    Ok(redis_connection)
+}
+
+/// Returns true if the connection should be verified (via PING) based on the health check policy.
+///
+/// # Arguments
+///
+/// * `health_check` - The health check policy
+/// * `last_used` - The last time the connection was used
+pub(crate) fn should_verify(health_check: HealthCheck, last_used: Option<Instant>) -> bool {
+    match health_check {
+        HealthCheck::Always => true,
+        HealthCheck::Idle(idle_time) => match last_used {
+            Some(last_used) => last_used.elapsed() >= idle_time,
+            None => true,
+        },
+        HealthCheck::Never => false,
+    }
+}
+
+/// Returns true if the provided ROLE response belongs to a primary server.
+pub(crate) fn is_primary_role(value: &redis::Value) -> bool {
+    match value {
+        redis::Value::Array(values) => match values.first() {
+            Some(redis::Value::BulkString(role)) => role == b"master",
+            Some(redis::Value::SimpleString(role)) => role == "master",
+            _ => false,
+        },
+        _ => false,
+    }
+}
+
+/// Returns the delay before the next connection attempt or None if no more attempts
+/// should be made.
+///
+/// # Arguments
+///
+/// * `policy` - The reconnect policy
+/// * `attempt` - The amount of connection attempts made so far
+/// * `elapsed` - The time passed since the first connection attempt
+pub(crate) fn get_reconnect_delay(
+    policy: &ReconnectPolicy,
+    attempt: u32,
+    elapsed: Duration,
+) -> Option<Duration> {
+    if attempt == 0 || attempt >= policy.max_attempts {
+        return None;
+    }
+
+    let factor = policy.multiplier.max(1.0).powi(attempt as i32 - 1);
+    let max_delay_secs = policy.max_delay.as_secs_f64();
+    let delay_secs = (policy.initial_delay.as_secs_f64() * factor).min(max_delay_secs);
+    let mut delay = Duration::from_secs_f64(delay_secs);
+
+    if policy.jitter {
+        delay = add_jitter(delay);
+    }
+
+    match policy.max_elapsed_time {
+        Some(max_elapsed_time) if elapsed + delay > max_elapsed_time => None,
+        _ => Some(delay),
+    }
+}
+
+/// Returns a random delay between half and the full provided delay.
+fn add_jitter(delay: Duration) -> Duration {
+    let random = RandomState::new().build_hasher().finish();
+    let ratio = 0.5 + (random % 1000) as f64 / 2000.0;
+
+    delay.mul_f64(ratio)
 }
 
 /// If the client connection is not open or not valid, this function will create
-/// a new redis connection and modify the client to store this new connection.
-fn open_connection(connection: &mut Connection, client: &redis::Client) -> RedisEmptyResult {
-    let output: RedisEmptyResult;
+/// a new redis connection and modify the client to store this new connection.<br>
+/// Failed connection attempts are retried based on the connection reconnect policy.
+fn open_connection(
+    connection: &mut Connection,
+    server: &Server,
+    options: &ConnectionOptions,
+) -> RedisEmptyResult {
+    if connection.is_connection_valid(options.health_check, server.is_resolved()) {
+        return Ok(());
+    }
 
-    if !connection.is_connection_open() {
-        output = match client.get_connection() {
+    let start_time = Instant::now();
+    let mut attempt = 0;
+    loop {
+        match connection.open(server, options) {
             Ok(redis_connection) => {
                 connection.connection = Some(redis_connection);
-                Ok(())
+                connection.generation += 1;
+                return Ok(());
+            }
+            Err(error) => {
+                attempt += 1;
+
+                match get_reconnect_delay(&options.reconnect_policy, attempt, start_time.elapsed())
+                {
+                    Some(delay) => thread::sleep(delay),
+                    None => return Err(error.into()),
+                }
             }
-            Err(error) => Err(RedisError::RedisError(error)),
         }
-    } else {
-        output = Ok(());
     }
-
-    output
 }
 
 impl Connection {
+    /// Opens a new connection to the redis server or cluster.
+    fn open(
+        self: &Connection,
+        server: &Server,
+        options: &ConnectionOptions,
+    ) -> redis::RedisResult<Box<dyn redis::ConnectionLike + Send>> {
+        #[cfg(feature = "cluster")]
+        if let Some(ref cluster) = self.cluster {
+            let redis_connection = open_cluster(cluster, options)?;
+            return Ok(Box::new(redis_connection));
+        }
+
+        let redis_connection = open(server, options)?;
+        Ok(Box::new(redis_connection))
+    }
+
     /// Returns true if the currently stored connection is valid, otherwise false.<br>
     /// There is no need to call this function as any redis operation invocation will
     /// ensure a valid connection is created.
     pub(crate) fn is_connection_open(self: &mut Connection) -> bool {
-        let open;
+        match self.connection {
+            Some(ref mut redis_connection) => {
+                let result: redis::RedisResult<()> =
+                    redis::cmd("PING").query(redis_connection.as_mut());
+
+                result.is_ok()
+            }
+            None => false,
+        }
+    }
 
+    /// Returns true if the current connection is open to a primary server (verified via ROLE).
+    fn is_primary_connection(self: &mut Connection) -> bool {
         match self.connection {
             Some(ref mut redis_connection) => {
-                let result: redis::RedisResult<()> = redis::cmd("PING").query(redis_connection);
+                let result: redis::RedisResult<redis::Value> =
+                    redis::cmd("ROLE").query(redis_connection.as_mut());
 
-                open = result.is_ok();
+                match result {
+                    Ok(value) => is_primary_role(&value),
+                    Err(_) => false,
+                }
             }
-            None => open = false,
+            None => false,
         }
+    }
 
-        open
+    /// Returns true if the current connection can be used, verifying it only if required
+    /// by the health check policy.<br>
+    /// In case the primary should be verified, the connection is only valid while the server is still the primary,
+    /// so a primary demoted by a failover is replaced by a connection to the newly resolved primary.
+    fn is_connection_valid(
+        self: &mut Connection,
+        health_check: HealthCheck,
+        verify_primary: bool,
+    ) -> bool {
+        let open = match self.connection {
+            Some(ref redis_connection) => redis_connection.is_open(),
+            None => false,
+        };
+
+        if open && should_verify(health_check, self.last_used) {
+            if verify_primary {
+                self.is_primary_connection()
+            } else {
+                self.is_connection_open()
+            }
+        } else {
+            open
+        }
+    }
+
+    /// Drops the current connection (if any), the next operation will open a new one.
+    pub(crate) fn close(self: &mut Connection) {
+        self.connection = None;
+        self.last_used = None;
     }
 
     pub(crate) fn get_redis_connection(
         self: &mut Connection,
-        client: &redis::Client,
-    ) -> RedisResult<&mut redis::Connection> {
-        match open_connection(self, client) {
+        server: &Server,
+        options: &ConnectionOptions,
+    ) -> RedisResult<&mut dyn redis::ConnectionLike> {
+        match self.get_redis_connection_with_generation(server, options) {
+            Ok((redis_connection, _)) => Ok(redis_connection),
+            Err(error) => Err(error),
+        }
+    }
+
+    /// Returns a valid connection and a number identifying it, which changes whenever a new connection is opened.
+    pub(crate) fn get_redis_connection_with_generation(
+        self: &mut Connection,
+        server: &Server,
+        options: &ConnectionOptions,
+    ) -> RedisResult<(&mut dyn redis::ConnectionLike, u64)> {
+        match open_connection(self, server, options) {
             Err(error) => Err(error),
             _ => match self.connection {
-                Some(ref mut redis_connection) => Ok(redis_connection),
-                None => Err(RedisError::Description("Redis connection not available.")),
+                Some(ref mut redis_connection) => {
+                    self.last_used = Some(Instant::now());
+                    Ok((redis_connection.as_mut(), self.generation))
+                }
+                None => Err(RedisError::NotConnected(None)),
             },
         }
     }
@@ -70,5 +325,20 @@ impl Connection {
 
 /// Creates and returns a new connection
 pub(crate) fn create() -> Connection {
-    Connection { connection: None }
+    Connection {
+        connection: None,
+        last_used: None,
+        generation: 0,
+        #[cfg(feature = "cluster")]
+        cluster: None,
+    }
+}
+
+/// Creates and returns a new connection which opens cluster connections via the provided cluster client
+#[cfg(feature = "cluster")]
+pub(crate) fn create_cluster(cluster: redis::cluster::ClusterClient) -> Connection {
+    Connection {
+        cluster: Some(cluster),
+        ..create()
+    }
 }
@@ -4,4 +4,137 @@ use super::*;
 fn create_check_state() {
     let mut connection = create();
     assert!(!connection.is_connection_open());
+    assert!(connection.last_used.is_none());
+}
+
+#[test]
+fn should_verify_always() {
+    assert!(should_verify(HealthCheck::Always, None));
+    assert!(should_verify(HealthCheck::Always, Some(Instant::now())));
+}
+
+#[test]
+fn should_verify_never() {
+    assert!(!should_verify(HealthCheck::Never, None));
+    assert!(!should_verify(HealthCheck::Never, Some(Instant::now())));
+}
+
+#[test]
+fn should_verify_idle() {
+    let health_check = HealthCheck::Idle(Duration::from_secs(60));
+
+    assert!(should_verify(health_check, None));
+    assert!(!should_verify(health_check, Some(Instant::now())));
+    assert!(should_verify(
+        HealthCheck::Idle(Duration::from_millis(0)),
+        Some(Instant::now())
+    ));
+}
+
+#[test]
+fn is_primary_role_master() {
+    let value = redis::Value::Array(vec![
+        redis::Value::BulkString(b"master".to_vec()),
+        redis::Value::Int(0),
+        redis::Value::Array(vec![]),
+    ]);
+
+    assert!(is_primary_role(&value));
+}
+
+#[test]
+fn is_primary_role_replica() {
+    let value = redis::Value::Array(vec![
+        redis::Value::BulkString(b"slave".to_vec()),
+        redis::Value::BulkString(b"127.0.0.1".to_vec()),
+        redis::Value::Int(6379),
+        redis::Value::BulkString(b"connected".to_vec()),
+        redis::Value::Int(0),
+    ]);
+
+    assert!(!is_primary_role(&value));
+    assert!(!is_primary_role(&redis::Value::Nil));
+}
+
+#[test]
+fn get_reconnect_delay_no_retries() {
+    let policy = ReconnectPolicy::new();
+
+    let delay = get_reconnect_delay(&policy, 1, Duration::from_millis(0));
+    assert!(delay.is_none());
+}
+
+#[test]
+fn get_reconnect_delay_exponential() {
+    let mut policy = ReconnectPolicy::new();
+    policy.max_attempts = 5;
+    policy.jitter = false;
+
+    let mut delay = get_reconnect_delay(&policy, 1, Duration::from_millis(0));
+    assert_eq!(delay.unwrap(), Duration::from_millis(100));
+    delay = get_reconnect_delay(&policy, 2, Duration::from_millis(0));
+    assert_eq!(delay.unwrap(), Duration::from_millis(200));
+    delay = get_reconnect_delay(&policy, 4, Duration::from_millis(0));
+    assert_eq!(delay.unwrap(), Duration::from_millis(800));
+    delay = get_reconnect_delay(&policy, 5, Duration::from_millis(0));
+    assert!(delay.is_none());
+}
+
+#[test]
+fn get_reconnect_delay_max_delay() {
+    let mut policy = ReconnectPolicy::new();
+    policy.max_attempts = 50;
+    policy.jitter = false;
+    policy.max_delay = Duration::from_secs(1);
+
+    let delay = get_reconnect_delay(&policy, 20, Duration::from_millis(0));
+    assert_eq!(delay.unwrap(), Duration::from_secs(1));
+}
+
+#[test]
+fn get_reconnect_delay_jitter() {
+    let mut policy = ReconnectPolicy::new();
+    policy.max_attempts = 5;
+
+    for _ in 0..100 {
+        let delay = get_reconnect_delay(&policy, 2, Duration::from_millis(0)).unwrap();
+        assert!(delay >= Duration::from_millis(100));
+        assert!(delay <= Duration::from_millis(200));
+    }
+}
+
+#[test]
+fn get_reconnect_delay_max_elapsed_time() {
+    let mut policy = ReconnectPolicy::new();
+    policy.max_attempts = 5;
+    policy.jitter = false;
+    policy.max_elapsed_time = Some(Duration::from_secs(1));
+
+    let mut delay = get_reconnect_delay(&policy, 1, Duration::from_millis(500));
+    assert!(delay.is_some());
+    delay = get_reconnect_delay(&policy, 1, Duration::from_millis(950));
+    assert!(delay.is_none());
+}
+
+#[test]
+fn get_redis_connection_refused() {
+    let server = Server::Fixed(redis::Client::open("redis://127.0.0.1:1/").unwrap());
+    let mut connection = create();
+    let mut options = ConnectionOptions::default();
+    options.reconnect_policy.max_attempts = 3;
+    options.reconnect_policy.initial_delay = Duration::from_millis(10);
+
+    let result = connection.get_redis_connection(&server, &options);
+    assert!(result.is_err());
+}
+
+#[test]
+#[cfg(unix)]
+fn get_redis_connection_unix_socket_not_found() {
+    let server =
+        Server::Fixed(redis::Client::open("redis+unix:///tmp/simple_redis_missing.sock").unwrap());
+    let mut connection = create();
+
+    let result = connection.get_redis_connection(&server, &ConnectionOptions::default());
+    assert!(result.is_err());
 }
@@ -0,0 +1,305 @@
+//! # consumer
+//!
+//! Implements a stream consumer group worker loop with pending entries recovery.
+//!
+
+#[cfg(test)]
+#[path = "./consumer_test.rs"]
+mod consumer_test;
+
+use crate::client::Client;
+use crate::streams;
+use crate::types::{
+    Interrupts, RedisEmptyResult, RedisResult, StreamConsumerOptions, StreamEntry,
+    StreamEntryStatus,
+};
+use std::time::{Duration, Instant};
+
+/// Reads entries of a single stream as part of a consumer group and invokes a handler for each entry.<br>
+/// Handled entries are acknowledged, while failed entries are left pending and delivered again to this
+/// consumer after the retry interval.<br>
+/// Entries which are pending for too long on other (possibly dead) consumers are periodically claimed.
+#[derive(Debug, Clone)]
+pub struct StreamConsumer {
+    /// The stream key
+    key: String,
+    /// The consumer group name
+    group: String,
+    /// The consumer name
+    consumer: String,
+    /// The consumer behaviour options
+    options: StreamConsumerOptions,
+    /// The consumer state
+    state: ConsumerState,
+}
+
+#[derive(Debug, Clone)]
+struct ConsumerState {
+    /// True once the consumer group was created (or found to exist)
+    group_ready: bool,
+    /// True while pending entries of this consumer should be delivered again
+    pending: bool,
+    /// The last pending entry ID read in the current pending entries scan
+    pending_id: String,
+    /// True if an entry handling failed since the current pending entries scan started
+    failed: bool,
+    /// If set, the next pending entries scan will not start before this time
+    retry_after: Option<Instant>,
+    /// The next XAUTOCLAIM scan start ID
+    claim_id: String,
+    /// Set on each claim attempt
+    last_claim: Option<Instant>,
+}
+
+impl Default for ConsumerState {
+    fn default() -> Self {
+        ConsumerState {
+            group_ready: false,
+            // entries left pending by a previous run of this consumer are delivered first
+            pending: true,
+            pending_id: "0".to_string(),
+            failed: false,
+            retry_after: None,
+            claim_id: "0-0".to_string(),
+            last_claim: None,
+        }
+    }
+}
+
+fn is_due(last: Option<Instant>, interval: Duration) -> bool {
+    match last {
+        Some(last) => last.elapsed() >= interval,
+        None => true,
+    }
+}
+
+fn is_reached(time: Option<Instant>) -> bool {
+    match time {
+        Some(time) => Instant::now() >= time,
+        None => true,
+    }
+}
+
+/// Returns the max time to wait for new entries, which ends before the next scheduled retry (if any).
+fn get_block_time(
+    options: &StreamConsumerOptions,
+    interrupts: &Interrupts,
+    retry_after: Option<Instant>,
+) -> Duration {
+    let block = match interrupts.next_polling_time {
+        Some(millis) => Duration::from_millis(millis),
+        None => options.block,
+    };
+
+    match retry_after {
+        // a zero block time blocks forever, so at least a single millisecond is used
+        Some(retry_after) => block.min(
+            retry_after
+                .saturating_duration_since(Instant::now())
+                .max(Duration::from_millis(1)),
+        ),
+        None => block,
+    }
+}
+
+fn get_entries(streams: Vec<(String, Vec<StreamEntry>)>) -> Vec<StreamEntry> {
+    streams
+        .into_iter()
+        .flat_map(|(_, entries)| entries)
+        .collect()
+}
+
+impl StreamConsumer {
+    /// Returns a new stream consumer.
+    ///
+    /// # Arguments
+    ///
+    /// * `key` - The stream key
+    /// * `group` - The consumer group name
+    /// * `consumer` - The consumer name, which should be unique within the group
+    /// * `options` - The consumer behaviour options
+    pub fn new(
+        key: &str,
+        group: &str,
+        consumer: &str,
+        options: StreamConsumerOptions,
+    ) -> StreamConsumer {
+        StreamConsumer {
+            key: key.to_string(),
+            group: group.to_string(),
+            consumer: consumer.to_string(),
+            options,
+            state: ConsumerState::default(),
+        }
+    }
+
+    /// Reads the stream entries and invokes the provided on_entry handler for each entry.<br>
+    /// This function will block and continue to read entries, until the interrupts stop flag is set.
+    ///
+    /// # Arguments
+    ///
+    /// * `client` - The client used to read and acknowledge the entries
+    /// * `on_entry` - Invoked on each read entry and returns the handling status
+    /// * `poll_interrupts` - Returns the interrupts struct, enabling to stop the consumer and modify the max block time.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// # use simple_redis::types::{StreamConsumerOptions, StreamEntry, StreamEntryStatus};
+    /// # use simple_redis::Interrupts;
+    /// # let mut client = simple_redis::create("redis://127.0.0.1:6379/").unwrap();
+    /// let mut consumer = simple_redis::StreamConsumer::new(
+    ///     "events",
+    ///     "workers",
+    ///     "worker1",
+    ///     StreamConsumerOptions::new(),
+    /// );
+    ///
+    /// consumer.run(
+    ///     &mut client,
+    ///     &mut |entry: &StreamEntry| -> StreamEntryStatus {
+    ///         println!("Got entry: {}", entry.id);
+    ///
+    ///         StreamEntryStatus::Processed
+    ///     },
+    ///     &mut || -> Interrupts { Interrupts::new() },
+    /// ).unwrap();
+    /// ```
+    pub fn run(
+        self: &mut StreamConsumer,
+        client: &mut Client,
+        on_entry: &mut dyn FnMut(&StreamEntry) -> StreamEntryStatus,
+        poll_interrupts: &mut dyn FnMut() -> Interrupts,
+    ) -> RedisEmptyResult {
+        self.create_group(client)?;
+
+        loop {
+            let interrupts = poll_interrupts();
+            if interrupts.stop {
+                return Ok(());
+            }
+
+            let entries = self.read_entries(client, &interrupts)?;
+
+            let mut acknowledged = vec![];
+            for entry in entries {
+                // deleted entries have no fields and are only removed from the pending list
+                let status = if entry.fields.is_empty() {
+                    StreamEntryStatus::Processed
+                } else {
+                    on_entry(&entry)
+                };
+
+                match status {
+                    StreamEntryStatus::Processed => acknowledged.push(entry.id),
+                    StreamEntryStatus::Failed => self.on_failure(),
+                }
+            }
+
+            if !acknowledged.is_empty() {
+                client.xack(
+                    &self.key,
+                    &self.group,
+                    acknowledged.iter().map(|id| id.as_str()).collect(),
+                )?;
+            }
+        }
+    }
+
+    fn create_group(self: &mut StreamConsumer, client: &mut Client) -> RedisEmptyResult {
+        if self.options.create_group && !self.state.group_ready {
+            match client.xgroup_create(&self.key, &self.group, "$", true) {
+                Err(ref error) if streams::is_busy_group_error(error) => (),
+                Err(error) => return Err(error),
+                Ok(_) => (),
+            }
+            self.state.group_ready = true;
+        }
+
+        Ok(())
+    }
+
+    fn on_failure(self: &mut StreamConsumer) {
+        self.state.failed = true;
+
+        if !self.state.pending {
+            self.state.pending = true;
+            self.state.pending_id = "0".to_string();
+            self.state.retry_after = Some(Instant::now() + self.options.retry_interval);
+        }
+    }
+
+    /// Returns the next entries to handle, which are either claimed entries, entries pending on this
+    /// consumer or new entries.
+    fn read_entries(
+        self: &mut StreamConsumer,
+        client: &mut Client,
+        interrupts: &Interrupts,
+    ) -> RedisResult<Vec<StreamEntry>> {
+        if let Some(min_idle) = self.options.claim_min_idle {
+            if is_due(self.state.last_claim, self.options.claim_interval) {
+                self.state.last_claim = Some(Instant::now());
+
+                let claimed = client.xautoclaim(
+                    &self.key,
+                    &self.group,
+                    &self.consumer,
+                    min_idle,
+                    &self.state.claim_id,
+                    Some(self.options.count),
+                )?;
+                self.state.claim_id = claimed.next_id;
+
+                if !claimed.entries.is_empty() {
+                    return Ok(claimed.entries);
+                }
+            }
+        }
+
+        if self.state.pending && is_reached(self.state.retry_after) {
+            let streams = client.xreadgroup(
+                &self.group,
+                &self.consumer,
+                vec![(&self.key, &self.state.pending_id)],
+                Some(self.options.count),
+                None,
+                false,
+            )?;
+            let entries = get_entries(streams);
+
+            match entries.last() {
+                Some(entry) => {
+                    self.state.pending_id = entry.id.clone();
+                    return Ok(entries);
+                }
+                None => {
+                    // the scan is done, failed entries (if any) will be scanned again after the retry interval
+                    self.state.pending_id = "0".to_string();
+                    self.state.pending = self.state.failed;
+                    self.state.failed = false;
+                    self.state.retry_after = if self.state.pending {
+                        Some(Instant::now() + self.options.retry_interval)
+                    } else {
+                        None
+                    };
+                }
+            }
+        }
+
+        let retry_after = if self.state.pending {
+            self.state.retry_after
+        } else {
+            None
+        };
+        let streams = client.xreadgroup(
+            &self.group,
+            &self.consumer,
+            vec![(&self.key, ">")],
+            Some(self.options.count),
+            Some(get_block_time(&self.options, interrupts, retry_after)),
+            false,
+        )?;
+
+        Ok(get_entries(streams))
+    }
+}
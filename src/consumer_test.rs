@@ -0,0 +1,111 @@
+use super::*;
+use std::collections::HashMap;
+
+fn create_entry(id: &str) -> StreamEntry {
+    StreamEntry {
+        id: id.to_string(),
+        fields: HashMap::new(),
+    }
+}
+
+#[test]
+fn new_state() {
+    let consumer = StreamConsumer::new("key", "group", "consumer", StreamConsumerOptions::new());
+
+    assert_eq!(consumer.key, "key");
+    assert_eq!(consumer.group, "group");
+    assert_eq!(consumer.consumer, "consumer");
+    assert!(!consumer.state.group_ready);
+    assert!(consumer.state.pending);
+    assert_eq!(consumer.state.pending_id, "0");
+    assert!(consumer.state.retry_after.is_none());
+    assert_eq!(consumer.state.claim_id, "0-0");
+}
+
+#[test]
+fn on_failure_schedules_retry() {
+    let mut consumer =
+        StreamConsumer::new("key", "group", "consumer", StreamConsumerOptions::new());
+    consumer.state.pending = false;
+    consumer.state.pending_id = "5-0".to_string();
+
+    consumer.on_failure();
+
+    assert!(consumer.state.pending);
+    assert!(consumer.state.failed);
+    assert_eq!(consumer.state.pending_id, "0");
+    assert!(!is_reached(consumer.state.retry_after));
+}
+
+#[test]
+fn on_failure_while_pending() {
+    let mut consumer =
+        StreamConsumer::new("key", "group", "consumer", StreamConsumerOptions::new());
+    consumer.state.pending_id = "5-0".to_string();
+
+    consumer.on_failure();
+
+    assert!(consumer.state.pending);
+    assert!(consumer.state.failed);
+    assert_eq!(consumer.state.pending_id, "5-0");
+    assert!(consumer.state.retry_after.is_none());
+}
+
+#[test]
+fn is_due_values() {
+    assert!(is_due(None, Duration::from_secs(10)));
+    assert!(!is_due(Some(Instant::now()), Duration::from_secs(10)));
+    assert!(is_due(Some(Instant::now()), Duration::from_secs(0)));
+}
+
+#[test]
+fn get_block_time_values() {
+    let options = StreamConsumerOptions::new();
+    let mut interrupts = Interrupts::new();
+
+    assert_eq!(get_block_time(&options, &interrupts, None), options.block);
+
+    interrupts.next_polling_time = Some(10);
+    assert_eq!(
+        get_block_time(&options, &interrupts, None),
+        Duration::from_millis(10)
+    );
+}
+
+#[test]
+fn get_block_time_retry_scheduled() {
+    let options = StreamConsumerOptions::new();
+    let interrupts = Interrupts::new();
+
+    let block_time = get_block_time(
+        &options,
+        &interrupts,
+        Some(Instant::now() + Duration::from_millis(100)),
+    );
+    assert!(block_time <= Duration::from_millis(100));
+    assert!(block_time > Duration::from_millis(0));
+
+    let block_time = get_block_time(&options, &interrupts, Some(Instant::now()));
+    assert_eq!(block_time, Duration::from_millis(1));
+
+    let block_time = get_block_time(
+        &options,
+        &interrupts,
+        Some(Instant::now() + Duration::from_secs(60)),
+    );
+    assert_eq!(block_time, options.block);
+}
+
+#[test]
+fn get_entries_flatten() {
+    let entries = get_entries(vec![
+        (
+            "key1".to_string(),
+            vec![create_entry("1-0"), create_entry("2-0")],
+        ),
+        ("key2".to_string(), vec![create_entry("3-0")]),
+    ]);
+
+    let ids: Vec<String> = entries.into_iter().map(|entry| entry.id).collect();
+    assert_eq!(ids, vec!["1-0", "2-0", "3-0"]);
+}
@@ -0,0 +1,224 @@
+//! # functions
+//!
+//! Defines the redis functions (FUNCTION/FCALL) commands exposed by the redis client.
+//!
+
+#[cfg(test)]
+#[path = "./functions_test.rs"]
+mod functions_test;
+
+use crate::client::Client;
+use crate::pipeline::convert_value;
+use crate::script::create_eval_args;
+use crate::types::{
+    from_redis_error, FunctionInfo, FunctionLibrary, FunctionRestorePolicy, RedisEmptyResult,
+    RedisError, RedisResult, RedisStringResult,
+};
+
+/// Returns the key/value pairs of the provided value, which is either a flat array or a map.
+fn get_pairs(value: redis::Value) -> RedisResult<Vec<(String, redis::Value)>> {
+    let pairs = match value {
+        redis::Value::Map(pairs) => pairs,
+        redis::Value::Array(values) => {
+            let mut pairs = vec![];
+            let mut iterator = values.into_iter();
+            while let Some(key) = iterator.next() {
+                match iterator.next() {
+                    Some(value) => pairs.push((key, value)),
+                    None => {
+                        return Err(RedisError::UnexpectedResponse(
+                            "Unexpected function response.",
+                        ))
+                    }
+                }
+            }
+
+            pairs
+        }
+        _ => {
+            return Err(RedisError::UnexpectedResponse(
+                "Unexpected function response.",
+            ))
+        }
+    };
+
+    let mut output = vec![];
+    for (key, value) in pairs {
+        match redis::from_owned_redis_value::<String>(key) {
+            Ok(key) => output.push((key, value)),
+            Err(error) => return Err(from_redis_error::<String>(error)),
+        }
+    }
+
+    Ok(output)
+}
+
+fn parse_function_info(value: redis::Value) -> RedisResult<FunctionInfo> {
+    let mut function_info = FunctionInfo::default();
+
+    for (key, value) in get_pairs(value)? {
+        match key.as_str() {
+            "name" => function_info.name = convert_value(value)?,
+            "description" => function_info.description = convert_value(value)?,
+            "flags" => function_info.flags = convert_value(value)?,
+            _ => (),
+        }
+    }
+
+    Ok(function_info)
+}
+
+/// Converts a single library of the FUNCTION LIST response to the library metadata.
+pub(crate) fn parse_function_library(value: redis::Value) -> RedisResult<FunctionLibrary> {
+    let mut library = FunctionLibrary::default();
+
+    for (key, value) in get_pairs(value)? {
+        match key.as_str() {
+            "library_name" => library.name = convert_value(value)?,
+            "engine" => library.engine = convert_value(value)?,
+            "library_code" => library.code = convert_value(value)?,
+            "functions" => {
+                let values: Vec<redis::Value> = convert_value(value)?;
+
+                for function_value in values {
+                    library.functions.push(parse_function_info(function_value)?);
+                }
+            }
+            _ => (),
+        }
+    }
+
+    Ok(library)
+}
+
+impl Client {
+    /// See redis [FUNCTION LOAD](https://redis.io/commands/function-load) command.<br>
+    /// Returns the loaded library name.
+    ///
+    /// # Arguments
+    ///
+    /// * `code` - The library source code
+    /// * `replace` - If true, an existing library with the same name is replaced
+    pub fn function_load(self: &mut Client, code: &str, replace: bool) -> RedisStringResult {
+        let args = if replace {
+            vec!["LOAD", "REPLACE", code]
+        } else {
+            vec!["LOAD", code]
+        };
+
+        self.run_command::<String>("FUNCTION", args)
+    }
+
+    /// See redis [FUNCTION LIST](https://redis.io/commands/function-list) command.
+    ///
+    /// # Arguments
+    ///
+    /// * `library_pattern` - If provided, only libraries with a matching name are returned
+    /// * `with_code` - If true, the library source code is returned as well
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # let mut client = simple_redis::create("redis://127.0.0.1:6379/").unwrap();
+    /// match client.function_list(None, false) {
+    ///     Ok(libraries) => {
+    ///         for library in libraries {
+    ///             println!("Library: {} with {} functions", library.name, library.functions.len());
+    ///         }
+    ///     }
+    ///     Err(error) => println!("Unable to list functions: {}", error),
+    /// }
+    /// ```
+    pub fn function_list(
+        self: &mut Client,
+        library_pattern: Option<&str>,
+        with_code: bool,
+    ) -> RedisResult<Vec<FunctionLibrary>> {
+        let mut args = vec!["LIST"];
+        if let Some(pattern) = library_pattern {
+            args.push("LIBRARYNAME");
+            args.push(pattern);
+        }
+        if with_code {
+            args.push("WITHCODE");
+        }
+
+        let values = self.run_command::<Vec<redis::Value>>("FUNCTION", args)?;
+
+        let mut libraries = vec![];
+        for value in values {
+            libraries.push(parse_function_library(value)?);
+        }
+
+        Ok(libraries)
+    }
+
+    /// See redis [FUNCTION DELETE](https://redis.io/commands/function-delete) command.
+    pub fn function_delete(self: &mut Client, library: &str) -> RedisEmptyResult {
+        self.run_command_empty_response("FUNCTION", vec!["DELETE", library])
+    }
+
+    /// See redis [FUNCTION FLUSH](https://redis.io/commands/function-flush) command.
+    pub fn function_flush(self: &mut Client) -> RedisEmptyResult {
+        self.run_command_empty_response("FUNCTION", vec!["FLUSH"])
+    }
+
+    /// See redis [FUNCTION DUMP](https://redis.io/commands/function-dump) command.<br>
+    /// Returns the serialized payload of all libraries, which can be restored via function_restore.
+    pub fn function_dump(self: &mut Client) -> RedisResult<Vec<u8>> {
+        self.run_command::<Vec<u8>>("FUNCTION", vec!["DUMP"])
+    }
+
+    /// See redis [FUNCTION RESTORE](https://redis.io/commands/function-restore) command.
+    ///
+    /// # Arguments
+    ///
+    /// * `payload` - The serialized payload returned by function_dump
+    /// * `policy` - Defines how existing libraries are handled (default is append)
+    pub fn function_restore(
+        self: &mut Client,
+        payload: &[u8],
+        policy: Option<FunctionRestorePolicy>,
+    ) -> RedisEmptyResult {
+        let mut cmd = redis::cmd("FUNCTION");
+        cmd.arg("RESTORE").arg(payload);
+        match policy {
+            Some(FunctionRestorePolicy::Append) => cmd.arg("APPEND"),
+            Some(FunctionRestorePolicy::Replace) => cmd.arg("REPLACE"),
+            Some(FunctionRestorePolicy::Flush) => cmd.arg("FLUSH"),
+            None => &mut cmd,
+        };
+
+        self.run_on_connection(false, |connection| match cmd.query(connection) {
+            Ok(output) => Ok(output),
+            Err(error) => Err(error.into()),
+        })
+    }
+
+    /// See redis [FCALL](https://redis.io/commands/fcall) command.
+    pub fn fcall<T: redis::FromRedisValue>(
+        self: &mut Client,
+        function: &str,
+        keys: Vec<&str>,
+        args: Vec<&str>,
+    ) -> RedisResult<T> {
+        let fcall_args = create_eval_args(function, keys, args);
+
+        self.run_command("FCALL", fcall_args.iter().map(|arg| arg.as_str()).collect())
+    }
+
+    /// See redis [FCALL_RO](https://redis.io/commands/fcall_ro) command.
+    pub fn fcall_ro<T: redis::FromRedisValue>(
+        self: &mut Client,
+        function: &str,
+        keys: Vec<&str>,
+        args: Vec<&str>,
+    ) -> RedisResult<T> {
+        let fcall_args = create_eval_args(function, keys, args);
+
+        self.run_command(
+            "FCALL_RO",
+            fcall_args.iter().map(|arg| arg.as_str()).collect(),
+        )
+    }
+}
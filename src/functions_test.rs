@@ -0,0 +1,74 @@
+use super::*;
+
+fn bulk(value: &str) -> redis::Value {
+    redis::Value::BulkString(value.as_bytes().to_vec())
+}
+
+#[test]
+fn parse_function_library_flat() {
+    let value = redis::Value::Array(vec![
+        bulk("library_name"),
+        bulk("mylib"),
+        bulk("engine"),
+        bulk("LUA"),
+        bulk("functions"),
+        redis::Value::Array(vec![redis::Value::Array(vec![
+            bulk("name"),
+            bulk("myfunc"),
+            bulk("description"),
+            redis::Value::Nil,
+            bulk("flags"),
+            redis::Value::Array(vec![bulk("no-writes")]),
+        ])]),
+        bulk("library_code"),
+        bulk("#!lua name=mylib"),
+    ]);
+
+    let library = parse_function_library(value).unwrap();
+    assert_eq!(library.name, "mylib");
+    assert_eq!(library.engine, "LUA");
+    assert_eq!(library.code, Some("#!lua name=mylib".to_string()));
+    assert_eq!(
+        library.functions,
+        vec![FunctionInfo {
+            name: "myfunc".to_string(),
+            description: None,
+            flags: vec!["no-writes".to_string()],
+        }]
+    );
+}
+
+#[test]
+fn parse_function_library_map() {
+    let value = redis::Value::Map(vec![
+        (bulk("library_name"), bulk("mylib")),
+        (bulk("engine"), bulk("LUA")),
+        (
+            bulk("functions"),
+            redis::Value::Array(vec![redis::Value::Map(vec![
+                (bulk("name"), bulk("myfunc")),
+                (bulk("description"), bulk("my description")),
+                (bulk("flags"), redis::Value::Set(vec![])),
+            ])]),
+        ),
+    ]);
+
+    let library = parse_function_library(value).unwrap();
+    assert_eq!(library.name, "mylib");
+    assert!(library.code.is_none());
+    assert_eq!(library.functions.len(), 1);
+    assert_eq!(
+        library.functions[0].description,
+        Some("my description".to_string())
+    );
+    assert!(library.functions[0].flags.is_empty());
+}
+
+#[test]
+fn parse_function_library_invalid() {
+    let mut result = parse_function_library(bulk("mylib"));
+    assert!(result.is_err());
+
+    result = parse_function_library(redis::Value::Array(vec![bulk("library_name")]));
+    assert!(result.is_err());
+}
@@ -0,0 +1,163 @@
+//! # hash
+//!
+//! Enables storing and reading structs as redis hashes.<br>
+//! The `RedisHash` trait can be implemented manually or derived via `#[derive(RedisHash)]` when the `derive` feature
+//! is enabled.
+//!
+
+#[cfg(test)]
+#[path = "./hash_test.rs"]
+mod hash_test;
+
+use crate::client::Client;
+use crate::types::{RedisEmptyResult, RedisError, RedisResult};
+use std::collections::HashMap;
+use std::str::FromStr;
+
+/// Defines how a struct is converted to and from redis hash fields.
+pub trait RedisHash: Sized {
+    /// Returns the hash fields and their values.
+    fn to_redis_fields(&self) -> Vec<(String, String)>;
+
+    /// Returns the hash fields which currently have no value and should be removed from the hash
+    /// (for example optional fields which are None).
+    fn to_removed_redis_fields(&self) -> Vec<String> {
+        vec![]
+    }
+
+    /// Creates a new instance from the hash fields read from the provided key.
+    fn from_redis_fields(key: &str, values: &HashMap<String, String>) -> RedisResult<Self>;
+}
+
+/// Parses the requested hash field value.<br>
+/// In case the field is missing, a not found error is returned and in case the value can not be parsed, a
+/// parse error holding the field name is returned.
+pub fn parse_field<T: FromStr>(
+    key: &str,
+    field: &str,
+    values: &HashMap<String, String>,
+) -> RedisResult<T> {
+    match parse_optional_field(key, field, values)? {
+        Some(value) => Ok(value),
+        None => Err(RedisError::NotFound {
+            key: key.to_string(),
+            field: Some(field.to_string()),
+        }),
+    }
+}
+
+/// Parses the requested hash field value, returning None in case the field is missing.<br>
+/// In case the value can not be parsed, a parse error holding the field name is returned.
+pub fn parse_optional_field<T: FromStr>(
+    key: &str,
+    field: &str,
+    values: &HashMap<String, String>,
+) -> RedisResult<Option<T>> {
+    match values.get(field) {
+        Some(value) => match value.parse::<T>() {
+            Ok(output) => Ok(Some(output)),
+            Err(_) => Err(RedisError::Parse {
+                key: Some(key.to_string()),
+                field: Some(field.to_string()),
+                raw_value: Some(value.to_string()),
+                target_type: std::any::type_name::<T>(),
+            }),
+        },
+        None => Ok(None),
+    }
+}
+
+/// Defines the struct hash commands exposed by the redis client.
+impl Client {
+    /// Stores all the struct fields in the hash using a single command
+    /// (see redis [HSET](https://redis.io/commands/hset) command).<br>
+    /// Fields without a value (see `RedisHash::to_removed_redis_fields`) are removed from the hash via
+    /// [HDEL](https://redis.io/commands/hdel) in the same MULTI/EXEC transaction.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use simple_redis::hash::RedisHash;
+    /// # use std::collections::HashMap;
+    /// # let mut client = simple_redis::create("redis://127.0.0.1:6379/").unwrap();
+    /// struct User {
+    ///     name: String,
+    ///     age: u32,
+    /// }
+    ///
+    /// impl RedisHash for User {
+    ///     fn to_redis_fields(&self) -> Vec<(String, String)> {
+    ///         vec![
+    ///             ("name".to_string(), self.name.clone()),
+    ///             ("age".to_string(), self.age.to_string()),
+    ///         ]
+    ///     }
+    ///
+    ///     fn from_redis_fields(
+    ///         key: &str,
+    ///         values: &HashMap<String, String>,
+    ///     ) -> simple_redis::RedisResult<Self> {
+    ///         Ok(User {
+    ///             name: simple_redis::hash::parse_field(key, "name", values)?,
+    ///             age: simple_redis::hash::parse_field(key, "age", values)?,
+    ///         })
+    ///     }
+    /// }
+    ///
+    /// let user = User { name: "user1".to_string(), age: 30 };
+    /// match client.hset_struct("my_user", &user) {
+    ///     Err(error) => println!("Unable to set value in Redis: {}", error),
+    ///     _ => println!("Value set in Redis")
+    /// }
+    ///
+    /// let stored_user: User = client.hget_struct("my_user").unwrap();
+    /// assert_eq!(stored_user.age, 30);
+    /// ```
+    pub fn hset_struct<T: RedisHash>(self: &mut Client, key: &str, value: &T) -> RedisEmptyResult {
+        let fields = value.to_redis_fields();
+        let removed_fields = value.to_removed_redis_fields();
+
+        let mut set_args = Vec::with_capacity(fields.len() * 2 + 1);
+        set_args.push(key);
+        for (field, value) in &fields {
+            set_args.push(field);
+            set_args.push(value);
+        }
+
+        let mut delete_args = Vec::with_capacity(removed_fields.len() + 1);
+        delete_args.push(key);
+        delete_args.extend(removed_fields.iter().map(|field| field.as_str()));
+
+        match (fields.is_empty(), removed_fields.is_empty()) {
+            (true, true) => Ok(()),
+            (false, true) => self.run_command::<()>("HSET", set_args),
+            (true, false) => self.run_command::<()>("HDEL", delete_args),
+            (false, false) => {
+                let results = self.transaction(vec![], 0, &mut |_, pipeline| {
+                    pipeline
+                        .add_command("HSET", set_args.clone())
+                        .add_command("HDEL", delete_args.clone());
+                    Ok(())
+                })?;
+
+                results.into_typed::<Vec<redis::Value>>().map(|_| ())
+            }
+        }
+    }
+
+    /// Reads all the hash fields (see redis [HGETALL](https://redis.io/commands/hgetall) command) and
+    /// returns them as a struct.<br>
+    /// In case the key does not exist, a not found error is returned.
+    pub fn hget_struct<T: RedisHash>(self: &mut Client, key: &str) -> RedisResult<T> {
+        let values = self.hgetall(key)?;
+
+        if values.is_empty() {
+            Err(RedisError::NotFound {
+                key: key.to_string(),
+                field: None,
+            })
+        } else {
+            T::from_redis_fields(key, &values)
+        }
+    }
+}
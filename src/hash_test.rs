@@ -0,0 +1,64 @@
+use super::*;
+
+fn create_values() -> HashMap<String, String> {
+    let mut values = HashMap::new();
+    values.insert("name".to_string(), "user1".to_string());
+    values.insert("age".to_string(), "abc".to_string());
+
+    values
+}
+
+#[test]
+fn parse_field_valid() {
+    let value: String = parse_field("my_key", "name", &create_values()).unwrap();
+    assert_eq!(value, "user1");
+}
+
+#[test]
+fn parse_field_missing() {
+    let error = parse_field::<u32>("my_key", "missing", &create_values()).unwrap_err();
+
+    match error {
+        RedisError::NotFound { key, field } => {
+            assert_eq!(key, "my_key");
+            assert_eq!(field.unwrap(), "missing");
+        }
+        _ => panic!("Invalid error: {:?}", error),
+    }
+}
+
+#[test]
+fn parse_field_invalid() {
+    let error = parse_field::<u32>("my_key", "age", &create_values()).unwrap_err();
+    assert_eq!(
+        error.to_string(),
+        "Unable to parse field: age of key: my_key to type: u32."
+    );
+
+    match error {
+        RedisError::Parse {
+            key,
+            field,
+            raw_value,
+            target_type,
+        } => {
+            assert_eq!(key.unwrap(), "my_key");
+            assert_eq!(field.unwrap(), "age");
+            assert_eq!(raw_value.unwrap(), "abc");
+            assert_eq!(target_type, "u32");
+        }
+        _ => panic!("Invalid error: {:?}", error),
+    }
+}
+
+#[test]
+fn parse_optional_field_missing() {
+    let value: Option<u32> = parse_optional_field("my_key", "missing", &create_values()).unwrap();
+    assert!(value.is_none());
+}
+
+#[test]
+fn parse_optional_field_invalid() {
+    let result = parse_optional_field::<u32>("my_key", "age", &create_values());
+    assert!(result.is_err());
+}
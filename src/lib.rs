@@ -21,69 +21,155 @@
 //!
 //! ## Connection Resiliency
 //!
-//! Connection resiliency is managed by verifying the internally managed connection before every operation against the
-//! redis server.<br>
+//! Connection resiliency is managed by verifying the internally managed connection (via PING) before an operation is
+//! invoked on a connection which was idle for at least 1 second.<br>
 //! In case of any connection issue, a new connection will be allocated to ensure the operation is invoked on a valid
-//! connection only.<br>
-//! However, this comes at a small performance cost of PING operation to the redis server.<br>
+//! connection only, while broken connections which were not verified are detected by the I/O errors of the invoked
+//! commands.<br>
+//! The verification can be done before every operation, after a different idle time or disabled completely (see
+//! `HealthCheck`).<br>
+//! Reconnection attempts can be retried with exponential backoff and idempotent commands which failed due to a
+//! lost connection are invoked again once reconnected (see `ReconnectPolicy`).<br>
 //!
 //! ## Subscription Resiliency
 //!
 //! Subscription resiliency is ensured by recreating the internal pubsub and issuing new subscription requests
-//! automatically in case of any error while fetching a message from the subscribed channels.
+//! automatically in case the connection was lost while fetching messages from the subscribed channels.<br>
+//! The new connection is opened based on the reconnect policy and the `fetch_messages_with_events` function notifies
+//! once the connection was lost and reopened, enabling to resync any state affected by messages which were missed.<br>
+//! Subscriptions can also be modified while fetching messages via the `SubscriptionHandle`, which issues the changes on
+//! the live connection instead of reopening it.<br>
+//! Instead of a callback, messages can also be consumed via the blocking `messages` iterator or the channel receiver
+//! returned by `spawn_subscriber`, both fetching the messages on a background thread.<br>
+//! When subscribing to many channels, the `MessageRouter` enables to register a handler per channel or channel pattern
+//! instead of matching the channel of each message in a single callback.
+//!
+//! ## Structured Values
+//!
+//! When the optional `serde` feature is enabled, any serializable value can be stored and read directly, for example via
+//! `set_json`/`get_json`.<br>
+//! Additional formats are provided by the `msgpack` and `bincode` features, and custom formats can be used by
+//! implementing the `Codec` trait.<br>
+//! Structs can also be stored as hashes, where each struct field is stored as a separate hash field, via
+//! `hset_struct`/`hget_struct`.<br>
+//! The required `RedisHash` trait can be derived when the optional `derive` feature is enabled.
+//!
+//! ## Async Client
+//!
+//! When the optional `async` feature is enabled, the `AsyncClient` provides the same commands as async functions
+//! which can be used directly from tokio based services.<br>
+//! The async client is backed by a multiplexed connection which is reopened (based on the reconnect policy) in case
+//! it was lost, and subscriptions are consumed as a `Stream` of messages which resubscribes automatically.<br>
+//! The connection is verified based on the health check policy, and since the multiplexed connection has a single
+//! response timeout, the read and write timeouts together bound the time to wait for each command.
+//!
+//! ## Cluster
+//!
+//! When the optional `cluster` feature is enabled, the client can be connected to a redis cluster via
+//! `create_cluster` or the `ClusterClientBuilder`, while providing the same commands as a single server client.<br>
+//! The cluster topology is discovered from the provided nodes and each command is routed to the node owning the hash
+//! slot of its keys, following MOVED and ASK redirections and refreshing the topology in case of a failover.<br>
+//! Pipelines and transactions are routed based on their first command, so all their keys should share the same hash
+//! slot (for example via a `{hashtag}`).
+//!
+//! ## Sentinel
+//!
+//! Sentinel managed masters can be used via a `redis+sentinel://` connection string or the
+//! `ClientBuilder::sentinel` builder, which list the sentinel addresses and the master name.<br>
+//! The current primary server is resolved via the sentinels whenever a new connection is opened, so once the
+//! connection is lost due to a failover, the new primary is used.<br>
+//! Whenever the connection is verified based on the health check policy, the server role is checked (via ROLE) instead
+//! of a PING, so once the primary was demoted by a failover, a new connection to the new primary is opened.<br>
+//! In case a command is rejected with a `READONLY` error since the server was demoted to a replica, the primary is
+//! resolved again and the command is invoked on the new primary.
+//!
+//! ## Read Replicas
+//!
+//! Replica servers can be added via `ClientBuilder::replica`, in which case read only commands (for example `get`,
+//! `hgetall`, `lrange`, `smembers` and `zrange`) are invoked on the replicas while all other commands are invoked on the
+//! primary server.<br>
+//! The reads are distributed among the replicas one after the other or based on their response time (see
+//! `ReplicaSelection`). A replica which failed is skipped for a while and in case no replica is available, the read
+//! only commands are invoked on the primary server.<br>
+//! Reads made while building a transaction are always invoked on the primary server, which holds the watched keys.
+//!
+//! ## TLS
+//!
+//! When the optional `tls` feature is enabled, connections are encrypted by using the `rediss://` scheme in the connection
+//! string, for example: `rediss://redis.example.com:6380/`.<br>
+//! The `tls` feature uses rustls (same as the `tls-rustls` feature), while the `tls-native-tls` feature uses the platform
+//! TLS library instead.<br>
+//! With rustls, the `ClientBuilder` enables to verify the server via a custom CA bundle (`tls_ca_certificates`) and to
+//! authenticate the client via mutual TLS (`tls_client_certificate`).<br>
+//! The underlying redis crate does not accept custom certificates for native-tls connections, so with the
+//! `tls-native-tls` feature alone, setting these options makes the client creation fail with an invalid input error.<br>
+//! The server name used for SNI and for verifying the server certificate is always the connection string host name and
+//! can not be overridden.
+//!
+//! ## Unix Sockets
+//!
+//! Co-located redis servers can be used via unix sockets, avoiding the TCP overhead, by using the `unix://` (or
+//! `redis+unix://`) scheme in the connection string, for example: `unix:///var/run/redis.sock?db=1&pass=secret`.<br>
+//! The database and password are defined via the `db` and `pass` query parameters (or via the `ClientBuilder`) and apply
+//! to both the commands and the subscriptions connections.
+//!
+//! ## Error Handling
+//!
+//! All errors are returned as a `RedisError` which distinguishes between the different failure reasons, such as
+//! connection errors, timeouts, missing keys, values which failed to parse and errors returned by the redis server.<br>
+//! The `is_connection_error` and `is_retryable` functions enable to decide how to handle an error without matching
+//! specific variants.
 //!
 //! # Examples
 //!
 //! ## Initialization and Simple Operations
 //!
 //! ```
-//! fn main() {
-//!     match simple_redis::create("redis://127.0.0.1:6379/") {
-//!         Ok(mut client) =>  {
-//!             println!("Created Redis Client");
+//! match simple_redis::create("redis://127.0.0.1:6379/") {
+//!     Ok(mut client) =>  {
+//!         println!("Created Redis Client");
 //!
-//!             match client.set("my_key", "my_value") {
-//!                 Err(error) => println!("Unable to set value in Redis: {}", error),
-//!                 _ => println!("Value set in Redis")
-//!             };
-//!
-//!             match client.get_string("my_key") {
-//!                 Ok(value) => println!("Read value from Redis: {}", value),
-//!                 Err(error) => println!("Unable to get value from Redis: {}", error)
-//!             };
-//!
-//!             match client.set("my_numeric_key", 255.5) {
-//!                 Err(error) => println!("Unable to set value in Redis: {}", error),
-//!                 _ => println!("Value set in Redis")
-//!             };
-//!
-//!             match client.get::<f32>("my_numeric_key") {
-//!                 Ok(value) => println!("Read value from Redis: {}", value),
-//!                 Err(error) => println!("Unable to get value from Redis: {}", error)
-//!             };
-//!
-//!             match client.hgetall("my_map") {
-//!                 Ok(map) => {
-//!                     match map.get("my_field") {
-//!                         Some(value) => println!("Got field value from map: {}", value),
-//!                         None => println!("Map field is emtpy"),
-//!                     }
-//!                 },
-//!                 Err(error) => println!("Unable to read map from Redis: {}", error),
-//!             };
+//!         match client.set("my_key", "my_value") {
+//!             Err(error) => println!("Unable to set value in Redis: {}", error),
+//!             _ => println!("Value set in Redis")
+//!         };
 //!
-//!             /// run some command that is not built in the library
-//!             match client.run_command::<String>("ECHO", vec!["testing"]) {
-//!                 Ok(value) => assert_eq!(value, "testing"),
-//!                 _ => panic!("test error"),
-//!             };
+//!         match client.get_string("my_key") {
+//!             Ok(value) => println!("Read value from Redis: {}", value),
+//!             Err(error) => println!("Unable to get value from Redis: {}", error)
+//!         };
 //!
-//!             /// publish messages
-//!             let result = client.publish("news_channel", "test message");
-//!             assert!(result.is_ok());
-//!         },
-//!         Err(error) => println!("Unable to create Redis client: {}", error)
-//!     }
+//!         match client.set("my_numeric_key", 255.5) {
+//!             Err(error) => println!("Unable to set value in Redis: {}", error),
+//!             _ => println!("Value set in Redis")
+//!         };
+//!
+//!         match client.get::<f32>("my_numeric_key") {
+//!             Ok(value) => println!("Read value from Redis: {}", value),
+//!             Err(error) => println!("Unable to get value from Redis: {}", error)
+//!         };
+//!
+//!         match client.hgetall("my_map") {
+//!             Ok(map) => {
+//!                 match map.get("my_field") {
+//!                     Some(value) => println!("Got field value from map: {}", value),
+//!                     None => println!("Map field is emtpy"),
+//!                 }
+//!             },
+//!             Err(error) => println!("Unable to read map from Redis: {}", error),
+//!         };
+//!
+//!         /// run some command that is not built in the library
+//!         match client.run_command::<String>("ECHO", vec!["testing"]) {
+//!             Ok(value) => assert_eq!(value, "testing"),
+//!             _ => panic!("test error"),
+//!         };
+//!
+//!         /// publish messages
+//!         let result = client.publish("news_channel", "test message");
+//!         assert!(result.is_ok());
+//!     },
+//!     Err(error) => println!("Unable to create Redis client: {}", error)
 //! }
 //! ```
 //!
@@ -122,23 +208,21 @@
 //! ## Closing Connection
 //!
 //! ```rust
-//! fn main() {
-//!     match simple_redis::create("redis://127.0.0.1:6379/") {
-//!         Ok(mut client) =>  {
-//!             println!("Created Redis Client");
+//! match simple_redis::create("redis://127.0.0.1:6379/") {
+//!     Ok(mut client) =>  {
+//!         println!("Created Redis Client");
 //!
-//!             match client.set("my_key", "my_value") {
-//!                 Err(error) => println!("Unable to set value in Redis: {}", error),
-//!                 _ => println!("Value set in Redis")
-//!             };
+//!         match client.set("my_key", "my_value") {
+//!             Err(error) => println!("Unable to set value in Redis: {}", error),
+//!             _ => println!("Value set in Redis")
+//!         };
 //!
-//!             match client.quit() {
-//!                 Err(error) => println!("Error: {}", error),
-//!                 _ => println!("Connection Closed.")
-//!             }
-//!         },
-//!         Err(error) => println!("Unable to create Redis client: {}", error)
-//!     }
+//!         match client.quit() {
+//!             Err(error) => println!("Error: {}", error),
+//!             _ => println!("Connection Closed.")
+//!         }
+//!     },
+//!     Err(error) => println!("Unable to create Redis client: {}", error)
 //! }
 //! ```
 //!
@@ -165,10 +249,31 @@ mod lib_test;
 #[cfg(doctest)]
 doc_comment::doctest!("../README.md");
 
+#[cfg(feature = "async")]
+pub mod async_client;
+#[cfg(feature = "async")]
+mod async_commands;
+mod binary;
 pub mod client;
+#[cfg(feature = "cluster")]
+pub mod cluster;
+#[cfg(feature = "serde")]
+pub mod codec;
 mod commands;
 mod connection;
+pub mod consumer;
+mod functions;
+pub mod hash;
+pub mod messages;
+pub mod pipeline;
+pub mod pool;
+mod replica;
+pub mod router;
+pub mod script;
+mod sentinel;
+mod streams;
 mod subscriber;
+mod transaction;
 pub mod types;
 
 /// Error Type
@@ -180,11 +285,67 @@ pub type Message = types::Message;
 /// Blocking operations interrupts
 pub type Interrupts = types::Interrupts;
 
+/// Subscriptions connection state change notification
+pub type SubscriptionEvent = types::SubscriptionEvent;
+
+/// Enables to modify the subscriptions while fetching messages
+pub type SubscriptionHandle = types::SubscriptionHandle;
+
+/// Enables to configure and construct a new redis client
+pub type ClientBuilder = client::ClientBuilder;
+
+/// Defines how a lost connection is reopened
+pub type ReconnectPolicy = types::ReconnectPolicy;
+
+/// Defines when the connection is verified before invoking a command
+pub type HealthCheck = types::HealthCheck;
+
+/// Holds commands which are sent together in a single round-trip
+pub type Pipeline = pipeline::Pipeline;
+
+/// Lua script invoked via EVALSHA
+pub type Script = script::Script;
+
+/// Thread safe pool of redis clients
+pub type Pool = pool::Pool;
+
+/// Defines the size and behaviour of a clients pool
+pub type PoolOptions = types::PoolOptions;
+
+/// Defines how read only commands are distributed among the replicas
+pub type ReplicaSelection = types::ReplicaSelection;
+
+/// Stream consumer group worker
+pub type StreamConsumer = consumer::StreamConsumer;
+
+/// Blocking iterator over the subscribed channels messages
+pub type Messages = messages::Messages;
+
+/// Stops the background subscriber thread
+pub type SubscriberShutdown = messages::SubscriberShutdown;
+
+/// Routes messages to handlers per channel and channel pattern
+pub type MessageRouter<'a> = router::MessageRouter<'a>;
+
+/// Derives the `hash::RedisHash` trait for structs with named fields
+#[cfg(feature = "derive")]
+pub use simple_redis_derive::RedisHash;
+
+/// Async redis client (requires the `async` feature)
+#[cfg(feature = "async")]
+pub type AsyncClient = async_client::AsyncClient;
+
+/// Enables to configure and construct a new redis cluster client (requires the `cluster` feature)
+#[cfg(feature = "cluster")]
+pub type ClusterClientBuilder = cluster::ClusterClientBuilder;
+
 /// Redis result which either holds a value or a Redis error
 pub type RedisResult<T> = types::RedisResult<T>;
 
 /// Constructs a new redis client.<br>
-/// The redis connection string must be in the following format: `redis://[:<passwd>@]<hostname>[:port][/<db>]`
+/// The redis connection string must be in the following format: `redis://[:<passwd>@]<hostname>[:port][/<db>]`<br>
+/// Sentinel managed masters are defined in the following format:
+/// `redis+sentinel://[:<passwd>@]<hostname>[:port][,<hostname>[:port]...]/<master name>[/<db>]`
 ///
 /// # Arguments
 ///
@@ -205,3 +366,44 @@ pub type RedisResult<T> = types::RedisResult<T>;
 pub fn create(connection_string: &str) -> Result<client::Client, RedisError> {
     client::create(connection_string)
 }
+
+/// Constructs a new async redis client (requires the `async` feature).<br>
+/// The redis connection string must be in the following format: `redis://[:<passwd>@]<hostname>[:port][/<db>]`
+///
+/// # Arguments
+///
+/// * `connection_string` - The connection string in the format of: `redis://[:<passwd>@]<hostname>[:port][/<db>]`
+///
+/// # Example
+///
+/// ```
+/// match simple_redis::create_async("redis://127.0.0.1:6379/") {
+///     Ok(client) => println!("Created Redis Client"),
+///     Err(error) => println!("Unable to create Redis client: {}", error)
+/// }
+/// ```
+#[cfg(feature = "async")]
+pub fn create_async(connection_string: &str) -> Result<async_client::AsyncClient, RedisError> {
+    async_client::create(connection_string)
+}
+
+/// Constructs a new redis client which is connected to a redis cluster (requires the `cluster` feature).<br>
+/// The cluster topology is discovered from the provided nodes and each command is routed to the node owning
+/// the command keys.
+///
+/// # Arguments
+///
+/// * `nodes` - The initial nodes connection strings in the format of: `redis://[:<passwd>@]<hostname>[:port]`
+///
+/// # Example
+///
+/// ```
+/// match simple_redis::create_cluster(vec!["redis://127.0.0.1:7000/", "redis://127.0.0.1:7001/"]) {
+///     Ok(client) => println!("Created Redis Cluster Client"),
+///     Err(error) => println!("Unable to create Redis client: {}", error)
+/// }
+/// ```
+#[cfg(feature = "cluster")]
+pub fn create_cluster(nodes: Vec<&str>) -> Result<client::Client, RedisError> {
+    cluster::create(nodes)
+}
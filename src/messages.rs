@@ -0,0 +1,261 @@
+//! # messages
+//!
+//! Enables to consume the subscribed channels messages via an iterator or a channel receiver, while the
+//! messages are fetched on a background thread.
+//!
+
+#[cfg(test)]
+#[path = "./messages_test.rs"]
+mod messages_test;
+
+use crate::client::Client;
+use crate::connection::{ConnectionOptions, Server};
+use crate::subscriber::Subscriber;
+use crate::types::{Interrupts, Message, RedisError, RedisResult, SubscriptionHandle};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::mpsc::{self, Receiver, RecvTimeoutError, SyncSender, TrySendError};
+use std::sync::Arc;
+use std::thread::{self, JoinHandle};
+use std::time::Duration;
+
+/// The amount of messages buffered by the messages iterator
+const DEFAULT_BUFFER_SIZE: usize = 100;
+/// The max time in millies the background thread waits for a message before checking for shutdown
+const POLLING_INTERVAL_MILLIS: u64 = 100;
+/// The time to wait before retrying to deliver a message while the buffer is full
+const FULL_BUFFER_DELAY: Duration = Duration::from_millis(10);
+
+/// Stops the background subscriber thread.<br>
+/// Dropping the handle also notifies the thread to stop, without waiting for it to end.
+#[derive(Debug)]
+pub struct SubscriberShutdown {
+    /// Set once the background thread should stop
+    stop: Arc<AtomicBool>,
+    /// The background thread
+    thread: Option<JoinHandle<()>>,
+    /// Modifies the subscriptions of the background thread
+    handle: SubscriptionHandle,
+}
+
+impl SubscriberShutdown {
+    /// Returns true if the background thread is still running.
+    pub fn is_running(self: &SubscriberShutdown) -> bool {
+        match self.thread {
+            Some(ref thread) => !thread.is_finished(),
+            None => false,
+        }
+    }
+
+    /// Returns a handle which enables to subscribe and unsubscribe while the background thread is fetching
+    /// messages.<br>
+    /// The background thread has its own subscriptions, so changes requested via the client subscription handle
+    /// do not affect it.
+    pub fn subscription_handle(self: &SubscriberShutdown) -> SubscriptionHandle {
+        self.handle.clone()
+    }
+
+    /// Notifies the background thread to stop and waits for it to end.
+    pub fn shutdown(mut self: SubscriberShutdown) {
+        self.stop.store(true, Ordering::SeqCst);
+
+        if let Some(thread) = self.thread.take() {
+            // a panic in the background thread is already reported by the thread itself
+            thread.join().ok();
+        }
+    }
+}
+
+impl Drop for SubscriberShutdown {
+    fn drop(&mut self) {
+        self.stop.store(true, Ordering::SeqCst);
+    }
+}
+
+/// Blocking iterator over the messages of the subscribed channels (see `Client::messages`).<br>
+/// The messages are fetched on a background thread which is stopped once the iterator is dropped.
+#[derive(Debug)]
+pub struct Messages {
+    /// Receives the messages from the background thread
+    receiver: Receiver<RedisResult<Message>>,
+    /// Stops the background thread
+    shutdown: SubscriberShutdown,
+    /// The max time to wait for the next message
+    timeout: Option<Duration>,
+}
+
+impl Messages {
+    /// Sets the max time to wait for the next message.<br>
+    /// Once the timeout is reached, a timeout error is returned and the iteration can continue.<br>
+    /// By default, the iterator will wait until the next message arrives.
+    pub fn set_timeout(self: &mut Messages, timeout: Option<Duration>) {
+        self.timeout = timeout;
+    }
+
+    /// Returns a handle which enables to subscribe and unsubscribe while iterating (see
+    /// `SubscriberShutdown::subscription_handle`).
+    pub fn subscription_handle(self: &Messages) -> SubscriptionHandle {
+        self.shutdown.subscription_handle()
+    }
+
+    /// Stops fetching messages and waits for the background thread to end.
+    pub fn shutdown(self: Messages) {
+        self.shutdown.shutdown();
+    }
+}
+
+impl Iterator for Messages {
+    type Item = RedisResult<Message>;
+
+    /// Returns the next message, or None in case the messages can no longer be fetched.
+    fn next(&mut self) -> Option<Self::Item> {
+        match self.timeout {
+            Some(timeout) => match self.receiver.recv_timeout(timeout) {
+                Ok(result) => Some(result),
+                Err(RecvTimeoutError::Timeout) => Some(Err(RedisError::Timeout(None))),
+                Err(RecvTimeoutError::Disconnected) => None,
+            },
+            None => self.receiver.recv().ok(),
+        }
+    }
+}
+
+/// Sends the value to the receiver, waiting while the buffer is full.<br>
+/// Returns false in case the value was not delivered since the receiver was dropped or a shutdown was requested.
+fn deliver(
+    sender: &SyncSender<RedisResult<Message>>,
+    stop: &AtomicBool,
+    value: RedisResult<Message>,
+) -> bool {
+    let mut value = value;
+
+    loop {
+        match sender.try_send(value) {
+            Ok(_) => return true,
+            Err(TrySendError::Full(pending_value)) => {
+                if stop.load(Ordering::SeqCst) {
+                    return false;
+                }
+
+                value = pending_value;
+                thread::sleep(FULL_BUFFER_DELAY);
+            }
+            Err(TrySendError::Disconnected(_)) => return false,
+        }
+    }
+}
+
+fn spawn(
+    server: Server,
+    options: ConnectionOptions,
+    mut subscriber: Subscriber,
+    buffer_size: usize,
+) -> (Receiver<RedisResult<Message>>, SubscriberShutdown) {
+    let (sender, receiver) = mpsc::sync_channel(buffer_size);
+    let stop = Arc::new(AtomicBool::new(false));
+    let thread_stop = stop.clone();
+    let handle = subscriber.get_handle();
+
+    let thread = thread::spawn(move || {
+        let result = subscriber.fetch_messages(
+            &server,
+            &options,
+            &mut |message: Message| -> bool { !deliver(&sender, &thread_stop, Ok(message)) },
+            &mut |_| (),
+            &mut || -> Interrupts {
+                Interrupts {
+                    stop: thread_stop.load(Ordering::SeqCst),
+                    next_polling_time: Some(POLLING_INTERVAL_MILLIS),
+                }
+            },
+        );
+
+        if let Err(error) = result {
+            deliver(&sender, &thread_stop, Err(error));
+        }
+    });
+
+    (
+        receiver,
+        SubscriberShutdown {
+            stop,
+            thread: Some(thread),
+            handle,
+        },
+    )
+}
+
+/// Defines the background messages consumption functions exposed by the redis client.
+impl Client {
+    /// Fetches the messages from all current subscriptions on a background thread and returns a blocking
+    /// iterator over them.<br>
+    /// Subscriptions can be modified via the iterator subscription handle while iterating.<br>
+    /// In case the messages can no longer be fetched, the error is returned as the last item.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// # use std::time::Duration;
+    /// # let mut client = simple_redis::create("redis://127.0.0.1:6379/").unwrap();
+    /// client.subscribe("important_notifications");
+    ///
+    /// let mut messages = client.messages();
+    /// messages.set_timeout(Some(Duration::from_secs(5)));
+    ///
+    /// for result in messages {
+    ///     match result {
+    ///         Ok(message) => {
+    ///             let payload: String = message.get_payload().unwrap();
+    ///             println!("Got message: {}", payload);
+    ///         }
+    ///         Err(error) => {
+    ///             println!("No message: {}", error);
+    ///             break;
+    ///         }
+    ///     }
+    /// }
+    /// ```
+    pub fn messages(self: &Client) -> Messages {
+        let (receiver, shutdown) = self.spawn_subscriber(DEFAULT_BUFFER_SIZE);
+
+        Messages {
+            receiver,
+            shutdown,
+            timeout: None,
+        }
+    }
+
+    /// Fetches the messages from all current subscriptions on a background thread and sends them to the
+    /// returned receiver.<br>
+    /// The background thread waits while the receiver buffer is full, and stops once the shutdown handle is
+    /// used or dropped, once the receiver is dropped or in case the messages can no longer be fetched, in which
+    /// case the error is sent as the last item.<br>
+    /// The client can continue to be used while the background thread is running.<br>
+    /// The background thread subscriptions can be modified via the shutdown handle subscription handle.
+    ///
+    /// # Arguments
+    ///
+    /// * `buffer_size` - The max amount of messages waiting in the receiver
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// # let mut client = simple_redis::create("redis://127.0.0.1:6379/").unwrap();
+    /// client.subscribe("important_notifications");
+    ///
+    /// let (receiver, shutdown) = client.spawn_subscriber(10);
+    ///
+    /// let message = receiver.recv().unwrap().unwrap();
+    /// let payload: String = message.get_payload().unwrap();
+    /// println!("Got message: {}", payload);
+    ///
+    /// shutdown.shutdown();
+    /// ```
+    pub fn spawn_subscriber(
+        self: &Client,
+        buffer_size: usize,
+    ) -> (Receiver<RedisResult<Message>>, SubscriberShutdown) {
+        let (server, options, subscriber) = self.detach_subscriber();
+
+        spawn(server, options, subscriber, buffer_size)
+    }
+}
@@ -0,0 +1,81 @@
+use super::*;
+
+#[test]
+fn messages_no_subscriptions() {
+    let client = crate::create("redis://127.0.0.1:6379/").unwrap();
+    let mut messages = client.messages();
+
+    match messages.next() {
+        Some(Err(RedisError::NoSubscriptions)) => (),
+        _ => panic!("Invalid result"),
+    }
+    assert!(messages.next().is_none());
+}
+
+#[test]
+fn messages_connection_refused() {
+    let mut client = crate::create("redis://127.0.0.1:1/").unwrap();
+    client.subscribe("test").unwrap();
+    let mut messages = client.messages();
+    messages.set_timeout(Some(Duration::from_secs(30)));
+
+    match messages.next() {
+        Some(Err(ref error)) => assert!(error.is_connection_error()),
+        _ => panic!("Invalid result"),
+    }
+    assert!(messages.next().is_none());
+}
+
+#[test]
+fn messages_timeout() {
+    let (sender, receiver) = mpsc::sync_channel(1);
+    let mut messages = Messages {
+        receiver,
+        shutdown: SubscriberShutdown {
+            stop: Arc::new(AtomicBool::new(false)),
+            thread: None,
+            handle: SubscriptionHandle::new(),
+        },
+        timeout: Some(Duration::from_millis(10)),
+    };
+
+    match messages.next() {
+        Some(Err(RedisError::Timeout(None))) => (),
+        _ => panic!("Invalid result"),
+    }
+
+    drop(sender);
+    assert!(messages.next().is_none());
+}
+
+#[test]
+fn spawn_subscriber_shutdown() {
+    let mut client = crate::create("redis://127.0.0.1:1/").unwrap();
+    let mut policy = crate::ReconnectPolicy::new();
+    policy.max_attempts = 1000;
+    policy.initial_delay = Duration::from_millis(1);
+    policy.max_delay = Duration::from_millis(1);
+    client.set_reconnect_policy(policy);
+    client.subscribe("test").unwrap();
+
+    let (receiver, shutdown) = client.spawn_subscriber(1);
+    assert!(shutdown.is_running());
+
+    shutdown.shutdown();
+    assert!(receiver.recv().is_err());
+}
+
+#[test]
+fn deliver_full_buffer_stopped() {
+    let (sender, receiver) = mpsc::sync_channel(1);
+    let stop = AtomicBool::new(false);
+
+    assert!(deliver(&sender, &stop, Err(RedisError::NoSubscriptions)));
+
+    stop.store(true, Ordering::SeqCst);
+    assert!(!deliver(&sender, &stop, Err(RedisError::NoSubscriptions)));
+
+    drop(receiver);
+    stop.store(false, Ordering::SeqCst);
+    assert!(!deliver(&sender, &stop, Err(RedisError::NoSubscriptions)));
+}
@@ -0,0 +1,387 @@
+//! # pipeline
+//!
+//! Enables to send multiple commands to the redis server in a single round-trip.
+//!
+
+#[cfg(test)]
+#[path = "./pipeline_test.rs"]
+mod pipeline_test;
+
+use crate::client::{self, Client};
+use crate::types::{create_parse_error, from_redis_error, RedisArg, RedisError, RedisResult};
+use std::str::FromStr;
+
+/// Holds a list of commands which are sent together to the redis server.
+///
+/// # Example
+///
+/// ```
+/// # let mut client = simple_redis::create("redis://127.0.0.1:6379/").unwrap();
+/// let mut pipeline = client.pipeline();
+/// pipeline
+///     .set("my_key", "my_value")
+///     .incr("my_counter")
+///     .get("my_key");
+///
+/// match client.run_pipeline(&pipeline) {
+///     Ok(results) => {
+///         let value: String = results.get(2).unwrap();
+///         println!("Read value from Redis: {}", value);
+///     }
+///     Err(error) => println!("Unable to run pipeline: {}", error),
+/// }
+/// ```
+#[derive(Clone)]
+pub struct Pipeline {
+    /// The queued commands
+    commands: Vec<redis::Cmd>,
+    /// True if all queued commands can be safely invoked more than once
+    idempotent: bool,
+}
+
+/// Holds the responses of all pipeline commands, in the same order the commands were added.<br>
+/// Each response is kept separately so a failing command does not hide the responses of the other commands.
+#[derive(Debug, Clone)]
+pub struct PipelineResults {
+    pub(crate) values: Vec<redis::Value>,
+}
+
+/// Returns an error in case the provided value is a redis error response.
+pub(crate) fn check_value(value: redis::Value) -> RedisResult<redis::Value> {
+    match value {
+        redis::Value::ServerError(error) => Err(redis::RedisError::from(error).into()),
+        _ => Ok(value),
+    }
+}
+
+/// Converts the provided value to the requested type.
+pub(crate) fn convert_value<T: redis::FromRedisValue>(value: redis::Value) -> RedisResult<T> {
+    match redis::from_owned_redis_value(check_value(value)?) {
+        Ok(output) => Ok(output),
+        Err(error) => Err(from_redis_error::<T>(error)),
+    }
+}
+
+impl Pipeline {
+    /// Returns a new empty pipeline.
+    pub fn new() -> Pipeline {
+        Pipeline {
+            commands: vec![],
+            idempotent: true,
+        }
+    }
+
+    /// Returns the amount of queued commands.
+    pub fn len(self: &Pipeline) -> usize {
+        self.commands.len()
+    }
+
+    /// Returns true if no commands are queued.
+    pub fn is_empty(self: &Pipeline) -> bool {
+        self.commands.is_empty()
+    }
+
+    /// Removes all queued commands.
+    pub fn clear(self: &mut Pipeline) {
+        self.commands.clear();
+        self.idempotent = true;
+    }
+
+    /// Returns true if all queued commands can be safely invoked more than once.
+    pub(crate) fn is_idempotent(self: &Pipeline) -> bool {
+        self.idempotent
+    }
+
+    /// Returns the packed commands, wrapped with MULTI/EXEC if atomic is true.
+    pub(crate) fn pack(self: &Pipeline, atomic: bool) -> Vec<u8> {
+        let mut redis_pipeline = redis::Pipeline::with_capacity(self.commands.len());
+        if atomic {
+            redis_pipeline.atomic();
+        }
+
+        for cmd in &self.commands {
+            redis_pipeline.add_command(cmd.clone());
+        }
+
+        redis_pipeline.get_packed_pipeline()
+    }
+
+    /// Adds the requested command with the provided arguments.<br>
+    /// This function is also public to enable queueing commands that are not directly exposed by the pipeline.
+    ///
+    /// # Arguments
+    ///
+    /// * `command` - The Redis command, for example: `GET`
+    /// * `args` - Vector of arguments for the given command
+    pub fn add_command(self: &mut Pipeline, command: &str, args: Vec<&str>) -> &mut Pipeline {
+        let mut cmd = redis::cmd(command);
+
+        for arg in args {
+            cmd.arg(arg);
+        }
+
+        self.idempotent = self.idempotent && client::is_idempotent_command(command);
+        self.commands.push(cmd);
+
+        self
+    }
+
+    /// See redis [GET](https://redis.io/commands/get) command.
+    pub fn get(self: &mut Pipeline, key: &str) -> &mut Pipeline {
+        self.add_command("GET", vec![key])
+    }
+
+    /// See redis [SET](https://redis.io/commands/set) command.
+    pub fn set<T: RedisArg>(self: &mut Pipeline, key: &str, value: T) -> &mut Pipeline {
+        self.add_command("SET", vec![key, &value.to_string()])
+    }
+
+    /// See redis [SETEX](https://redis.io/commands/setex) command.
+    pub fn setex<T: RedisArg>(
+        self: &mut Pipeline,
+        key: &str,
+        value: T,
+        seconds: usize,
+    ) -> &mut Pipeline {
+        self.add_command(
+            "SETEX",
+            vec![key, &*seconds.to_string(), &value.to_string()],
+        )
+    }
+
+    /// See redis [DEL](https://redis.io/commands/del) command.
+    pub fn del(self: &mut Pipeline, key: &str) -> &mut Pipeline {
+        self.add_command("DEL", vec![key])
+    }
+
+    /// See redis [EXISTS](https://redis.io/commands/exists) command.
+    pub fn exists(self: &mut Pipeline, key: &str) -> &mut Pipeline {
+        self.add_command("EXISTS", vec![key])
+    }
+
+    /// See redis [EXPIRE](https://redis.io/commands/expire) command.
+    pub fn expire(self: &mut Pipeline, key: &str, seconds: usize) -> &mut Pipeline {
+        self.add_command("EXPIRE", vec![key, &*seconds.to_string()])
+    }
+
+    /// See redis [INCR](https://redis.io/commands/incr) command.
+    pub fn incr(self: &mut Pipeline, key: &str) -> &mut Pipeline {
+        self.add_command("INCR", vec![key])
+    }
+
+    /// See redis [INCRBY](https://redis.io/commands/incrby) command.
+    pub fn incrby<T: RedisArg>(self: &mut Pipeline, key: &str, value: T) -> &mut Pipeline {
+        self.add_command("INCRBY", vec![key, &*value.to_string()])
+    }
+
+    /// See redis [HGET](https://redis.io/commands/hget) command.
+    pub fn hget(self: &mut Pipeline, key: &str, field: &str) -> &mut Pipeline {
+        self.add_command("HGET", vec![key, field])
+    }
+
+    /// See redis [HGETALL](https://redis.io/commands/hgetall) command.
+    pub fn hgetall(self: &mut Pipeline, key: &str) -> &mut Pipeline {
+        self.add_command("HGETALL", vec![key])
+    }
+
+    /// See redis [HSET](https://redis.io/commands/hset) command.
+    pub fn hset<T: RedisArg>(
+        self: &mut Pipeline,
+        key: &str,
+        field: &str,
+        value: T,
+    ) -> &mut Pipeline {
+        self.add_command("HSET", vec![key, field, &value.to_string()])
+    }
+
+    /// See redis [HDEL](https://redis.io/commands/hdel) command.
+    pub fn hdel(self: &mut Pipeline, key: &str, field: &str) -> &mut Pipeline {
+        self.add_command("HDEL", vec![key, field])
+    }
+
+    /// See redis [LPUSH](https://redis.io/commands/lpush) command.
+    pub fn lpush<T: RedisArg>(self: &mut Pipeline, key: &str, value: T) -> &mut Pipeline {
+        self.add_command("LPUSH", vec![key, &value.to_string()])
+    }
+
+    /// See redis [RPUSH](https://redis.io/commands/rpush) command.
+    pub fn rpush<T: RedisArg>(self: &mut Pipeline, key: &str, value: T) -> &mut Pipeline {
+        self.add_command("RPUSH", vec![key, &value.to_string()])
+    }
+
+    /// See redis [LPOP](https://redis.io/commands/lpop) command.
+    pub fn lpop(self: &mut Pipeline, key: &str) -> &mut Pipeline {
+        self.add_command("LPOP", vec![key])
+    }
+
+    /// See redis [RPOP](https://redis.io/commands/rpop) command.
+    pub fn rpop(self: &mut Pipeline, key: &str) -> &mut Pipeline {
+        self.add_command("RPOP", vec![key])
+    }
+
+    /// See redis [LRANGE](https://redis.io/commands/lrange) command.
+    pub fn lrange(self: &mut Pipeline, key: &str, start: isize, stop: isize) -> &mut Pipeline {
+        self.add_command("LRANGE", vec![key, &start.to_string(), &stop.to_string()])
+    }
+
+    /// See redis [SADD](https://redis.io/commands/sadd) command.
+    pub fn sadd(self: &mut Pipeline, key: &str, member: &str) -> &mut Pipeline {
+        self.add_command("SADD", vec![key, member])
+    }
+
+    /// See redis [SREM](https://redis.io/commands/srem) command.
+    pub fn srem(self: &mut Pipeline, key: &str, member: &str) -> &mut Pipeline {
+        self.add_command("SREM", vec![key, member])
+    }
+
+    /// See redis [SMEMBERS](https://redis.io/commands/smembers) command.
+    pub fn smembers(self: &mut Pipeline, key: &str) -> &mut Pipeline {
+        self.add_command("SMEMBERS", vec![key])
+    }
+
+    /// See redis [ZADD](https://redis.io/commands/zadd) command.
+    pub fn zadd<T: RedisArg>(
+        self: &mut Pipeline,
+        key: &str,
+        score: T,
+        member: &str,
+    ) -> &mut Pipeline {
+        self.add_command("ZADD", vec![key, &score.to_string(), member])
+    }
+
+    /// See redis [ZRANGE](https://redis.io/commands/zrange) command.
+    pub fn zrange(self: &mut Pipeline, key: &str, start: isize, stop: isize) -> &mut Pipeline {
+        self.add_command("ZRANGE", vec![key, &start.to_string(), &stop.to_string()])
+    }
+
+    /// See redis [PUBLISH](https://redis.io/commands/publish) command.
+    pub fn publish(self: &mut Pipeline, channel: &str, message: &str) -> &mut Pipeline {
+        self.add_command("PUBLISH", vec![channel, message])
+    }
+}
+
+impl Default for Pipeline {
+    fn default() -> Self {
+        Pipeline::new()
+    }
+}
+
+impl PipelineResults {
+    /// Returns the amount of responses.
+    pub fn len(self: &PipelineResults) -> usize {
+        self.values.len()
+    }
+
+    /// Returns true if there are no responses.
+    pub fn is_empty(self: &PipelineResults) -> bool {
+        self.values.is_empty()
+    }
+
+    /// Returns true if the command at the provided index failed.
+    pub fn is_error(self: &PipelineResults, index: usize) -> bool {
+        matches!(self.values.get(index), Some(redis::Value::ServerError(_)))
+    }
+
+    /// Returns the typed response of the command at the provided index or the command error.
+    pub fn get<T: redis::FromRedisValue>(self: &PipelineResults, index: usize) -> RedisResult<T> {
+        match self.values.get(index) {
+            Some(value) => convert_value(value.clone()),
+            None => Err(RedisError::InvalidInput(
+                "Pipeline result index out of range.",
+            )),
+        }
+    }
+
+    /// Returns the response of the command at the provided index parsed from its string value.
+    pub fn get_from_string<T: FromStr>(self: &PipelineResults, index: usize) -> RedisResult<T> {
+        match self.get::<String>(index) {
+            Ok(value) => match T::from_str(&value) {
+                Ok(typed_value) => Ok(typed_value),
+                _ => Err(create_parse_error::<T>(None, None, Some(value))),
+            },
+            Err(error) => Err(error),
+        }
+    }
+
+    /// Returns all responses converted to the requested type (for example a tuple or a vector).<br>
+    /// In case any of the commands failed, the first error is returned.
+    pub fn into_typed<T: redis::FromRedisValue>(self: PipelineResults) -> RedisResult<T> {
+        let mut values = Vec::with_capacity(self.values.len());
+        for value in self.values {
+            values.push(check_value(value)?);
+        }
+
+        convert_value(redis::Value::Array(values))
+    }
+}
+
+impl Client {
+    /// Returns a new empty pipeline.<br>
+    /// Commands added to the pipeline are sent once the pipeline is invoked via run_pipeline.
+    pub fn pipeline(self: &Client) -> Pipeline {
+        Pipeline::new()
+    }
+
+    /// Sends all the pipeline commands in a single round-trip and returns all responses.<br>
+    /// A failing command does not fail the entire pipeline, instead, its error is kept in the results.
+    ///
+    /// # Arguments
+    ///
+    /// * `pipeline` - The pipeline holding the commands to invoke
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # let mut client = simple_redis::create("redis://127.0.0.1:6379/").unwrap();
+    /// let mut pipeline = client.pipeline();
+    /// pipeline.set("my_key", 10).get("my_key");
+    ///
+    /// let results = client.run_pipeline(&pipeline).unwrap();
+    /// let value: i64 = results.get(1).unwrap();
+    /// assert_eq!(value, 10);
+    /// ```
+    pub fn run_pipeline(self: &mut Client, pipeline: &Pipeline) -> RedisResult<PipelineResults> {
+        if pipeline.is_empty() {
+            return Ok(PipelineResults { values: vec![] });
+        }
+
+        let packed = pipeline.pack(false);
+        let count = pipeline.len();
+
+        self.run_on_connection(pipeline.is_idempotent(), |connection| {
+            match redis::ConnectionLike::req_packed_commands(connection, &packed, 0, count) {
+                Ok(values) => Ok(PipelineResults { values }),
+                Err(error) => Err(error.into()),
+            }
+        })
+    }
+
+    /// Sends all the pipeline commands in a single round-trip and returns all responses converted to the
+    /// requested type (for example a tuple).<br>
+    /// In case any of the commands failed, the first error is returned.
+    ///
+    /// # Arguments
+    ///
+    /// * `pipeline` - The pipeline holding the commands to invoke
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # let mut client = simple_redis::create("redis://127.0.0.1:6379/").unwrap();
+    /// let mut pipeline = client.pipeline();
+    /// pipeline.set("my_key", "my_value").get("my_key").exists("my_key");
+    ///
+    /// let (_, value, exists): ((), String, bool) = client.run_pipeline_typed(&pipeline).unwrap();
+    /// assert_eq!(value, "my_value");
+    /// assert!(exists);
+    /// ```
+    pub fn run_pipeline_typed<T: redis::FromRedisValue>(
+        self: &mut Client,
+        pipeline: &Pipeline,
+    ) -> RedisResult<T> {
+        match self.run_pipeline(pipeline) {
+            Ok(results) => results.into_typed(),
+            Err(error) => Err(error),
+        }
+    }
+}
@@ -0,0 +1,95 @@
+use super::*;
+
+fn create_results() -> PipelineResults {
+    PipelineResults {
+        values: vec![
+            redis::Value::Okay,
+            redis::Value::BulkString(b"10".to_vec()),
+            redis::parse_redis_value(b"-WRONGTYPE Operation against a key\r\n").unwrap(),
+        ],
+    }
+}
+
+#[test]
+fn new_empty() {
+    let pipeline = Pipeline::new();
+    assert!(pipeline.is_empty());
+    assert_eq!(pipeline.len(), 0);
+    assert!(pipeline.is_idempotent());
+}
+
+#[test]
+fn add_commands() {
+    let mut pipeline = Pipeline::new();
+    pipeline.set("key", "value").get("key");
+    assert_eq!(pipeline.len(), 2);
+    assert!(pipeline.is_idempotent());
+
+    pipeline.incr("counter");
+    assert_eq!(pipeline.len(), 3);
+    assert!(!pipeline.is_idempotent());
+
+    pipeline.clear();
+    assert!(pipeline.is_empty());
+    assert!(pipeline.is_idempotent());
+}
+
+#[test]
+fn pack() {
+    let mut pipeline = Pipeline::new();
+    pipeline.get("key");
+
+    let mut packed = pipeline.pack(false);
+    assert_eq!(packed, b"*2\r\n$3\r\nGET\r\n$3\r\nkey\r\n".to_vec());
+
+    packed = pipeline.pack(true);
+    assert!(packed.starts_with(b"*1\r\n$5\r\nMULTI\r\n"));
+    assert!(packed.ends_with(b"*1\r\n$4\r\nEXEC\r\n"));
+}
+
+#[test]
+fn results_get() {
+    let results = create_results();
+    assert_eq!(results.len(), 3);
+    assert!(!results.is_empty());
+
+    let value: i64 = results.get(1).unwrap();
+    assert_eq!(value, 10);
+
+    let text: String = results.get(1).unwrap();
+    assert_eq!(text, "10");
+
+    let parsed: u8 = results.get_from_string(1).unwrap();
+    assert_eq!(parsed, 10);
+}
+
+#[test]
+fn results_get_error() {
+    let results = create_results();
+
+    assert!(!results.is_error(0));
+    assert!(results.is_error(2));
+
+    let result = results.get::<String>(2);
+    assert!(result.is_err());
+
+    let result = results.get::<String>(3);
+    assert!(result.is_err());
+}
+
+#[test]
+fn results_into_typed() {
+    let mut results = create_results();
+    results.values.pop();
+
+    let (_, value): ((), i64) = results.into_typed().unwrap();
+    assert_eq!(value, 10);
+}
+
+#[test]
+fn results_into_typed_error() {
+    let results = create_results();
+
+    let result = results.into_typed::<Vec<String>>();
+    assert!(result.is_err());
+}
@@ -0,0 +1,252 @@
+//! # pool
+//!
+//! Implements a thread safe pool of redis clients.
+//!
+
+#[cfg(test)]
+#[path = "./pool_test.rs"]
+mod pool_test;
+
+use crate::client::{Client, ClientBuilder};
+use crate::types::{PoolOptions, RedisError, RedisResult};
+use std::ops::{Deref, DerefMut};
+use std::sync::{Arc, Condvar, Mutex, MutexGuard};
+use std::time::Instant;
+
+/// A thread safe pool of redis clients.<br>
+/// The pool can be cloned and shared between threads, while all clones share the same clients.
+#[derive(Clone)]
+pub struct Pool {
+    inner: Arc<PoolInner>,
+}
+
+/// A redis client checked out of the pool.<br>
+/// The client exposes the entire client API and is returned to the pool once dropped.
+pub struct PooledClient {
+    /// The checked out client, only empty while being dropped
+    client: Option<Client>,
+    /// The pool to return the client to
+    pool: Arc<PoolInner>,
+}
+
+struct PoolInner {
+    /// Used to create new clients
+    builder: ClientBuilder,
+    /// The pool size and behaviour options
+    options: PoolOptions,
+    /// The pool state
+    state: Mutex<PoolState>,
+    /// Notified whenever a client is returned to the pool or removed from it
+    available: Condvar,
+}
+
+struct PoolState {
+    /// Clients which are not checked out, the most recently used is last
+    idle: Vec<IdleClient>,
+    /// The total amount of clients, both idle and checked out
+    size: usize,
+}
+
+struct IdleClient {
+    client: Client,
+    idle_since: Instant,
+}
+
+fn lock_state(pool: &PoolInner) -> MutexGuard<'_, PoolState> {
+    match pool.state.lock() {
+        Ok(state) => state,
+        Err(poisoned) => poisoned.into_inner(),
+    }
+}
+
+/// Removes the clients which were idle for too long, while keeping at least the min pool size.
+fn evict_idle_clients(state: &mut PoolState, options: &PoolOptions) {
+    if let Some(idle_timeout) = options.idle_timeout {
+        while state.size > options.min_size
+            && !state.idle.is_empty()
+            && state.idle[0].idle_since.elapsed() >= idle_timeout
+        {
+            state.idle.remove(0);
+            state.size -= 1;
+        }
+    }
+}
+
+/// Returns true if the client connection responds to a PING.
+fn is_healthy(client: &mut Client) -> bool {
+    client.run_command_empty_response("PING", vec![]).is_ok()
+}
+
+fn release_slot(pool: &PoolInner) {
+    let mut state = lock_state(pool);
+    state.size -= 1;
+    pool.available.notify_one();
+}
+
+fn checkout(pool: &Arc<PoolInner>) -> RedisResult<PooledClient> {
+    let deadline = Instant::now() + pool.options.checkout_timeout;
+
+    let mut state = lock_state(pool);
+    loop {
+        evict_idle_clients(&mut state, &pool.options);
+
+        if let Some(idle_client) = state.idle.pop() {
+            drop(state);
+
+            let mut client = idle_client.client;
+            if !pool.options.test_on_checkout || is_healthy(&mut client) {
+                return Ok(PooledClient {
+                    client: Some(client),
+                    pool: pool.clone(),
+                });
+            }
+
+            // the broken client is discarded and its slot is freed
+            state = lock_state(pool);
+            state.size -= 1;
+        } else if state.size < pool.options.max_size {
+            state.size += 1;
+            drop(state);
+
+            return match pool.builder.build() {
+                Ok(client) => Ok(PooledClient {
+                    client: Some(client),
+                    pool: pool.clone(),
+                }),
+                Err(error) => {
+                    release_slot(pool);
+                    Err(error)
+                }
+            };
+        } else {
+            let now = Instant::now();
+            if now >= deadline {
+                return Err(RedisError::PoolExhausted);
+            }
+
+            state = match pool.available.wait_timeout(state, deadline - now) {
+                Ok((state, _)) => state,
+                Err(poisoned) => poisoned.into_inner().0,
+            };
+        }
+    }
+}
+
+impl Pool {
+    /// Returns an available client from the pool.<br>
+    /// In case all clients are checked out and the pool reached its max size, this function will block until
+    /// a client is returned to the pool or the checkout timeout is reached, in which case a `PoolExhausted` error
+    /// is returned.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # let pool = simple_redis::pool::create(
+    /// #     simple_redis::ClientBuilder::new("redis://127.0.0.1:6379/"),
+    /// #     simple_redis::PoolOptions::new(),
+    /// # ).unwrap();
+    /// match pool.get() {
+    ///     Ok(mut client) => match client.set("my_key", "my_value") {
+    ///         Err(error) => println!("Unable to set value in Redis: {}", error),
+    ///         _ => println!("Value set in Redis")
+    ///     },
+    ///     Err(error) => println!("Unable to get pooled client: {}", error)
+    /// }
+    /// ```
+    pub fn get(self: &Pool) -> RedisResult<PooledClient> {
+        checkout(&self.inner)
+    }
+
+    /// Returns the total amount of clients, both idle and checked out.
+    pub fn size(self: &Pool) -> usize {
+        lock_state(&self.inner).size
+    }
+
+    /// Returns the amount of clients which are currently not checked out.
+    pub fn idle_count(self: &Pool) -> usize {
+        lock_state(&self.inner).idle.len()
+    }
+}
+
+impl Deref for PooledClient {
+    type Target = Client;
+
+    fn deref(&self) -> &Client {
+        match self.client {
+            Some(ref client) => client,
+            None => unreachable!("pooled client accessed after release"),
+        }
+    }
+}
+
+impl DerefMut for PooledClient {
+    fn deref_mut(&mut self) -> &mut Client {
+        match self.client {
+            Some(ref mut client) => client,
+            None => unreachable!("pooled client accessed after release"),
+        }
+    }
+}
+
+impl Drop for PooledClient {
+    fn drop(&mut self) {
+        if let Some(client) = self.client.take() {
+            let mut state = lock_state(&self.pool);
+            state.idle.push(IdleClient {
+                client,
+                idle_since: Instant::now(),
+            });
+            evict_idle_clients(&mut state, &self.pool.options);
+
+            self.pool.available.notify_one();
+        }
+    }
+}
+
+/// Constructs a new clients pool.<br>
+/// All clients are created by the provided builder and the pool is populated with the min amount of clients
+/// (without connecting them).
+///
+/// # Arguments
+///
+/// * `builder` - Used to create the pooled clients
+/// * `options` - The pool size and behaviour options
+///
+/// # Example
+///
+/// ```
+/// let mut options = simple_redis::PoolOptions::new();
+/// options.max_size = 4;
+///
+/// match simple_redis::pool::create(
+///     simple_redis::ClientBuilder::new("redis://127.0.0.1:6379/"),
+///     options,
+/// ) {
+///     Ok(pool) => println!("Created Redis Pool"),
+///     Err(error) => println!("Unable to create Redis pool: {}", error)
+/// }
+/// ```
+pub fn create(builder: ClientBuilder, options: PoolOptions) -> Result<Pool, RedisError> {
+    if options.max_size == 0 || options.min_size > options.max_size {
+        return Err(RedisError::InvalidInput("Invalid pool size options."));
+    }
+
+    let mut idle = vec![];
+    for _ in 0..options.min_size {
+        let client = builder.build()?;
+        idle.push(IdleClient {
+            client,
+            idle_since: Instant::now(),
+        });
+    }
+
+    let size = idle.len();
+    Ok(Pool {
+        inner: Arc::new(PoolInner {
+            builder,
+            options,
+            state: Mutex::new(PoolState { idle, size }),
+            available: Condvar::new(),
+        }),
+    })
+}
@@ -0,0 +1,141 @@
+use super::*;
+use std::thread;
+use std::time::Duration;
+
+fn create_options(max_size: usize) -> PoolOptions {
+    let mut options = PoolOptions::new();
+    options.max_size = max_size;
+    options.test_on_checkout = false;
+    options.checkout_timeout = Duration::from_millis(50);
+
+    options
+}
+
+#[test]
+fn create_invalid_url() {
+    let mut options = create_options(2);
+    options.min_size = 1;
+
+    let result = create(ClientBuilder::new("test/bad/url"), options);
+    assert!(result.is_err());
+}
+
+#[test]
+fn create_invalid_size() {
+    let mut result = create(
+        ClientBuilder::new("redis://127.0.0.1:6379/"),
+        create_options(0),
+    );
+    assert!(result.is_err());
+
+    let mut options = create_options(2);
+    options.min_size = 3;
+    result = create(ClientBuilder::new("redis://127.0.0.1:6379/"), options);
+    assert!(result.is_err());
+}
+
+#[test]
+fn create_min_size() {
+    let mut options = create_options(5);
+    options.min_size = 2;
+
+    let pool = create(ClientBuilder::new("redis://127.0.0.1:6379/"), options).unwrap();
+    assert_eq!(pool.size(), 2);
+    assert_eq!(pool.idle_count(), 2);
+}
+
+#[test]
+fn get_and_release() {
+    let pool = create(
+        ClientBuilder::new("redis://127.0.0.1:6379/"),
+        create_options(2),
+    )
+    .unwrap();
+    assert_eq!(pool.size(), 0);
+
+    {
+        let mut client = pool.get().unwrap();
+        assert!(!client.is_connection_open());
+        assert_eq!(pool.size(), 1);
+        assert_eq!(pool.idle_count(), 0);
+    }
+
+    assert_eq!(pool.size(), 1);
+    assert_eq!(pool.idle_count(), 1);
+
+    let _client = pool.get().unwrap();
+    assert_eq!(pool.size(), 1);
+    assert_eq!(pool.idle_count(), 0);
+}
+
+#[test]
+fn get_timeout() {
+    let pool = create(
+        ClientBuilder::new("redis://127.0.0.1:6379/"),
+        create_options(1),
+    )
+    .unwrap();
+
+    let _client = pool.get().unwrap();
+    match pool.get() {
+        Err(error) => {
+            assert!(matches!(error, RedisError::PoolExhausted));
+            assert!(!error.is_connection_error());
+            assert!(!error.is_retryable());
+        }
+        _ => panic!("test error"),
+    }
+}
+
+#[test]
+fn get_wait_for_release() {
+    let mut options = create_options(1);
+    options.checkout_timeout = Duration::from_secs(5);
+    let pool = create(ClientBuilder::new("redis://127.0.0.1:6379/"), options).unwrap();
+
+    let client = pool.get().unwrap();
+
+    let handle = thread::spawn(move || {
+        thread::sleep(Duration::from_millis(100));
+        drop(client);
+    });
+
+    let result = pool.get();
+    assert!(result.is_ok());
+    assert_eq!(pool.size(), 1);
+
+    handle.join().unwrap();
+}
+
+#[test]
+fn idle_eviction() {
+    let mut options = create_options(3);
+    options.min_size = 1;
+    options.idle_timeout = Some(Duration::from_millis(0));
+    let pool = create(ClientBuilder::new("redis://127.0.0.1:6379/"), options).unwrap();
+
+    {
+        let _client1 = pool.get().unwrap();
+        let _client2 = pool.get().unwrap();
+        let _client3 = pool.get().unwrap();
+        assert_eq!(pool.size(), 3);
+    }
+
+    assert_eq!(pool.size(), 1);
+    assert_eq!(pool.idle_count(), 1);
+}
+
+#[test]
+fn test_on_checkout_discards_broken() {
+    let mut options = create_options(1);
+    options.test_on_checkout = true;
+    let pool = create(ClientBuilder::new("redis://127.0.0.1:1/"), options).unwrap();
+
+    drop(pool.get().unwrap());
+    assert_eq!(pool.idle_count(), 1);
+
+    // the idle client fails the health check and is replaced with a new one
+    let _client = pool.get().unwrap();
+    assert_eq!(pool.size(), 1);
+    assert_eq!(pool.idle_count(), 0);
+}
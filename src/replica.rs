@@ -0,0 +1,164 @@
+//! # replica
+//!
+//! Routes read only commands to the replica servers and tracks their availability.
+//!
+
+#[cfg(test)]
+#[path = "./replica_test.rs"]
+mod replica_test;
+
+use crate::connection::{self, Connection, ConnectionOptions, Server};
+use crate::types::{RedisResult, ReplicaSelection};
+use std::time::{Duration, Instant};
+
+/// The weight of the last response time in the average replica response time
+const LATENCY_WEIGHT: f64 = 0.2;
+
+/// A single replica server and its connection state.
+struct Replica {
+    /// The replica server
+    server: Server,
+    /// Holds the current replica connection
+    connection: Connection,
+    /// The average response time
+    latency: Option<Duration>,
+    /// The last time the replica failed
+    failed_at: Option<Instant>,
+}
+
+/// Holds the replica servers used for read only commands.
+pub(crate) struct Replicas {
+    /// The replica servers
+    replicas: Vec<Replica>,
+    /// Defines which replica is used for the next command
+    selection: ReplicaSelection,
+    /// The time to wait before using a failed replica again
+    retry_interval: Duration,
+    /// The next replica index when using the replicas one after the other
+    next_index: usize,
+    /// Options applied on every opened replica connection
+    options: ConnectionOptions,
+}
+
+impl Replica {
+    fn is_available(self: &Replica, retry_interval: Duration) -> bool {
+        match self.failed_at {
+            Some(failed_at) => failed_at.elapsed() >= retry_interval,
+            None => true,
+        }
+    }
+
+    fn update_latency(self: &mut Replica, duration: Duration) {
+        self.latency = Some(match self.latency {
+            Some(latency) => {
+                latency.mul_f64(1.0 - LATENCY_WEIGHT) + duration.mul_f64(LATENCY_WEIGHT)
+            }
+            None => duration,
+        });
+    }
+}
+
+impl Replicas {
+    /// Returns the indexes of the available replicas in the order in which they should be used.
+    fn get_order(self: &mut Replicas) -> Vec<usize> {
+        let retry_interval = self.retry_interval;
+        let mut order: Vec<usize> = (0..self.replicas.len())
+            .filter(|index| self.replicas[*index].is_available(retry_interval))
+            .collect();
+
+        if order.is_empty() {
+            return order;
+        }
+
+        match self.selection {
+            ReplicaSelection::RoundRobin => {
+                let next_index = self.next_index % self.replicas.len();
+                self.next_index = next_index + 1;
+
+                // start from the next replica and wrap around
+                let start = order
+                    .iter()
+                    .position(|index| *index >= next_index)
+                    .unwrap_or(0);
+                order.rotate_left(start);
+            }
+            // replicas without any measured response time are used first, so their latency becomes known
+            ReplicaSelection::LeastLatency => {
+                order.sort_by_key(|index| self.replicas[*index].latency)
+            }
+        }
+
+        order
+    }
+
+    /// Invokes the provided function with a connection of an available replica.<br>
+    /// In case the replica connection fails or the replica is temporarily unable to serve commands, the next
+    /// replica is used.<br>
+    /// Returns None in case no replica is available.
+    pub(crate) fn run<T, F>(self: &mut Replicas, run: &mut F) -> Option<RedisResult<T>>
+    where
+        F: FnMut(&mut dyn redis::ConnectionLike) -> RedisResult<T>,
+    {
+        for index in self.get_order() {
+            let replica = &mut self.replicas[index];
+            let start_time = Instant::now();
+
+            let result = match replica
+                .connection
+                .get_redis_connection(&replica.server, &self.options)
+            {
+                Ok(redis_connection) => run(redis_connection),
+                Err(error) => Err(error),
+            };
+
+            match result {
+                Err(ref error) if error.is_retryable() => {
+                    replica.connection.close();
+                    replica.failed_at = Some(Instant::now());
+                }
+                _ => {
+                    replica.failed_at = None;
+                    replica.update_latency(start_time.elapsed());
+                    return Some(result);
+                }
+            }
+        }
+
+        None
+    }
+
+    /// Drops all replica connections, the next read only command will open a new one.
+    pub(crate) fn close(self: &mut Replicas) {
+        for replica in &mut self.replicas {
+            replica.connection.close();
+        }
+    }
+}
+
+/// Creates and returns the replicas holder.<br>
+/// Replica connections are opened without retries, since commands fall back to the next replica or the primary.
+pub(crate) fn create(
+    servers: Vec<Server>,
+    selection: ReplicaSelection,
+    retry_interval: Duration,
+    options: &ConnectionOptions,
+) -> Replicas {
+    let mut options = options.clone();
+    options.reconnect_policy.max_attempts = 1;
+
+    Replicas {
+        replicas: servers
+            .into_iter()
+            .map(|server| Replica {
+                server,
+                connection: connection::create(),
+                latency: None,
+                failed_at: None,
+            })
+            .collect(),
+        selection,
+        retry_interval,
+        next_index: 0,
+        options,
+    }
+}
@@ -0,0 +1,109 @@
+use super::*;
+
+fn create_replicas(ports: Vec<u16>, selection: ReplicaSelection) -> Replicas {
+    let servers = ports
+        .iter()
+        .map(|port| {
+            Server::Fixed(redis::Client::open(format!("redis://127.0.0.1:{}/", port)).unwrap())
+        })
+        .collect();
+
+    create(
+        servers,
+        selection,
+        Duration::from_secs(60),
+        &Default::default(),
+    )
+}
+
+#[test]
+fn create_single_attempt() {
+    let replicas = create_replicas(vec![6380], ReplicaSelection::RoundRobin);
+
+    assert_eq!(replicas.replicas.len(), 1);
+    assert_eq!(replicas.options.reconnect_policy.max_attempts, 1);
+}
+
+#[test]
+fn get_order_empty() {
+    let mut replicas = create_replicas(vec![], ReplicaSelection::RoundRobin);
+
+    assert!(replicas.get_order().is_empty());
+}
+
+#[test]
+fn get_order_round_robin() {
+    let mut replicas = create_replicas(vec![6380, 6381, 6382], ReplicaSelection::RoundRobin);
+
+    assert_eq!(replicas.get_order(), vec![0, 1, 2]);
+    assert_eq!(replicas.get_order(), vec![1, 2, 0]);
+    assert_eq!(replicas.get_order(), vec![2, 0, 1]);
+    assert_eq!(replicas.get_order(), vec![0, 1, 2]);
+}
+
+#[test]
+fn get_order_round_robin_skip_failed() {
+    let mut replicas = create_replicas(vec![6380, 6381, 6382], ReplicaSelection::RoundRobin);
+    replicas.replicas[1].failed_at = Some(Instant::now());
+
+    assert_eq!(replicas.get_order(), vec![0, 2]);
+    assert_eq!(replicas.get_order(), vec![2, 0]);
+    assert_eq!(replicas.get_order(), vec![2, 0]);
+    assert_eq!(replicas.get_order(), vec![0, 2]);
+}
+
+#[test]
+fn get_order_retry_interval_passed() {
+    let mut replicas = create_replicas(vec![6380, 6381], ReplicaSelection::RoundRobin);
+    replicas.retry_interval = Duration::from_millis(0);
+    replicas.replicas[1].failed_at = Some(Instant::now());
+
+    assert_eq!(replicas.get_order(), vec![0, 1]);
+}
+
+#[test]
+fn get_order_least_latency() {
+    let mut replicas = create_replicas(vec![6380, 6381, 6382], ReplicaSelection::LeastLatency);
+    replicas.replicas[0].latency = Some(Duration::from_millis(20));
+    replicas.replicas[2].latency = Some(Duration::from_millis(5));
+
+    // replicas without measured latency are used first
+    assert_eq!(replicas.get_order(), vec![1, 2, 0]);
+
+    replicas.replicas[1].latency = Some(Duration::from_millis(10));
+    assert_eq!(replicas.get_order(), vec![2, 1, 0]);
+}
+
+#[test]
+fn update_latency_average() {
+    let mut replicas = create_replicas(vec![6380], ReplicaSelection::LeastLatency);
+    let replica = &mut replicas.replicas[0];
+
+    replica.update_latency(Duration::from_millis(100));
+    assert_eq!(replica.latency, Some(Duration::from_millis(100)));
+
+    replica.update_latency(Duration::from_millis(200));
+    assert_eq!(replica.latency, Some(Duration::from_millis(120)));
+}
+
+#[test]
+fn run_no_replicas() {
+    let mut replicas = create_replicas(vec![], ReplicaSelection::RoundRobin);
+
+    let result = replicas
+        .run(&mut |_: &mut dyn redis::ConnectionLike| -> RedisResult<()> { panic!("test error") });
+
+    assert!(result.is_none());
+}
+
+#[test]
+fn run_unavailable_replica() {
+    let mut replicas = create_replicas(vec![1], ReplicaSelection::RoundRobin);
+
+    let result = replicas
+        .run(&mut |_: &mut dyn redis::ConnectionLike| -> RedisResult<()> { panic!("test error") });
+
+    assert!(result.is_none());
+    assert!(replicas.replicas[0].failed_at.is_some());
+    assert!(replicas.get_order().is_empty());
+}
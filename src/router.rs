@@ -0,0 +1,289 @@
+//! # router
+//!
+//! Routes the subscribed channels messages to handlers registered per channel or channel pattern.
+//!
+
+#[cfg(test)]
+#[path = "./router_test.rs"]
+mod router_test;
+
+use crate::client::Client;
+use crate::types::{Interrupts, Message, RedisEmptyResult};
+use std::collections::HashMap;
+
+/// Handles a single message and returns true to stop fetching messages
+type MessageHandler<'a> = Box<dyn FnMut(Message) -> bool + 'a>;
+
+/// Invokes the handler registered for the message channel.<br>
+/// Messages received via a pattern subscription are routed to the handler registered for that pattern.<br>
+/// Other messages are routed to the handler registered for their exact channel, or else to the first handler (in
+/// registration order) whose pattern matches the channel.<br>
+/// Messages which do not match any channel or pattern are passed to the fallback handler, if defined.
+pub struct MessageRouter<'a> {
+    /// Handlers per exact channel name
+    channels: HashMap<String, MessageHandler<'a>>,
+    /// Handlers per channel pattern
+    patterns: Vec<(String, MessageHandler<'a>)>,
+    /// Handles all messages which are not routed to any other handler
+    fallback: Option<MessageHandler<'a>>,
+}
+
+/// Returns true if the channel matches the provided pattern using the same glob style rules as
+/// the redis [PSUBSCRIBE](https://redis.io/commands/psubscribe) command.<br>
+/// The pattern supports `*` for any sequence, `?` for any single character, `[...]` for a set of characters or ranges
+/// (negated via `[^...]`) and `\` for escaping the next character.
+///
+/// # Example
+///
+/// ```
+/// assert!(simple_redis::router::matches_pattern("news.*", "news.art"));
+/// assert!(simple_redis::router::matches_pattern("h[ae]llo", "hallo"));
+/// assert!(!simple_redis::router::matches_pattern("h[^e]llo", "hello"));
+/// ```
+pub fn matches_pattern(pattern: &str, channel: &str) -> bool {
+    glob_match(pattern.as_bytes(), channel.as_bytes())
+}
+
+fn glob_match(pattern: &[u8], value: &[u8]) -> bool {
+    let mut pattern_index = 0;
+    let mut value_index = 0;
+
+    while pattern_index < pattern.len() && value_index < value.len() {
+        match pattern[pattern_index] {
+            b'*' => {
+                while pattern_index + 1 < pattern.len() && pattern[pattern_index + 1] == b'*' {
+                    pattern_index += 1;
+                }
+
+                if pattern_index + 1 == pattern.len() {
+                    return true;
+                }
+
+                return (value_index..value.len())
+                    .any(|start| glob_match(&pattern[pattern_index + 1..], &value[start..]));
+            }
+            b'?' => value_index += 1,
+            b'[' => {
+                pattern_index += 1;
+                let negate = pattern_index < pattern.len() && pattern[pattern_index] == b'^';
+                if negate {
+                    pattern_index += 1;
+                }
+
+                let current = value[value_index];
+                let mut matched = false;
+                loop {
+                    if pattern_index >= pattern.len() {
+                        // unterminated set, the pattern ends here
+                        pattern_index = pattern.len() - 1;
+                        break;
+                    }
+
+                    let remaining = pattern.len() - pattern_index;
+                    if pattern[pattern_index] == b'\\' && remaining >= 2 {
+                        pattern_index += 1;
+                        matched |= pattern[pattern_index] == current;
+                    } else if pattern[pattern_index] == b']' {
+                        break;
+                    } else if remaining >= 3 && pattern[pattern_index + 1] == b'-' {
+                        let start = pattern[pattern_index].min(pattern[pattern_index + 2]);
+                        let end = pattern[pattern_index].max(pattern[pattern_index + 2]);
+                        matched |= current >= start && current <= end;
+                        pattern_index += 2;
+                    } else {
+                        matched |= pattern[pattern_index] == current;
+                    }
+
+                    pattern_index += 1;
+                }
+
+                if matched == negate {
+                    return false;
+                }
+                value_index += 1;
+            }
+            character => {
+                let expected = if character == b'\\' && pattern_index + 1 < pattern.len() {
+                    pattern_index += 1;
+                    pattern[pattern_index]
+                } else {
+                    character
+                };
+
+                if expected != value[value_index] {
+                    return false;
+                }
+                value_index += 1;
+            }
+        }
+
+        pattern_index += 1;
+
+        if value_index == value.len() {
+            while pattern_index < pattern.len() && pattern[pattern_index] == b'*' {
+                pattern_index += 1;
+            }
+        }
+    }
+
+    pattern_index == pattern.len() && value_index == value.len()
+}
+
+impl<'a> MessageRouter<'a> {
+    /// Returns a new router without any handlers.
+    pub fn new() -> MessageRouter<'a> {
+        MessageRouter {
+            channels: HashMap::new(),
+            patterns: vec![],
+            fallback: None,
+        }
+    }
+
+    /// Sets the handler for messages published to the provided channel, replacing any previous handler of
+    /// that channel.
+    ///
+    /// # Arguments
+    ///
+    /// * `channel` - The channel name, for example: `level_info`
+    /// * `handler` - Invoked on each message of the channel. If returns true, the fetching will stop.
+    pub fn add_channel_handler<F: FnMut(Message) -> bool + 'a>(
+        self: &mut MessageRouter<'a>,
+        channel: &str,
+        handler: F,
+    ) {
+        self.channels.insert(channel.to_string(), Box::new(handler));
+    }
+
+    /// Sets the handler for messages published to channels matching the provided pattern, replacing any
+    /// previous handler of that pattern.
+    ///
+    /// # Arguments
+    ///
+    /// * `pattern` - The channel pattern, for example: `level_*`
+    /// * `handler` - Invoked on each message of the matching channels. If returns true, the fetching will stop.
+    pub fn add_pattern_handler<F: FnMut(Message) -> bool + 'a>(
+        self: &mut MessageRouter<'a>,
+        pattern: &str,
+        handler: F,
+    ) {
+        match self.patterns.iter_mut().find(|(value, _)| value == pattern) {
+            Some(entry) => entry.1 = Box::new(handler),
+            None => self.patterns.push((pattern.to_string(), Box::new(handler))),
+        }
+    }
+
+    /// Sets the handler for messages which are not routed to any channel or pattern handler.
+    pub fn set_fallback_handler<F: FnMut(Message) -> bool + 'a>(
+        self: &mut MessageRouter<'a>,
+        handler: F,
+    ) {
+        self.fallback = Some(Box::new(handler));
+    }
+
+    /// Returns all channels with a registered handler.
+    pub fn get_channels(self: &MessageRouter<'a>) -> Vec<String> {
+        self.channels.keys().cloned().collect()
+    }
+
+    /// Returns all patterns with a registered handler.
+    pub fn get_patterns(self: &MessageRouter<'a>) -> Vec<String> {
+        self.patterns
+            .iter()
+            .map(|(pattern, _)| pattern.clone())
+            .collect()
+    }
+
+    /// Invokes the handler matching the message channel and returns its result.<br>
+    /// In case no handler matches the message, it is ignored and false is returned.<br>
+    /// This function can be used as the fetch_messages on_message handler.
+    pub fn route(self: &mut MessageRouter<'a>, message: Message) -> bool {
+        let pattern_index = if message.from_pattern() {
+            // messages received via a pattern subscription are routed to the handler of that pattern
+            match message.get_pattern::<String>() {
+                Ok(message_pattern) => self
+                    .patterns
+                    .iter()
+                    .position(|(pattern, _)| *pattern == message_pattern),
+                Err(_) => None,
+            }
+        } else {
+            let channel = message.get_channel_name();
+
+            if let Some(handler) = self.channels.get_mut(channel) {
+                return handler(message);
+            }
+
+            self.patterns
+                .iter()
+                .position(|(pattern, _)| matches_pattern(pattern, channel))
+        };
+
+        match (pattern_index, self.fallback.as_mut()) {
+            (Some(index), _) => (self.patterns[index].1)(message),
+            (None, Some(fallback)) => fallback(message),
+            (None, None) => false,
+        }
+    }
+}
+
+impl<'a> Default for MessageRouter<'a> {
+    fn default() -> Self {
+        MessageRouter::new()
+    }
+}
+
+/// Defines the message routing functions exposed by the redis client.
+impl Client {
+    /// Subscribes to all channels and patterns registered in the router and fetches the messages from all
+    /// subscriptions, invoking the router handler matching each message (see fetch_messages).
+    ///
+    /// # Arguments
+    ///
+    /// * `router` - Routes each message to its handler. If the handler returns true, the fetching will stop.
+    /// * `poll_interrupts` - Returns the interrupts struct, enabling to modify the fetching.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// # use simple_redis::{Interrupts, Message, MessageRouter};
+    /// # let mut client = simple_redis::create("redis://127.0.0.1:6379/").unwrap();
+    /// let mut router = MessageRouter::new();
+    /// router.add_channel_handler("orders", |message: Message| -> bool {
+    ///     let payload: String = message.get_payload().unwrap();
+    ///     println!("Got order: {}", payload);
+    ///     false
+    /// });
+    /// router.add_pattern_handler("alerts.*", |message: Message| -> bool {
+    ///     println!("Got alert on: {}", message.get_channel_name());
+    ///     false
+    /// });
+    /// router.set_fallback_handler(|message: Message| -> bool {
+    ///     println!("Unexpected message on: {}", message.get_channel_name());
+    ///     false
+    /// });
+    ///
+    /// client.route_messages(&mut router, &mut || -> Interrupts { Interrupts::new() }).unwrap();
+    /// ```
+    pub fn route_messages(
+        self: &mut Client,
+        router: &mut MessageRouter,
+        poll_interrupts: &mut dyn FnMut() -> Interrupts,
+    ) -> RedisEmptyResult {
+        for channel in router.get_channels() {
+            if !self.is_subscribed(&channel) {
+                self.subscribe(&channel)?;
+            }
+        }
+
+        for pattern in router.get_patterns() {
+            if !self.is_psubscribed(&pattern) {
+                self.psubscribe(&pattern)?;
+            }
+        }
+
+        self.fetch_messages(
+            &mut |message: Message| router.route(message),
+            poll_interrupts,
+        )
+    }
+}
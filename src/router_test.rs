@@ -0,0 +1,164 @@
+use super::*;
+use redis::Value;
+
+fn create_message(pattern: Option<&str>, channel: &str, payload: &str) -> Message {
+    let mut values = vec![];
+    match pattern {
+        Some(pattern) => {
+            values.push(Value::BulkString(b"pmessage".to_vec()));
+            values.push(Value::BulkString(pattern.as_bytes().to_vec()));
+        }
+        None => values.push(Value::BulkString(b"message".to_vec())),
+    }
+    values.push(Value::BulkString(channel.as_bytes().to_vec()));
+    values.push(Value::BulkString(payload.as_bytes().to_vec()));
+
+    Message::from_owned_value(Value::Array(values)).unwrap()
+}
+
+#[test]
+fn matches_pattern_wildcards() {
+    assert!(matches_pattern("news", "news"));
+    assert!(!matches_pattern("news", "news1"));
+    assert!(matches_pattern("*", "news"));
+    assert!(matches_pattern("news.*", "news.art"));
+    assert!(matches_pattern("news.*", "news."));
+    assert!(!matches_pattern("news.*", "news"));
+    assert!(matches_pattern("n*s", "news"));
+    assert!(matches_pattern("n**s", "news"));
+    assert!(!matches_pattern("n*s", "newt"));
+    assert!(matches_pattern("*.*.done", "a.b.c.done"));
+    assert!(matches_pattern("h?llo", "hello"));
+    assert!(!matches_pattern("h?llo", "hllo"));
+}
+
+#[test]
+fn matches_pattern_sets() {
+    assert!(matches_pattern("h[ae]llo", "hello"));
+    assert!(matches_pattern("h[ae]llo", "hallo"));
+    assert!(!matches_pattern("h[ae]llo", "hillo"));
+    assert!(matches_pattern("h[^e]llo", "hallo"));
+    assert!(!matches_pattern("h[^e]llo", "hello"));
+    assert!(matches_pattern("h[a-b]llo", "hbllo"));
+    assert!(matches_pattern("h[b-a]llo", "hallo"));
+    assert!(!matches_pattern("h[a-b]llo", "hcllo"));
+    assert!(matches_pattern("h[\\]]llo", "h]llo"));
+    assert!(matches_pattern("h[ab", "ha"));
+}
+
+#[test]
+fn matches_pattern_escape() {
+    assert!(matches_pattern("news\\*", "news*"));
+    assert!(!matches_pattern("news\\*", "news1"));
+    assert!(matches_pattern("news\\?", "news?"));
+    assert!(!matches_pattern("news\\?", "newsa"));
+}
+
+#[test]
+fn route_channel_before_pattern() {
+    let mut channel_messages = 0;
+    let mut pattern_messages = 0;
+
+    {
+        let mut router = MessageRouter::new();
+        router.add_pattern_handler("news.*", |_| {
+            pattern_messages += 1;
+            false
+        });
+        router.add_channel_handler("news.art", |message: Message| {
+            channel_messages += 1;
+            message.get_payload::<String>().unwrap() == "stop"
+        });
+
+        assert!(!router.route(create_message(None, "news.art", "test")));
+        assert!(router.route(create_message(None, "news.art", "stop")));
+        assert!(!router.route(create_message(None, "news.music", "test")));
+    }
+
+    assert_eq!(channel_messages, 2);
+    assert_eq!(pattern_messages, 1);
+}
+
+#[test]
+fn route_by_message_pattern() {
+    let mut first_messages = 0;
+    let mut second_messages = 0;
+
+    {
+        let mut router = MessageRouter::new();
+        router.add_pattern_handler("news.*", |_| {
+            first_messages += 1;
+            false
+        });
+        router.add_pattern_handler("news.a*", |_| {
+            second_messages += 1;
+            false
+        });
+
+        router.route(create_message(Some("news.a*"), "news.art", "test"));
+        router.route(create_message(None, "news.art", "test"));
+    }
+
+    assert_eq!(first_messages, 1);
+    assert_eq!(second_messages, 1);
+}
+
+#[test]
+fn route_pattern_message_before_channel() {
+    let mut channel_messages = 0;
+    let mut pattern_messages = 0;
+
+    {
+        let mut router = MessageRouter::new();
+        router.add_channel_handler("news.art", |_| {
+            channel_messages += 1;
+            false
+        });
+        router.add_pattern_handler("news.*", |_| {
+            pattern_messages += 1;
+            false
+        });
+
+        router.route(create_message(Some("news.*"), "news.art", "test"));
+        router.route(create_message(None, "news.art", "test"));
+    }
+
+    assert_eq!(channel_messages, 1);
+    assert_eq!(pattern_messages, 1);
+}
+
+#[test]
+fn route_fallback() {
+    let mut channels = vec![];
+
+    {
+        let mut router = MessageRouter::new();
+        router.add_channel_handler("news", |_| false);
+
+        assert!(!router.route(create_message(None, "other", "test")));
+
+        router.set_fallback_handler(|message: Message| {
+            channels.push(message.get_channel_name().to_string());
+            true
+        });
+
+        assert!(!router.route(create_message(None, "news", "test")));
+        assert!(router.route(create_message(None, "other", "test")));
+    }
+
+    assert_eq!(channels, vec!["other".to_string()]);
+}
+
+#[test]
+fn add_handlers_replace() {
+    let mut router = MessageRouter::new();
+    router.add_channel_handler("news", |_| false);
+    router.add_channel_handler("news", |_| true);
+    router.add_pattern_handler("news.*", |_| false);
+    router.add_pattern_handler("news.*", |_| true);
+
+    assert_eq!(router.get_channels(), vec!["news".to_string()]);
+    assert_eq!(router.get_patterns(), vec!["news.*".to_string()]);
+    assert!(router.route(create_message(None, "news", "test")));
+    assert!(router.route(create_message(None, "news.art", "test")));
+}
@@ -0,0 +1,169 @@
+//! # script
+//!
+//! Implements lua scripting support with EVALSHA caching.
+//!
+
+#[cfg(test)]
+#[path = "./script_test.rs"]
+mod script_test;
+
+use crate::client::Client;
+use crate::types::{
+    create_parse_error, RedisBoolResult, RedisEmptyResult, RedisError, RedisResult,
+    RedisStringResult,
+};
+use std::str::FromStr;
+
+/// Holds a lua script and its SHA1 hash.<br>
+/// The script is invoked via EVALSHA and only loaded to the redis server in case it is not already cached.
+///
+/// # Example
+///
+/// ```
+/// # let mut client = simple_redis::create("redis://127.0.0.1:6379/").unwrap();
+/// let script = simple_redis::Script::new("return tonumber(ARGV[1]) + tonumber(ARGV[2])");
+///
+/// match client.run_script::<i64>(&script, vec![], vec!["1", "2"]) {
+///     Ok(value) => assert_eq!(value, 3),
+///     Err(error) => println!("Unable to run script: {}", error),
+/// }
+/// ```
+#[derive(Debug, Clone)]
+pub struct Script {
+    /// The lua source code
+    code: String,
+    /// The SHA1 hash of the source code in hex format
+    hash: String,
+}
+
+impl Script {
+    /// Returns a new script for the provided lua source code.
+    pub fn new(code: &str) -> Script {
+        let redis_script = redis::Script::new(code);
+
+        Script {
+            code: code.to_string(),
+            hash: redis_script.get_hash().to_string(),
+        }
+    }
+
+    /// Returns the lua source code.
+    pub fn get_code(self: &Script) -> &str {
+        &self.code
+    }
+
+    /// Returns the SHA1 hash of the source code in hex format.
+    pub fn get_hash(self: &Script) -> &str {
+        &self.hash
+    }
+}
+
+/// Returns the EVAL/EVALSHA/FCALL arguments in the form of: `script numkeys [key ...] [arg ...]`
+pub(crate) fn create_eval_args(script: &str, keys: Vec<&str>, args: Vec<&str>) -> Vec<String> {
+    let mut eval_args = Vec::with_capacity(keys.len() + args.len() + 2);
+
+    eval_args.push(script.to_string());
+    eval_args.push(keys.len().to_string());
+    for key in keys {
+        eval_args.push(key.to_string());
+    }
+    for arg in args {
+        eval_args.push(arg.to_string());
+    }
+
+    eval_args
+}
+
+fn is_no_script_error(error: &RedisError) -> bool {
+    match error {
+        RedisError::ServerError { code, .. } => code == "NOSCRIPT",
+        _ => false,
+    }
+}
+
+impl Client {
+    /// See redis [EVAL](https://redis.io/commands/eval) command.
+    pub fn eval<T: redis::FromRedisValue>(
+        self: &mut Client,
+        code: &str,
+        keys: Vec<&str>,
+        args: Vec<&str>,
+    ) -> RedisResult<T> {
+        let eval_args = create_eval_args(code, keys, args);
+
+        self.run_command("EVAL", eval_args.iter().map(|arg| arg.as_str()).collect())
+    }
+
+    /// See redis [EVALSHA](https://redis.io/commands/evalsha) command.
+    pub fn evalsha<T: redis::FromRedisValue>(
+        self: &mut Client,
+        sha1: &str,
+        keys: Vec<&str>,
+        args: Vec<&str>,
+    ) -> RedisResult<T> {
+        let eval_args = create_eval_args(sha1, keys, args);
+
+        self.run_command(
+            "EVALSHA",
+            eval_args.iter().map(|arg| arg.as_str()).collect(),
+        )
+    }
+
+    /// See redis [SCRIPT LOAD](https://redis.io/commands/script-load) command.
+    pub fn script_load(self: &mut Client, code: &str) -> RedisStringResult {
+        self.run_command::<String>("SCRIPT", vec!["LOAD", code])
+    }
+
+    /// See redis [SCRIPT EXISTS](https://redis.io/commands/script-exists) command.
+    pub fn script_exists(self: &mut Client, sha1: &str) -> RedisBoolResult {
+        match self.run_command::<Vec<bool>>("SCRIPT", vec!["EXISTS", sha1]) {
+            Ok(values) => Ok(values.first().copied().unwrap_or(false)),
+            Err(error) => Err(error),
+        }
+    }
+
+    /// See redis [SCRIPT FLUSH](https://redis.io/commands/script-flush) command.
+    pub fn script_flush(self: &mut Client) -> RedisEmptyResult {
+        self.run_command_empty_response("SCRIPT", vec!["FLUSH"])
+    }
+
+    /// Invokes the provided script via EVALSHA.<br>
+    /// In case the script is not cached by the redis server, it is loaded (via SCRIPT LOAD) and invoked again.
+    ///
+    /// # Arguments
+    ///
+    /// * `script` - The script to invoke
+    /// * `keys` - The keys passed to the script (KEYS)
+    /// * `args` - The additional arguments passed to the script (ARGV)
+    pub fn run_script<T: redis::FromRedisValue>(
+        self: &mut Client,
+        script: &Script,
+        keys: Vec<&str>,
+        args: Vec<&str>,
+    ) -> RedisResult<T> {
+        match self.evalsha(script.get_hash(), keys.clone(), args.clone()) {
+            Err(ref error) if is_no_script_error(error) => {
+                self.script_load(script.get_code())?;
+
+                self.evalsha(script.get_hash(), keys, args)
+            }
+            result => result,
+        }
+    }
+
+    /// Invokes the provided script (see run_script) and parses the string output to the requested type.
+    pub fn run_script_from_string_response<T: FromStr>(
+        self: &mut Client,
+        script: &Script,
+        keys: Vec<&str>,
+        args: Vec<&str>,
+    ) -> RedisResult<T> {
+        match self.run_script::<String>(script, keys, args) {
+            Ok(value) => match T::from_str(&value) {
+                Ok(typed_value) => Ok(typed_value),
+                _ => Err(create_parse_error::<T>(None, None, Some(value))),
+            },
+            Err(error) => Err(error),
+        }
+    }
+}
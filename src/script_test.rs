@@ -0,0 +1,36 @@
+use super::*;
+
+#[test]
+fn new_script() {
+    let script = Script::new("return 1");
+
+    assert_eq!(script.get_code(), "return 1");
+    assert_eq!(
+        script.get_hash(),
+        "e0e1f9fabfc9d4800c877a703b823ac0578ff8db"
+    );
+}
+
+#[test]
+fn create_eval_args_empty() {
+    let eval_args = create_eval_args("return 1", vec![], vec![]);
+
+    assert_eq!(eval_args, vec!["return 1", "0"]);
+}
+
+#[test]
+fn create_eval_args_keys_and_args() {
+    let eval_args = create_eval_args("sha", vec!["key1", "key2"], vec!["arg1"]);
+
+    assert_eq!(eval_args, vec!["sha", "2", "key1", "key2", "arg1"]);
+}
+
+#[test]
+fn is_no_script_error_check() {
+    let error: RedisError =
+        redis::RedisError::from((redis::ErrorKind::NoScriptError, "NOSCRIPT")).into();
+    assert!(is_no_script_error(&error));
+
+    let error = RedisError::UnexpectedResponse("test");
+    assert!(!is_no_script_error(&error));
+}
@@ -0,0 +1,151 @@
+//! # sentinel
+//!
+//! Resolves the current primary server of a sentinel managed master.
+//!
+
+#[cfg(test)]
+#[path = "./sentinel_test.rs"]
+mod sentinel_test;
+
+use crate::types::RedisError;
+use std::time::Duration;
+
+/// The connection string scheme of sentinel managed masters
+pub(crate) const SENTINEL_SCHEME: &str = "redis+sentinel://";
+/// The default sentinel port
+const DEFAULT_SENTINEL_PORT: u16 = 26379;
+/// The max time to wait while connecting to a sentinel, unless a connect timeout is defined
+const DEFAULT_SENTINEL_TIMEOUT: Duration = Duration::from_secs(1);
+
+#[derive(Debug, Clone)]
+/// Holds the sentinels and master information used to resolve the current primary server.
+pub(crate) struct Sentinel {
+    /// The sentinels connection info
+    sentinels: Vec<redis::ConnectionInfo>,
+    /// The master name as monitored by the sentinels
+    master_name: String,
+    /// The database and credentials used when connecting to the primary server
+    pub(crate) redis: redis::RedisConnectionInfo,
+}
+
+/// Returns true if the connection string defines a sentinel managed master.
+pub(crate) fn is_sentinel_connection_string(connection_string: &str) -> bool {
+    connection_string.starts_with(SENTINEL_SCHEME)
+}
+
+/// Parses the connection string in the format of:
+/// `redis+sentinel://[[<username>]:<passwd>@]<host>[:port][,<host>[:port]...]/<master name>[/<db>]`
+pub(crate) fn parse(connection_string: &str) -> Result<Sentinel, RedisError> {
+    let value = match connection_string.strip_prefix(SENTINEL_SCHEME) {
+        Some(value) => value,
+        None => {
+            return Err(RedisError::InvalidInput(
+                "Invalid sentinel connection string.",
+            ))
+        }
+    };
+
+    let (authority, path) = match value.split_once('/') {
+        Some(parts) => parts,
+        None => return Err(RedisError::InvalidInput("Missing sentinel master name.")),
+    };
+    let (user_info, hosts) = match authority.rsplit_once('@') {
+        Some((user_info, hosts)) => (Some(user_info), hosts),
+        None => (None, authority),
+    };
+    let (master_name, db) = match path.split_once('/') {
+        Some((master_name, db)) => (master_name, db),
+        None => (path, ""),
+    };
+
+    if master_name.is_empty() {
+        return Err(RedisError::InvalidInput("Missing sentinel master name."));
+    }
+
+    let mut sentinels = vec![];
+    for host in hosts.split(',').filter(|host| !host.is_empty()) {
+        let address = if host.contains(':') {
+            host.to_string()
+        } else {
+            format!("{}:{}", host, DEFAULT_SENTINEL_PORT)
+        };
+
+        sentinels.push(redis::IntoConnectionInfo::into_connection_info(
+            format!("redis://{}/", address).as_str(),
+        )?);
+    }
+
+    if sentinels.is_empty() {
+        return Err(RedisError::InvalidInput("Missing sentinel address."));
+    }
+
+    // the primary credentials and database are parsed (and decoded) the same as for a single server
+    let primary_connection_string = match user_info {
+        Some(user_info) => format!("redis://{}@localhost/{}", user_info, db),
+        None => format!("redis://localhost/{}", db),
+    };
+    let primary_info =
+        redis::IntoConnectionInfo::into_connection_info(primary_connection_string.as_str())?;
+
+    Ok(Sentinel {
+        sentinels,
+        master_name: master_name.to_string(),
+        redis: primary_info.redis,
+    })
+}
+
+/// Returns the master address as reported by the provided sentinel, or None if the master is not known to it.
+fn get_master_address(
+    sentinel: &redis::ConnectionInfo,
+    master_name: &str,
+    timeout: Duration,
+) -> redis::RedisResult<Option<(String, u16)>> {
+    let client = redis::Client::open(sentinel.clone())?;
+    let mut connection = client.get_connection_with_timeout(timeout)?;
+    connection.set_read_timeout(Some(timeout))?;
+
+    redis::cmd("SENTINEL")
+        .arg("get-master-addr-by-name")
+        .arg(master_name)
+        .query(&mut connection)
+}
+
+impl Sentinel {
+    /// Asks the sentinels (one after the other) for the current primary address (see
+    /// [SENTINEL GET-MASTER-ADDR-BY-NAME](https://redis.io/docs/latest/operate/oss_and_stack/management/sentinel/))
+    /// and returns a client for that server.
+    pub(crate) fn get_primary_client(
+        self: &Sentinel,
+        connect_timeout: Option<Duration>,
+    ) -> redis::RedisResult<redis::Client> {
+        let timeout = connect_timeout.unwrap_or(DEFAULT_SENTINEL_TIMEOUT);
+        let mut last_error = None;
+
+        for sentinel in &self.sentinels {
+            match get_master_address(sentinel, &self.master_name, timeout) {
+                Ok(Some((host, port))) => {
+                    return redis::Client::open(redis::ConnectionInfo {
+                        addr: redis::ConnectionAddr::Tcp(host, port),
+                        redis: self.redis.clone(),
+                    });
+                }
+                Ok(None) => {
+                    last_error = Some(redis::RedisError::from((
+                        redis::ErrorKind::MasterNameNotFoundBySentinel,
+                        "Master name not found by sentinel",
+                        self.master_name.clone(),
+                    )))
+                }
+                Err(error) => last_error = Some(error),
+            }
+        }
+
+        match last_error {
+            Some(error) => Err(error),
+            None => Err(redis::RedisError::from((
+                redis::ErrorKind::EmptySentinelList,
+                "No sentinels defined",
+            ))),
+        }
+    }
+}
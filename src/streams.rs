@@ -0,0 +1,534 @@
+//! # streams
+//!
+//! Defines the redis streams commands exposed by the redis client.
+//!
+
+#[cfg(test)]
+#[path = "./streams_test.rs"]
+mod streams_test;
+
+use crate::client::Client;
+use crate::pipeline::convert_value;
+use crate::types::{
+    RedisBoolResult, RedisEmptyResult, RedisError, RedisResult, RedisStringResult,
+    StreamAutoClaimResult, StreamEntry, StreamPendingEntry, StreamTrim,
+};
+use std::collections::HashMap;
+use std::time::Duration;
+
+/// Converts a single stream entry in the form of `[id, [field, value, ...]]` to a stream entry.<br>
+/// Deleted entries (returned by some commands with empty fields) are converted to entries without fields.
+pub(crate) fn parse_stream_entry(value: redis::Value) -> RedisResult<StreamEntry> {
+    let mut values: Vec<redis::Value> = convert_value(value)?;
+    if values.len() != 2 {
+        return Err(RedisError::UnexpectedResponse("Unexpected stream entry."));
+    }
+
+    let fields_value = values.pop().unwrap_or(redis::Value::Nil);
+    let id = convert_value(values.pop().unwrap_or(redis::Value::Nil))?;
+    let fields = match fields_value {
+        redis::Value::Nil => HashMap::new(),
+        value => convert_value(value)?,
+    };
+
+    Ok(StreamEntry { id, fields })
+}
+
+/// Converts a list of stream entries.
+pub(crate) fn parse_stream_entries(value: redis::Value) -> RedisResult<Vec<StreamEntry>> {
+    let values: Vec<redis::Value> = match value {
+        redis::Value::Nil => vec![],
+        value => convert_value(value)?,
+    };
+
+    let mut entries = Vec::with_capacity(values.len());
+    for value in values {
+        // older redis versions return nil for claimed entries which no longer exist
+        if value != redis::Value::Nil {
+            entries.push(parse_stream_entry(value)?);
+        }
+    }
+
+    Ok(entries)
+}
+
+/// Converts the XREAD/XREADGROUP response to the (stream key, entries) pairs.<br>
+/// The response is either a list of `[key, entries]` pairs or a map (RESP3), and nil in case of a timeout.
+pub(crate) fn parse_read_response(
+    value: redis::Value,
+) -> RedisResult<Vec<(String, Vec<StreamEntry>)>> {
+    let pairs = match value {
+        redis::Value::Nil => vec![],
+        redis::Value::Map(pairs) => pairs,
+        redis::Value::Array(values) => {
+            let mut pairs = Vec::with_capacity(values.len());
+            for value in values {
+                let mut pair: Vec<redis::Value> = convert_value(value)?;
+                if pair.len() != 2 {
+                    return Err(RedisError::UnexpectedResponse(
+                        "Unexpected stream read response.",
+                    ));
+                }
+
+                let entries = pair.pop().unwrap_or(redis::Value::Nil);
+                let key = pair.pop().unwrap_or(redis::Value::Nil);
+                pairs.push((key, entries));
+            }
+
+            pairs
+        }
+        _ => {
+            return Err(RedisError::UnexpectedResponse(
+                "Unexpected stream read response.",
+            ))
+        }
+    };
+
+    let mut output = Vec::with_capacity(pairs.len());
+    for (key, entries) in pairs {
+        output.push((convert_value(key)?, parse_stream_entries(entries)?));
+    }
+
+    Ok(output)
+}
+
+/// Converts the extended XPENDING response to the pending entries.
+pub(crate) fn parse_pending_response(value: redis::Value) -> RedisResult<Vec<StreamPendingEntry>> {
+    let values: Vec<(String, String, u64, u64)> = convert_value(value)?;
+
+    Ok(values
+        .into_iter()
+        .map(|(id, consumer, idle, delivery_count)| StreamPendingEntry {
+            id,
+            consumer,
+            idle: Duration::from_millis(idle),
+            delivery_count,
+        })
+        .collect())
+}
+
+/// Converts the XAUTOCLAIM response in the form of `[next id, entries, deleted ids]`.<br>
+/// The deleted IDs are only returned by redis 7 and above.
+pub(crate) fn parse_autoclaim_response(value: redis::Value) -> RedisResult<StreamAutoClaimResult> {
+    let values: Vec<redis::Value> = convert_value(value)?;
+    if values.len() < 2 {
+        return Err(RedisError::UnexpectedResponse(
+            "Unexpected stream claim response.",
+        ));
+    }
+
+    let mut iterator = values.into_iter();
+    let next_id = convert_value(iterator.next().unwrap_or(redis::Value::Nil))?;
+    let entries = parse_stream_entries(iterator.next().unwrap_or(redis::Value::Nil))?;
+    let deleted_ids = match iterator.next() {
+        Some(value) => convert_value(value)?,
+        None => vec![],
+    };
+
+    Ok(StreamAutoClaimResult {
+        next_id,
+        entries,
+        deleted_ids,
+    })
+}
+
+/// Returns true if the error was returned since the consumer group already exists.
+pub(crate) fn is_busy_group_error(error: &RedisError) -> bool {
+    match error {
+        RedisError::ServerError { code, .. } => code == "BUSYGROUP",
+        _ => false,
+    }
+}
+
+/// Returns the trim arguments, for example: `MAXLEN ~ 1000`
+pub(crate) fn create_trim_args(trim: &StreamTrim) -> Vec<String> {
+    let (strategy, threshold, approximate) = match trim {
+        StreamTrim::MaxLen { count, approximate } => ("MAXLEN", count.to_string(), approximate),
+        StreamTrim::MinId { id, approximate } => ("MINID", id.to_string(), approximate),
+    };
+
+    let operator = if *approximate { "~" } else { "=" };
+
+    vec![strategy.to_string(), operator.to_string(), threshold]
+}
+
+/// Returns the `COUNT count` arguments in case a count is provided.
+pub(crate) fn create_count_args(count: Option<usize>) -> Vec<String> {
+    match count {
+        Some(count) => vec!["COUNT".to_string(), count.to_string()],
+        None => vec![],
+    }
+}
+
+/// Returns the `BLOCK milliseconds` arguments in case a block timeout is provided.
+pub(crate) fn create_block_args(block: Option<Duration>) -> Vec<String> {
+    match block {
+        Some(block) => vec!["BLOCK".to_string(), block.as_millis().to_string()],
+        None => vec![],
+    }
+}
+
+/// Returns the `STREAMS key [key ...] id [id ...]` arguments.
+pub(crate) fn create_streams_args(streams: Vec<(&str, &str)>) -> Vec<String> {
+    let mut args = Vec::with_capacity(streams.len() * 2 + 1);
+
+    args.push("STREAMS".to_string());
+    for (key, _) in &streams {
+        args.push(key.to_string());
+    }
+    for (_, id) in &streams {
+        args.push(id.to_string());
+    }
+
+    args
+}
+
+impl Client {
+    /// See redis [XADD](https://redis.io/commands/xadd) command.<br>
+    /// Returns the ID of the added entry.
+    ///
+    /// # Arguments
+    ///
+    /// * `key` - The stream key
+    /// * `id` - The entry ID, if not provided, the ID is generated by redis (*)
+    /// * `fields` - The entry (field, value) pairs
+    /// * `trim` - If provided, the stream is trimmed as part of the command
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # let mut client = simple_redis::create("redis://127.0.0.1:6379/").unwrap();
+    /// let trim = simple_redis::types::StreamTrim::MaxLen { count: 1000, approximate: true };
+    ///
+    /// match client.xadd("my_stream", None, vec![("event", "login"), ("user", "user1")], Some(trim)) {
+    ///     Ok(id) => println!("Added entry: {}", id),
+    ///     Err(error) => println!("Unable to add entry: {}", error),
+    /// }
+    /// ```
+    pub fn xadd(
+        self: &mut Client,
+        key: &str,
+        id: Option<&str>,
+        fields: Vec<(&str, &str)>,
+        trim: Option<StreamTrim>,
+    ) -> RedisStringResult {
+        let trim_args = match trim {
+            Some(ref trim) => create_trim_args(trim),
+            None => vec![],
+        };
+
+        let mut args = vec![key];
+        args.extend(trim_args.iter().map(|arg| arg.as_str()));
+        args.push(id.unwrap_or("*"));
+        for (field, value) in fields {
+            args.push(field);
+            args.push(value);
+        }
+
+        self.run_key_command_string_response("XADD", args, key, None)
+    }
+
+    /// See redis [XRANGE](https://redis.io/commands/xrange) command.
+    ///
+    /// # Arguments
+    ///
+    /// * `key` - The stream key
+    /// * `start` - The min entry ID, `-` for the first entry
+    /// * `end` - The max entry ID, `+` for the last entry
+    /// * `count` - If provided, the max amount of returned entries
+    pub fn xrange(
+        self: &mut Client,
+        key: &str,
+        start: &str,
+        end: &str,
+        count: Option<usize>,
+    ) -> RedisResult<Vec<StreamEntry>> {
+        let count_args = create_count_args(count);
+
+        let mut args = vec![key, start, end];
+        args.extend(count_args.iter().map(|arg| arg.as_str()));
+
+        let value = self.run_command::<redis::Value>("XRANGE", args)?;
+
+        parse_stream_entries(value)
+    }
+
+    /// See redis [XREVRANGE](https://redis.io/commands/xrevrange) command.<br>
+    /// The entries are returned in reverse order (newest first).
+    ///
+    /// # Arguments
+    ///
+    /// * `key` - The stream key
+    /// * `end` - The max entry ID, `+` for the last entry
+    /// * `start` - The min entry ID, `-` for the first entry
+    /// * `count` - If provided, the max amount of returned entries
+    pub fn xrevrange(
+        self: &mut Client,
+        key: &str,
+        end: &str,
+        start: &str,
+        count: Option<usize>,
+    ) -> RedisResult<Vec<StreamEntry>> {
+        let count_args = create_count_args(count);
+
+        let mut args = vec![key, end, start];
+        args.extend(count_args.iter().map(|arg| arg.as_str()));
+
+        let value = self.run_command::<redis::Value>("XREVRANGE", args)?;
+
+        parse_stream_entries(value)
+    }
+
+    /// See redis [XREAD](https://redis.io/commands/xread) command.<br>
+    /// Returns the (stream key, entries) pairs of all streams with new entries, or an empty list in case
+    /// the block timeout was reached.<br>
+    /// The block timeout should be lower than the client read timeout (if defined).
+    ///
+    /// # Arguments
+    ///
+    /// * `streams` - The (stream key, last read entry ID) pairs, use `$` to only read entries added from now on
+    /// * `count` - If provided, the max amount of returned entries per stream
+    /// * `block` - If provided, max time to block until new entries are added, zero will block indefinitely
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # let mut client = simple_redis::create("redis://127.0.0.1:6379/").unwrap();
+    /// # use std::time::Duration;
+    /// match client.xread(vec![("my_stream", "0")], Some(10), Some(Duration::from_millis(100))) {
+    ///     Ok(streams) => {
+    ///         for (key, entries) in streams {
+    ///             println!("Read {} entries from: {}", entries.len(), key);
+    ///         }
+    ///     }
+    ///     Err(error) => println!("Unable to read streams: {}", error),
+    /// }
+    /// ```
+    pub fn xread(
+        self: &mut Client,
+        streams: Vec<(&str, &str)>,
+        count: Option<usize>,
+        block: Option<Duration>,
+    ) -> RedisResult<Vec<(String, Vec<StreamEntry>)>> {
+        let mut args = create_count_args(count);
+        args.extend(create_block_args(block));
+        args.extend(create_streams_args(streams));
+
+        let value = self
+            .run_command::<redis::Value>("XREAD", args.iter().map(|arg| arg.as_str()).collect())?;
+
+        parse_read_response(value)
+    }
+
+    /// See redis [XLEN](https://redis.io/commands/xlen) command.
+    pub fn xlen(self: &mut Client, key: &str) -> RedisResult<i64> {
+        self.run_command("XLEN", vec![key])
+    }
+
+    /// See redis [XDEL](https://redis.io/commands/xdel) command.<br>
+    /// Returns the amount of deleted entries.
+    pub fn xdel(self: &mut Client, key: &str, ids: Vec<&str>) -> RedisResult<i64> {
+        let mut args = vec![key];
+        args.extend(ids);
+
+        self.run_command("XDEL", args)
+    }
+
+    /// See redis [XTRIM](https://redis.io/commands/xtrim) command.<br>
+    /// Returns the amount of evicted entries.
+    pub fn xtrim(self: &mut Client, key: &str, trim: StreamTrim) -> RedisResult<i64> {
+        let trim_args = create_trim_args(&trim);
+
+        let mut args = vec![key];
+        args.extend(trim_args.iter().map(|arg| arg.as_str()));
+
+        self.run_command("XTRIM", args)
+    }
+
+    /// See redis [XGROUP CREATE](https://redis.io/commands/xgroup-create) command.
+    ///
+    /// # Arguments
+    ///
+    /// * `key` - The stream key
+    /// * `group` - The consumer group name
+    /// * `id` - The last delivered entry ID, `$` to only deliver entries added from now on or `0` for all entries
+    /// * `mkstream` - If true, the stream is created in case it does not exist
+    pub fn xgroup_create(
+        self: &mut Client,
+        key: &str,
+        group: &str,
+        id: &str,
+        mkstream: bool,
+    ) -> RedisEmptyResult {
+        let mut args = vec!["CREATE", key, group, id];
+        if mkstream {
+            args.push("MKSTREAM");
+        }
+
+        self.run_command_empty_response("XGROUP", args)
+    }
+
+    /// See redis [XGROUP DESTROY](https://redis.io/commands/xgroup-destroy) command.<br>
+    /// Returns true if the consumer group existed and was destroyed.
+    pub fn xgroup_destroy(self: &mut Client, key: &str, group: &str) -> RedisBoolResult {
+        self.run_command_bool_response("XGROUP", vec!["DESTROY", key, group])
+    }
+
+    /// See redis [XGROUP SETID](https://redis.io/commands/xgroup-setid) command.
+    pub fn xgroup_setid(self: &mut Client, key: &str, group: &str, id: &str) -> RedisEmptyResult {
+        self.run_command_empty_response("XGROUP", vec!["SETID", key, group, id])
+    }
+
+    /// See redis [XREADGROUP](https://redis.io/commands/xreadgroup) command.<br>
+    /// Returns the (stream key, entries) pairs of all streams with entries, or an empty list in case
+    /// the block timeout was reached.
+    ///
+    /// # Arguments
+    ///
+    /// * `group` - The consumer group name
+    /// * `consumer` - The consumer name
+    /// * `streams` - The (stream key, ID) pairs, use `>` for new entries or any other ID for the consumer pending entries
+    /// * `count` - If provided, the max amount of returned entries per stream
+    /// * `block` - If provided, max time to block until new entries are added, zero will block indefinitely
+    /// * `noack` - If true, the entries are not added to the pending list and do not require acknowledgment
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # let mut client = simple_redis::create("redis://127.0.0.1:6379/").unwrap();
+    /// # client.xgroup_create("my_stream", "my_group", "$", true).ok();
+    /// match client.xreadgroup("my_group", "consumer1", vec![("my_stream", ">")], Some(10), None, false) {
+    ///     Ok(streams) => {
+    ///         for (key, entries) in streams {
+    ///             for entry in entries {
+    ///                 println!("Read entry: {} from: {}", entry.id, key);
+    ///                 client.xack(&key, "my_group", vec![&entry.id]).unwrap();
+    ///             }
+    ///         }
+    ///     }
+    ///     Err(error) => println!("Unable to read streams: {}", error),
+    /// }
+    /// ```
+    pub fn xreadgroup(
+        self: &mut Client,
+        group: &str,
+        consumer: &str,
+        streams: Vec<(&str, &str)>,
+        count: Option<usize>,
+        block: Option<Duration>,
+        noack: bool,
+    ) -> RedisResult<Vec<(String, Vec<StreamEntry>)>> {
+        let mut args = vec!["GROUP".to_string(), group.to_string(), consumer.to_string()];
+        args.extend(create_count_args(count));
+        args.extend(create_block_args(block));
+        if noack {
+            args.push("NOACK".to_string());
+        }
+        args.extend(create_streams_args(streams));
+
+        let value = self.run_command::<redis::Value>(
+            "XREADGROUP",
+            args.iter().map(|arg| arg.as_str()).collect(),
+        )?;
+
+        parse_read_response(value)
+    }
+
+    /// See redis [XACK](https://redis.io/commands/xack) command.<br>
+    /// Returns the amount of acknowledged entries.
+    pub fn xack(self: &mut Client, key: &str, group: &str, ids: Vec<&str>) -> RedisResult<i64> {
+        let mut args = vec![key, group];
+        args.extend(ids);
+
+        self.run_command("XACK", args)
+    }
+
+    /// See redis [XPENDING](https://redis.io/commands/xpending) command (extended form).
+    ///
+    /// # Arguments
+    ///
+    /// * `key` - The stream key
+    /// * `group` - The consumer group name
+    /// * `start` - The min entry ID, `-` for the first entry
+    /// * `end` - The max entry ID, `+` for the last entry
+    /// * `count` - The max amount of returned entries
+    /// * `consumer` - If provided, only entries pending on this consumer are returned
+    pub fn xpending(
+        self: &mut Client,
+        key: &str,
+        group: &str,
+        start: &str,
+        end: &str,
+        count: usize,
+        consumer: Option<&str>,
+    ) -> RedisResult<Vec<StreamPendingEntry>> {
+        let count_string = count.to_string();
+
+        let mut args = vec![key, group, start, end, &count_string];
+        if let Some(consumer) = consumer {
+            args.push(consumer);
+        }
+
+        let value = self.run_command::<redis::Value>("XPENDING", args)?;
+
+        parse_pending_response(value)
+    }
+
+    /// See redis [XCLAIM](https://redis.io/commands/xclaim) command.<br>
+    /// Returns the claimed entries.
+    ///
+    /// # Arguments
+    ///
+    /// * `key` - The stream key
+    /// * `group` - The consumer group name
+    /// * `consumer` - The consumer which claims the entries
+    /// * `min_idle` - Only entries pending for at least this time are claimed
+    /// * `ids` - The entry IDs to claim
+    pub fn xclaim(
+        self: &mut Client,
+        key: &str,
+        group: &str,
+        consumer: &str,
+        min_idle: Duration,
+        ids: Vec<&str>,
+    ) -> RedisResult<Vec<StreamEntry>> {
+        let min_idle_string = min_idle.as_millis().to_string();
+
+        let mut args = vec![key, group, consumer, &min_idle_string];
+        args.extend(ids);
+
+        let value = self.run_command::<redis::Value>("XCLAIM", args)?;
+
+        parse_stream_entries(value)
+    }
+
+    /// See redis [XAUTOCLAIM](https://redis.io/commands/xautoclaim) command.
+    ///
+    /// # Arguments
+    ///
+    /// * `key` - The stream key
+    /// * `group` - The consumer group name
+    /// * `consumer` - The consumer which claims the entries
+    /// * `min_idle` - Only entries pending for at least this time are claimed
+    /// * `start` - The entry ID to start scanning from, `0-0` to scan from the start
+    /// * `count` - If provided, the max amount of entries to claim
+    pub fn xautoclaim(
+        self: &mut Client,
+        key: &str,
+        group: &str,
+        consumer: &str,
+        min_idle: Duration,
+        start: &str,
+        count: Option<usize>,
+    ) -> RedisResult<StreamAutoClaimResult> {
+        let min_idle_string = min_idle.as_millis().to_string();
+        let count_args = create_count_args(count);
+
+        let mut args = vec![key, group, consumer, &min_idle_string, start];
+        args.extend(count_args.iter().map(|arg| arg.as_str()));
+
+        let value = self.run_command::<redis::Value>("XAUTOCLAIM", args)?;
+
+        parse_autoclaim_response(value)
+    }
+}
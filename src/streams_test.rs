@@ -0,0 +1,189 @@
+use super::*;
+
+fn bulk(value: &str) -> redis::Value {
+    redis::Value::BulkString(value.as_bytes().to_vec())
+}
+
+fn entry_value(id: &str, fields: Vec<&str>) -> redis::Value {
+    redis::Value::Array(vec![
+        bulk(id),
+        redis::Value::Array(fields.into_iter().map(bulk).collect()),
+    ])
+}
+
+#[test]
+fn parse_stream_entry_valid() {
+    let entry = parse_stream_entry(entry_value("1-0", vec!["field1", "value1"])).unwrap();
+
+    assert_eq!(entry.id, "1-0");
+    assert_eq!(entry.fields.len(), 1);
+    assert_eq!(entry.fields.get("field1").unwrap(), "value1");
+}
+
+#[test]
+fn parse_stream_entry_deleted() {
+    let entry =
+        parse_stream_entry(redis::Value::Array(vec![bulk("1-0"), redis::Value::Nil])).unwrap();
+
+    assert_eq!(entry.id, "1-0");
+    assert!(entry.fields.is_empty());
+}
+
+#[test]
+fn parse_stream_entry_invalid() {
+    let result = parse_stream_entry(redis::Value::Array(vec![bulk("1-0")]));
+
+    assert!(result.is_err());
+}
+
+#[test]
+fn parse_stream_entries_nil() {
+    let entries = parse_stream_entries(redis::Value::Nil).unwrap();
+
+    assert!(entries.is_empty());
+}
+
+#[test]
+fn parse_read_response_array() {
+    let value = redis::Value::Array(vec![
+        redis::Value::Array(vec![
+            bulk("stream1"),
+            redis::Value::Array(vec![
+                entry_value("1-0", vec!["a", "1"]),
+                entry_value("2-0", vec!["b", "2"]),
+            ]),
+        ]),
+        redis::Value::Array(vec![
+            bulk("stream2"),
+            redis::Value::Array(vec![entry_value("3-0", vec!["c", "3"])]),
+        ]),
+    ]);
+
+    let streams = parse_read_response(value).unwrap();
+
+    assert_eq!(streams.len(), 2);
+    assert_eq!(streams[0].0, "stream1");
+    assert_eq!(streams[0].1.len(), 2);
+    assert_eq!(streams[0].1[1].id, "2-0");
+    assert_eq!(streams[1].0, "stream2");
+    assert_eq!(streams[1].1[0].fields.get("c").unwrap(), "3");
+}
+
+#[test]
+fn parse_read_response_map() {
+    let value = redis::Value::Map(vec![(
+        bulk("stream1"),
+        redis::Value::Array(vec![entry_value("1-0", vec!["a", "1"])]),
+    )]);
+
+    let streams = parse_read_response(value).unwrap();
+
+    assert_eq!(streams.len(), 1);
+    assert_eq!(streams[0].0, "stream1");
+    assert_eq!(streams[0].1[0].id, "1-0");
+}
+
+#[test]
+fn parse_read_response_nil() {
+    let streams = parse_read_response(redis::Value::Nil).unwrap();
+
+    assert!(streams.is_empty());
+}
+
+#[test]
+fn create_trim_args_values() {
+    let mut args = create_trim_args(&StreamTrim::MaxLen {
+        count: 100,
+        approximate: true,
+    });
+    assert_eq!(args, vec!["MAXLEN", "~", "100"]);
+
+    args = create_trim_args(&StreamTrim::MinId {
+        id: "10-0".to_string(),
+        approximate: false,
+    });
+    assert_eq!(args, vec!["MINID", "=", "10-0"]);
+}
+
+#[test]
+fn create_read_args_values() {
+    assert!(create_count_args(None).is_empty());
+    assert_eq!(create_count_args(Some(5)), vec!["COUNT", "5"]);
+    assert!(create_block_args(None).is_empty());
+    assert_eq!(
+        create_block_args(Some(Duration::from_secs(2))),
+        vec!["BLOCK", "2000"]
+    );
+    assert_eq!(
+        create_streams_args(vec![("s1", "0"), ("s2", "$")]),
+        vec!["STREAMS", "s1", "s2", "0", "$"]
+    );
+}
+
+#[test]
+fn parse_pending_response_valid() {
+    let value = redis::Value::Array(vec![redis::Value::Array(vec![
+        bulk("1-0"),
+        bulk("consumer1"),
+        redis::Value::Int(1500),
+        redis::Value::Int(3),
+    ])]);
+
+    let entries = parse_pending_response(value).unwrap();
+
+    assert_eq!(
+        entries,
+        vec![StreamPendingEntry {
+            id: "1-0".to_string(),
+            consumer: "consumer1".to_string(),
+            idle: Duration::from_millis(1500),
+            delivery_count: 3,
+        }]
+    );
+}
+
+#[test]
+fn parse_autoclaim_response_with_deleted() {
+    let value = redis::Value::Array(vec![
+        bulk("5-0"),
+        redis::Value::Array(vec![entry_value("1-0", vec!["a", "1"]), redis::Value::Nil]),
+        redis::Value::Array(vec![bulk("2-0")]),
+    ]);
+
+    let result = parse_autoclaim_response(value).unwrap();
+
+    assert_eq!(result.next_id, "5-0");
+    assert_eq!(result.entries.len(), 1);
+    assert_eq!(result.entries[0].id, "1-0");
+    assert_eq!(result.deleted_ids, vec!["2-0"]);
+}
+
+#[test]
+fn parse_autoclaim_response_without_deleted() {
+    let value = redis::Value::Array(vec![bulk("0-0"), redis::Value::Array(vec![])]);
+
+    let result = parse_autoclaim_response(value).unwrap();
+
+    assert_eq!(result.next_id, "0-0");
+    assert!(result.entries.is_empty());
+    assert!(result.deleted_ids.is_empty());
+}
+
+#[test]
+fn is_busy_group_error_values() {
+    let busy_group = redis::parse_redis_value(b"-BUSYGROUP Consumer Group name already exists\r\n")
+        .unwrap()
+        .extract_error()
+        .unwrap_err();
+    assert!(is_busy_group_error(&busy_group.into()));
+
+    let other = redis::parse_redis_value(b"-ERR other\r\n")
+        .unwrap()
+        .extract_error()
+        .unwrap_err();
+    assert!(!is_busy_group_error(&other.into()));
+
+    assert!(!is_busy_group_error(&RedisError::UnexpectedResponse(
+        "test"
+    )));
+}
@@ -7,60 +7,119 @@
 #[path = "./subscriber_test.rs"]
 mod subscriber_test;
 
-use crate::types::{Interrupts, Message, RedisEmptyResult, RedisError};
-use std::time::Duration;
+use crate::connection::{self, ConnectionOptions, Server};
+use crate::types::{
+    Interrupts, Message, RedisEmptyResult, RedisError, SubscriptionChange, SubscriptionEvent,
+    SubscriptionHandle,
+};
+use std::thread;
+use std::time::{Duration, Instant};
+
+/// The max time to wait for a message before applying the changes requested via the subscription handle
+const CHANGES_POLLING_INTERVAL: Duration = Duration::from_millis(100);
 
 /// The redis pubsub wrapper.
 pub(crate) struct Subscriber {
     subscriptions: Vec<String>,
     psubscriptions: Vec<String>,
     redis_connection: Option<redis::Connection>,
+    /// Holds the subscription changes requested via the subscription handles
+    handle: SubscriptionHandle,
 }
 
 fn subscribe_all<'a>(
-    subscriber: &'a mut Subscriber,
-    client: &redis::Client,
+    redis_connection: &'a mut Option<redis::Connection>,
+    subscriptions: &[String],
+    psubscriptions: &[String],
+    server: &Server,
+    options: &ConnectionOptions,
 ) -> Result<redis::PubSub<'a>, RedisError> {
     // get pubsub
-    match client.get_connection() {
-        Ok(redis_connection) => {
-            let redis_connection_ref = subscriber.redis_connection.get_or_insert(redis_connection);
+    match connection::open(server, options) {
+        Ok(new_connection) => {
+            // always replace the previous connection which may no longer be valid
+            let redis_connection_ref = redis_connection.insert(new_connection);
             let mut redis_pubsub = redis_connection_ref.as_pubsub();
 
-            for channel in &subscriber.subscriptions {
-                let result = redis_pubsub.subscribe(channel);
-
-                if result.is_err() {
-                    let subscription_error = match result.err() {
-                        Some(error) => Err(RedisError::RedisError(error)),
-                        None => Err(RedisError::Description("Error while subscribing.")),
-                    };
+            for channel in subscriptions {
+                if let Err(error) = redis_pubsub.subscribe(channel) {
+                    return Err(error.into());
+                }
+            }
 
-                    return subscription_error;
+            for channel in psubscriptions {
+                if let Err(error) = redis_pubsub.psubscribe(channel) {
+                    return Err(error.into());
                 }
             }
 
-            for channel in &subscriber.psubscriptions {
-                let result = redis_pubsub.psubscribe(channel);
+            Ok(redis_pubsub)
+        }
+        Err(error) => Err(error.into()),
+    }
+}
 
-                if result.is_err() {
-                    let subscription_error = match result.err() {
-                        Some(error) => Err(RedisError::RedisError(error)),
-                        None => Err(RedisError::Description("Error while subscribing.")),
-                    };
+/// Adds the channel to the provided subscriptions and returns true if it was not already there.
+fn add_channel(channels: &mut Vec<String>, channel: &str) -> bool {
+    if channels.iter().any(|value| value == channel) {
+        false
+    } else {
+        channels.push(channel.to_string());
+        true
+    }
+}
 
-                    return subscription_error;
-                }
+/// Removes the channel from the provided subscriptions and returns true if it was there.
+fn remove_channel(channels: &mut Vec<String>, channel: &str) -> bool {
+    match channels.iter().position(|value| value == channel) {
+        Some(index) => {
+            channels.remove(index);
+            true
+        }
+        None => false,
+    }
+}
+
+/// Applies the pending subscription changes on the subscriptions and, if provided, on the live pubsub.
+fn apply_changes(
+    changes: Vec<SubscriptionChange>,
+    subscriptions: &mut Vec<String>,
+    psubscriptions: &mut Vec<String>,
+    mut redis_pubsub: Option<&mut redis::PubSub>,
+) -> RedisEmptyResult {
+    for change in changes {
+        // the subscriptions are updated first so they are issued again in case of reconnection
+        let modified = match change {
+            SubscriptionChange::Subscribe(ref channel) => add_channel(subscriptions, channel),
+            SubscriptionChange::PSubscribe(ref channel) => add_channel(psubscriptions, channel),
+            SubscriptionChange::Unsubscribe(ref channel) => remove_channel(subscriptions, channel),
+            SubscriptionChange::PUnsubscribe(ref channel) => {
+                remove_channel(psubscriptions, channel)
             }
+        };
+
+        if let (true, Some(ref mut pubsub)) = (modified, redis_pubsub.as_mut()) {
+            let result = match change {
+                SubscriptionChange::Subscribe(channel) => pubsub.subscribe(channel),
+                SubscriptionChange::PSubscribe(channel) => pubsub.psubscribe(channel),
+                SubscriptionChange::Unsubscribe(channel) => pubsub.unsubscribe(channel),
+                SubscriptionChange::PUnsubscribe(channel) => pubsub.punsubscribe(channel),
+            };
 
-            Ok(redis_pubsub)
+            if let Err(error) = result {
+                return Err(error.into());
+            }
         }
-        Err(error) => Err(RedisError::RedisError(error)),
     }
+
+    Ok(())
 }
 
 fn fetch_messages(
     mut redis_pubsub: redis::PubSub,
+    subscriptions: &mut Vec<String>,
+    psubscriptions: &mut Vec<String>,
+    handle: &SubscriptionHandle,
     on_message: &mut dyn FnMut(Message) -> bool,
     poll_interrupts: &mut dyn FnMut() -> Interrupts,
 ) -> RedisEmptyResult {
@@ -68,29 +127,48 @@ fn fetch_messages(
         let interrupts = poll_interrupts();
         if interrupts.stop {
             return Ok(());
-        } else {
-            let duration_millis = interrupts.next_polling_time.unwrap_or(5000);
+        }
 
-            let read_timeout = if duration_millis == 0 {
-                None
-            } else {
-                Some(Duration::from_millis(duration_millis))
+        let polling_time = match interrupts.next_polling_time.unwrap_or(5000) {
+            0 => None,
+            duration_millis => Some(Duration::from_millis(duration_millis)),
+        };
+        let start_time = Instant::now();
+
+        loop {
+            apply_changes(
+                handle.take_changes(),
+                subscriptions,
+                psubscriptions,
+                Some(&mut redis_pubsub),
+            )?;
+
+            // reads are limited to short intervals so changes requested via the handle are applied
+            // without waiting for the entire polling time
+            let read_timeout = match polling_time {
+                Some(polling_time) => match polling_time.checked_sub(start_time.elapsed()) {
+                    Some(remaining) if !remaining.is_zero() => {
+                        remaining.min(CHANGES_POLLING_INTERVAL)
+                    }
+                    _ => break,
+                },
+                None => CHANGES_POLLING_INTERVAL,
             };
-            if let Err(error) = redis_pubsub.set_read_timeout(read_timeout) {
-                return Err(RedisError::RedisError(error));
+            if let Err(error) = redis_pubsub.set_read_timeout(Some(read_timeout)) {
+                return Err(error.into());
             };
 
-            let message_result = redis_pubsub.get_message();
-
-            match message_result {
+            match redis_pubsub.get_message() {
                 Ok(message) => {
                     if on_message(message) {
                         return Ok(());
                     }
+
+                    break;
                 }
                 Err(error) => {
                     if !error.is_timeout() {
-                        return Err(RedisError::RedisError(error));
+                        return Err(error.into());
                     }
                 }
             }
@@ -98,15 +176,73 @@ fn fetch_messages(
     }
 }
 
+/// Subscribes to all channels and fetches the messages.<br>
+/// In case the connection is lost, a new connection is opened based on the reconnect policy and all
+/// subscriptions are issued again.
 fn subscribe_and_fetch(
     subscriber: &mut Subscriber,
-    client: &redis::Client,
+    server: &Server,
+    options: &ConnectionOptions,
     on_message: &mut dyn FnMut(Message) -> bool,
+    on_event: &mut dyn FnMut(SubscriptionEvent),
     poll_interrupts: &mut dyn FnMut() -> Interrupts,
 ) -> RedisEmptyResult {
-    match subscribe_all(subscriber, client) {
-        Err(error) => Err(error),
-        Ok(pubsub) => fetch_messages(pubsub, on_message, poll_interrupts),
+    let mut reconnecting = false;
+    let mut start_time = Instant::now();
+    let mut attempt = 0;
+
+    loop {
+        // the pubsub borrows the subscriber connection so it must be dropped before handling the result
+        let subscribe_result = match subscribe_all(
+            &mut subscriber.redis_connection,
+            &subscriber.subscriptions,
+            &subscriber.psubscriptions,
+            server,
+            options,
+        ) {
+            Ok(pubsub) => {
+                if reconnecting {
+                    on_event(SubscriptionEvent::Reconnected);
+                }
+
+                Ok(fetch_messages(
+                    pubsub,
+                    &mut subscriber.subscriptions,
+                    &mut subscriber.psubscriptions,
+                    &subscriber.handle,
+                    on_message,
+                    poll_interrupts,
+                ))
+            }
+            Err(error) => Err(error),
+        };
+        subscriber.redis_connection = None;
+
+        match subscribe_result {
+            Ok(Err(error)) if error.is_connection_error() => {
+                reconnecting = true;
+                start_time = Instant::now();
+                attempt = 0;
+                on_event(SubscriptionEvent::Disconnected(error));
+            }
+            Ok(result) => return result,
+            Err(error) => {
+                attempt += 1;
+
+                match connection::get_reconnect_delay(
+                    &options.reconnect_policy,
+                    attempt,
+                    start_time.elapsed(),
+                ) {
+                    Some(delay) => thread::sleep(delay),
+                    None => return Err(error),
+                }
+
+                if poll_interrupts().stop {
+                    return Ok(());
+                }
+            }
+        }
     }
 }
 
@@ -159,44 +295,61 @@ impl Subscriber {
     }
 
     pub(crate) fn is_subscribed(self: &mut Subscriber, channel: &str) -> bool {
-        let search_result = self.subscriptions.iter().position(|x| x == channel);
-
-        match search_result {
-            None => false,
-            _ => true,
-        }
+        self.subscriptions.iter().any(|x| x == channel)
     }
 
     pub(crate) fn is_psubscribed(self: &mut Subscriber, channel: &str) -> bool {
-        let search_result = self.psubscriptions.iter().position(|x| x == channel);
-
-        match search_result {
-            None => false,
-            _ => true,
-        }
+        self.psubscriptions.iter().any(|x| x == channel)
     }
 
     pub(crate) fn unsubscribe_all(self: &mut Subscriber) -> RedisEmptyResult {
+        self.handle.take_changes();
         self.subscriptions.clear();
         self.psubscriptions.clear();
 
         Ok(())
     }
 
+    /// Returns a handle which enables to modify the subscriptions while fetching messages.
+    pub(crate) fn get_handle(self: &Subscriber) -> SubscriptionHandle {
+        self.handle.clone()
+    }
+
+    /// Returns a new subscriber with the same subscriptions and pending changes, having its own subscription
+    /// handle, so changes requested for one subscriber do not affect the other.
+    pub(crate) fn detach(self: &Subscriber) -> Subscriber {
+        Subscriber {
+            subscriptions: self.subscriptions.clone(),
+            psubscriptions: self.psubscriptions.clone(),
+            redis_connection: None,
+            handle: self.handle.duplicate(),
+        }
+    }
+
     fn has_subscriptions(self: &Subscriber) -> bool {
         !self.subscriptions.is_empty() || !self.psubscriptions.is_empty()
     }
 
     pub(crate) fn fetch_messages(
         self: &mut Subscriber,
-        client: &redis::Client,
+        server: &Server,
+        options: &ConnectionOptions,
         on_message: &mut dyn FnMut(Message) -> bool,
+        on_event: &mut dyn FnMut(SubscriptionEvent),
         poll_interrupts: &mut dyn FnMut() -> Interrupts,
     ) -> RedisEmptyResult {
+        // changes requested while not fetching only modify the subscriptions
+        apply_changes(
+            self.handle.take_changes(),
+            &mut self.subscriptions,
+            &mut self.psubscriptions,
+            None,
+        )?;
+
         if !self.has_subscriptions() {
-            Err(RedisError::Description("No subscriptions defined."))
+            Err(RedisError::NoSubscriptions)
         } else {
-            subscribe_and_fetch(self, client, on_message, poll_interrupts)
+            subscribe_and_fetch(self, server, options, on_message, on_event, poll_interrupts)
         }
     }
 }
@@ -207,5 +360,6 @@ pub(crate) fn create() -> Subscriber {
         subscriptions: vec![],
         psubscriptions: vec![],
         redis_connection: None,
+        handle: SubscriptionHandle::new(),
     }
 }
@@ -7,3 +7,165 @@ fn create_check_state() {
     assert_eq!(subscriber.psubscriptions.len(), 0);
     assert!(subscriber.redis_connection.is_none());
 }
+
+#[test]
+fn fetch_messages_no_subscriptions() {
+    let server = Server::Fixed(redis::Client::open("redis://127.0.0.1:6379/").unwrap());
+    let mut subscriber = create();
+
+    let result = subscriber.fetch_messages(
+        &server,
+        &ConnectionOptions::default(),
+        &mut |_| true,
+        &mut |_| panic!("test error"),
+        &mut || Interrupts::new(),
+    );
+
+    match result {
+        Err(RedisError::NoSubscriptions) => (),
+        _ => panic!("Invalid result"),
+    }
+}
+
+#[test]
+fn fetch_messages_connection_refused() {
+    let server = Server::Fixed(redis::Client::open("redis://127.0.0.1:1/").unwrap());
+    let mut subscriber = create();
+    subscriber.subscribe("test").unwrap();
+
+    let mut options = ConnectionOptions::default();
+    options.reconnect_policy.max_attempts = 3;
+    options.reconnect_policy.initial_delay = Duration::from_millis(1);
+
+    let mut polls = 0;
+    let result = subscriber.fetch_messages(
+        &server,
+        &options,
+        &mut |_| true,
+        &mut |_| panic!("test error"),
+        &mut || {
+            polls += 1;
+            Interrupts::new()
+        },
+    );
+
+    match result {
+        Err(ref error) => assert!(error.is_connection_error()),
+        _ => panic!("Invalid result"),
+    }
+    // interrupts are polled between connection attempts
+    assert_eq!(polls, 2);
+    assert!(subscriber.redis_connection.is_none());
+}
+
+#[test]
+fn fetch_messages_connection_refused_stopped() {
+    let server = Server::Fixed(redis::Client::open("redis://127.0.0.1:1/").unwrap());
+    let mut subscriber = create();
+    subscriber.subscribe("test").unwrap();
+
+    let mut options = ConnectionOptions::default();
+    options.reconnect_policy.max_attempts = 10;
+    options.reconnect_policy.initial_delay = Duration::from_millis(1);
+
+    let result = subscriber.fetch_messages(
+        &server,
+        &options,
+        &mut |_| true,
+        &mut |_| panic!("test error"),
+        &mut || {
+            let mut interrupts = Interrupts::new();
+            interrupts.stop = true;
+            interrupts
+        },
+    );
+
+    assert!(result.is_ok());
+}
+
+#[test]
+fn handle_changes_applied_on_fetch() {
+    let server = Server::Fixed(redis::Client::open("redis://127.0.0.1:1/").unwrap());
+    let mut subscriber = create();
+    let handle = subscriber.get_handle();
+
+    handle.subscribe("sub1");
+    handle.subscribe("sub1");
+    handle.psubscribe("sub2*");
+    handle.subscribe("sub3");
+    handle.unsubscribe("sub3");
+    assert!(!subscriber.is_subscribed("sub1"));
+
+    let result = subscriber.fetch_messages(
+        &server,
+        &ConnectionOptions::default(),
+        &mut |_| true,
+        &mut |_| panic!("test error"),
+        &mut || Interrupts::new(),
+    );
+    assert!(result.is_err());
+
+    assert_eq!(subscriber.subscriptions, vec!["sub1".to_string()]);
+    assert_eq!(subscriber.psubscriptions, vec!["sub2*".to_string()]);
+    assert!(subscriber.handle.take_changes().is_empty());
+}
+
+#[test]
+fn unsubscribe_all_clears_handle_changes() {
+    let mut subscriber = create();
+    let handle = subscriber.get_handle();
+
+    handle.subscribe("sub1");
+    subscriber.unsubscribe_all().unwrap();
+
+    assert!(subscriber.handle.take_changes().is_empty());
+}
+
+#[test]
+fn detach_own_handle() {
+    let mut subscriber = create();
+    subscriber.subscribe("sub1").unwrap();
+    subscriber.get_handle().subscribe("sub2");
+
+    let detached = subscriber.detach();
+    assert_eq!(detached.subscriptions, vec!["sub1".to_string()]);
+
+    detached.get_handle().subscribe("sub3");
+    subscriber.get_handle().psubscribe("sub4*");
+
+    assert_eq!(
+        subscriber.handle.take_changes(),
+        vec![
+            SubscriptionChange::Subscribe("sub2".to_string()),
+            SubscriptionChange::PSubscribe("sub4*".to_string())
+        ]
+    );
+    assert_eq!(
+        detached.handle.take_changes(),
+        vec![
+            SubscriptionChange::Subscribe("sub2".to_string()),
+            SubscriptionChange::Subscribe("sub3".to_string())
+        ]
+    );
+}
+
+#[test]
+fn apply_changes_without_pubsub() {
+    let mut subscriptions = vec!["sub1".to_string()];
+    let mut psubscriptions = vec![];
+
+    apply_changes(
+        vec![
+            SubscriptionChange::Unsubscribe("sub1".to_string()),
+            SubscriptionChange::PSubscribe("sub2*".to_string()),
+            SubscriptionChange::PUnsubscribe("other*".to_string()),
+        ],
+        &mut subscriptions,
+        &mut psubscriptions,
+        None,
+    )
+    .unwrap();
+
+    assert!(subscriptions.is_empty());
+    assert_eq!(psubscriptions, vec!["sub2*".to_string()]);
+}
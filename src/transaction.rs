@@ -0,0 +1,127 @@
+//! # transaction
+//!
+//! Implements MULTI/EXEC transactions with WATCH based optimistic locking.
+//!
+
+#[cfg(test)]
+#[path = "./transaction_test.rs"]
+mod transaction_test;
+
+use crate::client::Client;
+use crate::pipeline::{Pipeline, PipelineResults};
+use crate::types::{RedisEmptyResult, RedisError, RedisResult};
+
+/// Converts the EXEC response to the transaction results.<br>
+/// Returns None in case the transaction was aborted due to a modification of a watched key.
+pub(crate) fn parse_exec_response(
+    mut values: Vec<redis::Value>,
+) -> Option<RedisResult<PipelineResults>> {
+    match values.pop() {
+        Some(redis::Value::Nil) => None,
+        Some(redis::Value::Array(values)) => Some(Ok(PipelineResults { values })),
+        Some(redis::Value::ServerError(error)) => Some(Err(redis::RedisError::from(error).into())),
+        _ => Some(Err(RedisError::UnexpectedResponse(
+            "Unexpected transaction response.",
+        ))),
+    }
+}
+
+fn unwatch(client: &mut Client) -> RedisEmptyResult {
+    client.run_command_empty_response("UNWATCH", vec![])
+}
+
+impl Client {
+    /// Invokes the commands added by the provided builder atomically (via MULTI/EXEC).<br>
+    /// The provided keys are watched (via WATCH) before the builder is invoked, so the builder can read their
+    /// current values using the provided client and queue commands based on them.<br>
+    /// In case any of the watched keys was modified before the transaction was executed, the entire flow is
+    /// invoked again, up to the provided max retries.
+    ///
+    /// # Arguments
+    ///
+    /// * `keys` - The keys to watch
+    /// * `max_retries` - Max amount of times to retry the transaction in case a watched key was modified
+    /// * `build` - Invoked on every attempt and adds the transaction commands to the provided pipeline
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use simple_redis::client::Client;
+    /// # use simple_redis::types::RedisEmptyResult;
+    /// # use simple_redis::Pipeline;
+    /// # let mut client = simple_redis::create("redis://127.0.0.1:6379/").unwrap();
+    /// # client.set("my_counter", 1).unwrap();
+    /// let results = client.transaction(
+    ///     vec!["my_counter"],
+    ///     10,
+    ///     &mut |client: &mut Client, pipeline: &mut Pipeline| -> RedisEmptyResult {
+    ///         let value = client.get::<i64>("my_counter")?;
+    ///         pipeline.set("my_counter", value * 2);
+    ///         Ok(())
+    ///     },
+    /// );
+    ///
+    /// match results {
+    ///     Ok(_) => println!("Counter doubled"),
+    ///     Err(error) => println!("Transaction failed: {}", error),
+    /// }
+    /// ```
+    pub fn transaction(
+        self: &mut Client,
+        keys: Vec<&str>,
+        max_retries: usize,
+        build: &mut dyn FnMut(&mut Client, &mut Pipeline) -> RedisEmptyResult,
+    ) -> RedisResult<PipelineResults> {
+        let mut attempt = 0;
+        loop {
+            let generation = match self.get_redis_connection() {
+                Ok((_, generation)) => generation,
+                Err(error) => return Err(error),
+            };
+            if !keys.is_empty() {
+                self.run_command_empty_response("WATCH", keys.clone())?;
+            }
+
+            let mut pipeline = Pipeline::new();
+            self.watching = true;
+            let build_result = build(self, &mut pipeline);
+            self.watching = false;
+            if let Err(error) = build_result {
+                unwatch(self).ok();
+                return Err(error);
+            }
+
+            if pipeline.is_empty() {
+                unwatch(self)?;
+                return Ok(PipelineResults { values: vec![] });
+            }
+
+            let packed = pipeline.pack(true);
+            let offset = pipeline.len() + 1;
+            let result = match self.get_redis_connection() {
+                // in case the connection was replaced, the watched keys are no longer watched
+                Ok((_, current_generation)) if current_generation != generation => Ok(None),
+                Ok((connection, _)) => {
+                    match redis::ConnectionLike::req_packed_commands(connection, &packed, offset, 1)
+                    {
+                        Ok(values) => Ok(parse_exec_response(values)),
+                        Err(error) => Err(error.into()),
+                    }
+                }
+                Err(error) => Err(error),
+            };
+            self.close_on_connection_error(&result);
+
+            let output = result?;
+            match output {
+                Some(result) => return result,
+                None => {
+                    attempt += 1;
+                    if attempt > max_retries {
+                        return Err(RedisError::TransactionAborted);
+                    }
+                }
+            }
+        }
+    }
+}
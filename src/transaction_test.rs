@@ -0,0 +1,39 @@
+use super::*;
+
+#[test]
+fn parse_exec_response_aborted() {
+    let output = parse_exec_response(vec![redis::Value::Nil]);
+    assert!(output.is_none());
+}
+
+#[test]
+fn parse_exec_response_values() {
+    let output = parse_exec_response(vec![redis::Value::Array(vec![
+        redis::Value::Okay,
+        redis::Value::Int(5),
+    ])]);
+
+    let results = output.unwrap().unwrap();
+    assert_eq!(results.len(), 2);
+    let value: i64 = results.get(1).unwrap();
+    assert_eq!(value, 5);
+}
+
+#[test]
+fn parse_exec_response_error() {
+    let output = parse_exec_response(vec![redis::parse_redis_value(
+        b"-EXECABORT Transaction discarded because of previous errors.\r\n",
+    )
+    .unwrap()]);
+
+    let result = output.unwrap();
+    assert!(result.is_err());
+}
+
+#[test]
+fn parse_exec_response_empty() {
+    let output = parse_exec_response(vec![]);
+
+    let result = output.unwrap();
+    assert!(result.is_err());
+}
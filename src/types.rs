@@ -7,17 +7,153 @@
 #[path = "./types_test.rs"]
 mod types_test;
 
+use std::collections::HashMap;
 use std::error::Error;
 use std::fmt;
 use std::fmt::Display;
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
 
 #[derive(Debug)]
 /// Holds the error information
 pub enum RedisError {
-    /// Root redis error
+    /// Root redis error, for errors which are not covered by the other variants
     RedisError(redis::RedisError),
-    /// Description text of the error reason
-    Description(&'static str),
+    /// Unable to connect to the redis server or the connection was lost
+    NotConnected(Option<redis::RedisError>),
+    /// The operation did not complete in time
+    Timeout(Option<redis::RedisError>),
+    /// The requested key does not exist (or does not hold a value)
+    NotFound {
+        /// The requested key
+        key: String,
+        /// The requested hash field (if the error relates to a specific field)
+        field: Option<String>,
+    },
+    /// Unable to convert the returned value to the requested type
+    Parse {
+        /// The requested key (if known)
+        key: Option<String>,
+        /// The hash field holding the value (if the error relates to a specific field)
+        field: Option<String>,
+        /// The returned value (if available as text)
+        raw_value: Option<String>,
+        /// The requested type name
+        target_type: &'static str,
+    },
+    /// The operation was invoked against a key holding the wrong kind of value
+    WrongType {
+        /// The server error message
+        message: String,
+    },
+    /// Fetching messages was requested without any subscriptions
+    NoSubscriptions,
+    /// The transaction was aborted due to modification of watched keys
+    TransactionAborted,
+    /// No pooled client became available before the pool checkout timeout
+    PoolExhausted,
+    /// The redis server returned an error
+    ServerError {
+        /// The error code, for example: `ERR` or `BUSYGROUP`
+        code: String,
+        /// The error message
+        message: String,
+    },
+    /// Unable to encode or decode a value using the requested codec
+    Serialization(String),
+    /// The redis server returned a response in an unexpected format
+    UnexpectedResponse(&'static str),
+    /// The provided input (for example options or an index) is not valid
+    InvalidInput(&'static str),
+}
+
+impl RedisError {
+    /// Returns true if the error means the connection can no longer be used.<br>
+    /// Such errors cause the connection to be replaced on the next operation.
+    pub fn is_connection_error(&self) -> bool {
+        matches!(self, Self::NotConnected(_) | Self::Timeout(_))
+    }
+
+    /// Returns true if invoking the same operation again may succeed.<br>
+    /// Note that non idempotent operations may have been executed by the server even if an error was returned.
+    pub fn is_retryable(&self) -> bool {
+        match self {
+            Self::NotConnected(_) | Self::Timeout(_) | Self::TransactionAborted => true,
+            Self::ServerError { code, .. } => RETRYABLE_SERVER_ERROR_CODES.contains(&code.as_str()),
+            _ => false,
+        }
+    }
+}
+
+/// Server error codes of temporary failures
+/// The idle time after which a connection is verified by the default health check
+const DEFAULT_HEALTH_CHECK_IDLE_TIME: Duration = Duration::from_secs(1);
+
+const RETRYABLE_SERVER_ERROR_CODES: &[&str] = &[
+    "BUSY",
+    "CLUSTERDOWN",
+    "LOADING",
+    "MASTERDOWN",
+    "READONLY",
+    "TRYAGAIN",
+];
+
+impl From<redis::RedisError> for RedisError {
+    /// Converts the redis error to the matching error variant.
+    fn from(error: redis::RedisError) -> Self {
+        if error.is_timeout() {
+            return Self::Timeout(Some(error));
+        }
+        if error.is_io_error() || error.is_connection_dropped() || error.is_connection_refusal() {
+            return Self::NotConnected(Some(error));
+        }
+
+        // only errors returned by the redis server have an error code
+        let server_error = error.code().map(|code| {
+            (
+                code.to_string(),
+                error.detail().unwrap_or_default().to_string(),
+            )
+        });
+
+        match server_error {
+            Some((code, message)) if code == "WRONGTYPE" => Self::WrongType { message },
+            Some((code, message)) => Self::ServerError { code, message },
+            None => Self::RedisError(error),
+        }
+    }
+}
+
+/// Converts the redis error, returning a parse error for the requested type in case the value
+/// could not be converted.
+pub(crate) fn from_redis_error<T>(error: redis::RedisError) -> RedisError {
+    if error.kind() == redis::ErrorKind::TypeError {
+        create_parse_error::<T>(None, None, None)
+    } else {
+        error.into()
+    }
+}
+
+/// Returns a not found error for the provided key and field.
+pub(crate) fn create_not_found_error(key: &str, field: Option<&str>) -> RedisError {
+    RedisError::NotFound {
+        key: key.to_string(),
+        field: field.map(|field| field.to_string()),
+    }
+}
+
+/// Returns a parse error for the requested type.
+pub(crate) fn create_parse_error<T>(
+    key: Option<&str>,
+    field: Option<&str>,
+    raw_value: Option<String>,
+) -> RedisError {
+    RedisError::Parse {
+        key: key.map(|key| key.to_string()),
+        field: field.map(|field| field.to_string()),
+        raw_value,
+        target_type: std::any::type_name::<T>(),
+    }
 }
 
 impl Display for RedisError {
@@ -25,7 +161,47 @@ impl Display for RedisError {
     fn fmt(&self, format: &mut fmt::Formatter) -> Result<(), fmt::Error> {
         match self {
             Self::RedisError(ref cause) => cause.fmt(format),
-            Self::Description(description) => description.fmt(format),
+            Self::NotConnected(Some(ref cause)) => write!(format, "Not connected: {}", cause),
+            Self::NotConnected(None) => write!(format, "Redis connection not available."),
+            Self::Timeout(Some(ref cause)) => write!(format, "Timeout: {}", cause),
+            Self::Timeout(None) => write!(format, "Timeout while waiting for the operation."),
+            Self::NotFound { key, field } => match field {
+                Some(field) => write!(format, "Key: {} field: {} not found.", key, field),
+                None => write!(format, "Key: {} not found.", key),
+            },
+            Self::Parse {
+                key,
+                field,
+                target_type,
+                ..
+            } => match (key, field) {
+                (Some(key), Some(field)) => write!(
+                    format,
+                    "Unable to parse field: {} of key: {} to type: {}.",
+                    field, key, target_type
+                ),
+                (Some(key), None) => write!(
+                    format,
+                    "Unable to parse value of key: {} to type: {}.",
+                    key, target_type
+                ),
+                _ => write!(format, "Unable to parse value to type: {}.", target_type),
+            },
+            Self::WrongType { message } => write!(format, "WRONGTYPE {}", message),
+            Self::NoSubscriptions => write!(format, "No subscriptions defined."),
+            Self::TransactionAborted => write!(
+                format,
+                "Transaction aborted due to modification of watched keys."
+            ),
+            Self::PoolExhausted => write!(
+                format,
+                "No pooled client available before the checkout timeout."
+            ),
+            Self::ServerError { code, message } => write!(format, "{} {}", code, message),
+            Self::Serialization(message) => write!(format, "Serialization error: {}", message),
+            Self::UnexpectedResponse(description) | Self::InvalidInput(description) => {
+                description.fmt(format)
+            }
         }
     }
 }
@@ -33,8 +209,10 @@ impl Display for RedisError {
 impl Error for RedisError {
     fn source(&self) -> Option<&(dyn Error + 'static)> {
         match self {
-            Self::RedisError(error) => Some(error),
-            Self::Description(_) => None,
+            Self::RedisError(error)
+            | Self::NotConnected(Some(error))
+            | Self::Timeout(Some(error)) => Some(error),
+            _ => None,
         }
     }
 }
@@ -48,7 +226,7 @@ macro_rules! as_redis_arg {
     };
 }
 
-impl<'a> RedisArg for &'a str {}
+impl RedisArg for &str {}
 
 as_redis_arg!(u8);
 as_redis_arg!(i8);
@@ -96,3 +274,380 @@ impl Interrupts {
         Default::default()
     }
 }
+
+#[derive(Debug)]
+/// Notifies about changes in the state of the subscriptions connection while fetching messages.
+pub enum SubscriptionEvent {
+    /// The subscriptions connection was lost, messages published until the connection is reopened will be missed
+    Disconnected(RedisError),
+    /// The connection was reopened and all subscriptions were issued again
+    Reconnected,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+/// A subscription change requested via the subscription handle.
+pub(crate) enum SubscriptionChange {
+    Subscribe(String),
+    PSubscribe(String),
+    Unsubscribe(String),
+    PUnsubscribe(String),
+}
+
+#[derive(Debug, Clone, Default)]
+/// Enables to modify the client subscriptions while messages are being fetched.<br>
+/// The handle can be cloned and moved to other threads or into the fetch_messages callbacks.<br>
+/// Requested changes are sent on the live subscriptions connection before the next message is read.<br>
+/// While waiting for messages, the connection is checked for requested changes every 100 milliseconds, so changes
+/// requested from other threads are applied shortly after, regardless of the interrupts polling time.<br>
+/// In case messages are not being fetched, the changes are applied on the next fetch.
+pub struct SubscriptionHandle {
+    changes: Arc<Mutex<Vec<SubscriptionChange>>>,
+}
+
+impl SubscriptionHandle {
+    /// Returns a new instance.
+    pub(crate) fn new() -> SubscriptionHandle {
+        Default::default()
+    }
+
+    fn push(self: &SubscriptionHandle, change: SubscriptionChange) {
+        let mut changes = self
+            .changes
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner());
+        changes.push(change);
+    }
+
+    /// Returns a new independent handle holding a copy of the pending changes.
+    pub(crate) fn duplicate(self: &SubscriptionHandle) -> SubscriptionHandle {
+        let changes = self
+            .changes
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner());
+
+        SubscriptionHandle {
+            changes: Arc::new(Mutex::new(changes.clone())),
+        }
+    }
+
+    /// Returns all pending changes and clears them.
+    pub(crate) fn take_changes(self: &SubscriptionHandle) -> Vec<SubscriptionChange> {
+        let mut changes = self
+            .changes
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner());
+
+        std::mem::take(&mut *changes)
+    }
+
+    /// Subscribes to the provided channel.
+    pub fn subscribe(self: &SubscriptionHandle, channel: &str) {
+        self.push(SubscriptionChange::Subscribe(channel.to_string()))
+    }
+
+    /// Subscribes to the provided channel pattern.
+    pub fn psubscribe(self: &SubscriptionHandle, channel: &str) {
+        self.push(SubscriptionChange::PSubscribe(channel.to_string()))
+    }
+
+    /// Unsubscribes from the provided channel.
+    pub fn unsubscribe(self: &SubscriptionHandle, channel: &str) {
+        self.push(SubscriptionChange::Unsubscribe(channel.to_string()))
+    }
+
+    /// Unsubscribes from the provided channel pattern.
+    pub fn punsubscribe(self: &SubscriptionHandle, channel: &str) {
+        self.push(SubscriptionChange::PUnsubscribe(channel.to_string()))
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+/// Defines how a lost connection is reopened.<br>
+/// Delays between attempts grow exponentially, starting from the initial delay and
+/// multiplied after every failed attempt, until reaching the max delay.
+pub struct ReconnectPolicy {
+    /// Max amount of connection attempts (including the first one), 1 disables retries
+    pub max_attempts: u32,
+    /// The delay before the second connection attempt
+    pub initial_delay: Duration,
+    /// Upper limit of the delay between attempts
+    pub max_delay: Duration,
+    /// The factor by which the delay grows after every failed attempt
+    pub multiplier: f64,
+    /// If true, every delay is randomized between half and the full computed delay
+    pub jitter: bool,
+    /// If defined, no new attempts are made once this time has passed since the first attempt
+    pub max_elapsed_time: Option<Duration>,
+    /// If true, idempotent commands which failed due to a connection error are invoked again
+    /// once the connection is reopened
+    pub retry_idempotent_commands: bool,
+}
+
+impl ReconnectPolicy {
+    /// Returns a new instance.
+    pub fn new() -> ReconnectPolicy {
+        Default::default()
+    }
+}
+
+impl Default for ReconnectPolicy {
+    fn default() -> Self {
+        ReconnectPolicy {
+            max_attempts: 1,
+            initial_delay: Duration::from_millis(100),
+            max_delay: Duration::from_secs(5),
+            multiplier: 2.0,
+            jitter: true,
+            max_elapsed_time: None,
+            retry_idempotent_commands: true,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+/// Defines when an existing connection is verified (via PING) before invoking a command.<br>
+/// By default, only connections which were not used for at least 1 second are verified, so commands invoked one
+/// after the other do not pay for an additional round-trip.<br>
+/// Connections to a sentinel resolved primary are verified via ROLE, so a demoted primary is replaced.<br>
+/// Regardless of the selected option, a connection which failed with an I/O error is
+/// always replaced with a new connection.
+pub enum HealthCheck {
+    /// Verify the connection before every command
+    Always,
+    /// Verify the connection only if it was not used for at least the provided duration
+    Idle(Duration),
+    /// Never verify the connection
+    Never,
+}
+
+impl Default for HealthCheck {
+    fn default() -> Self {
+        HealthCheck::Idle(DEFAULT_HEALTH_CHECK_IDLE_TIME)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+/// Defines how read only commands are distributed among the available replicas.
+pub enum ReplicaSelection {
+    /// Use the replicas one after the other
+    #[default]
+    RoundRobin,
+    /// Use the replica with the lowest average response time
+    LeastLatency,
+}
+
+#[derive(Debug, Clone, Copy)]
+/// Defines the size and behaviour of a clients pool.
+pub struct PoolOptions {
+    /// The amount of clients which are never evicted from the pool, even when idle
+    pub min_size: usize,
+    /// Max amount of clients the pool holds (including checked out clients)
+    pub max_size: usize,
+    /// Max time to wait for an available client when all clients are checked out
+    pub checkout_timeout: Duration,
+    /// If defined, clients which were not used for this time are evicted from the pool
+    pub idle_timeout: Option<Duration>,
+    /// If true, idle clients are verified (via PING) before being checked out
+    pub test_on_checkout: bool,
+}
+
+impl PoolOptions {
+    /// Returns a new instance.
+    pub fn new() -> PoolOptions {
+        Default::default()
+    }
+}
+
+impl Default for PoolOptions {
+    fn default() -> Self {
+        PoolOptions {
+            min_size: 0,
+            max_size: 10,
+            checkout_timeout: Duration::from_secs(30),
+            idle_timeout: Some(Duration::from_secs(600)),
+            test_on_checkout: true,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+/// Holds the metadata of a function which is part of a functions library.
+pub struct FunctionInfo {
+    /// The function name
+    pub name: String,
+    /// The function description (if provided)
+    pub description: Option<String>,
+    /// The function flags, for example: `no-writes`
+    pub flags: Vec<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+/// Holds the metadata of a functions library (see redis [FUNCTION LIST](https://redis.io/commands/function-list) command).
+pub struct FunctionLibrary {
+    /// The library name
+    pub name: String,
+    /// The library engine, for example: `LUA`
+    pub engine: String,
+    /// The library functions
+    pub functions: Vec<FunctionInfo>,
+    /// The library source code, only provided if requested
+    pub code: Option<String>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+/// Defines how existing libraries are handled when restoring functions.
+pub enum FunctionRestorePolicy {
+    /// Append the restored libraries, fails in case of a library name collision
+    Append,
+    /// Append the restored libraries, replacing existing libraries with the same name
+    Replace,
+    /// Delete all existing libraries before restoring
+    Flush,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+/// Defines which members are updated by the ZADD command, based on their existence.
+pub enum ZAddCondition {
+    /// Only add new members, existing members are not updated (NX)
+    NotExists,
+    /// Only update existing members, new members are not added (XX)
+    Exists,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+/// Defines which existing members are updated by the ZADD command, based on their current score.
+pub enum ZAddComparison {
+    /// Only update existing members if the new score is greater than the current score (GT)
+    GreaterThan,
+    /// Only update existing members if the new score is less than the current score (LT)
+    LessThan,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+/// Defines the ZADD command options.
+pub struct ZAddOptions {
+    /// If defined, members are added/updated based on their existence
+    pub condition: Option<ZAddCondition>,
+    /// If defined, existing members are updated based on their current score
+    pub comparison: Option<ZAddComparison>,
+    /// If true, the output is the amount of changed members (added and updated) instead of only the added (CH)
+    pub changed: bool,
+}
+
+impl ZAddOptions {
+    /// Returns a new instance.
+    pub fn new() -> ZAddOptions {
+        Default::default()
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+/// Defines how scores of the same member are combined by the ZUNIONSTORE/ZINTERSTORE commands.
+pub enum Aggregate {
+    /// The scores are summed (default)
+    #[default]
+    Sum,
+    /// The minimum score is used
+    Min,
+    /// The maximum score is used
+    Max,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+/// Holds a single stream entry.
+pub struct StreamEntry {
+    /// The entry ID, for example: `1526919030474-55`
+    pub id: String,
+    /// The entry fields and values
+    pub fields: HashMap<String, String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+/// Defines how a stream is trimmed (see redis [XTRIM](https://redis.io/commands/xtrim) command).
+pub enum StreamTrim {
+    /// Evicts the oldest entries as long as the stream length exceeds the provided count (MAXLEN)
+    MaxLen {
+        /// The max stream length
+        count: usize,
+        /// If true, trimming is done only when whole nodes can be removed, which is more efficient (~)
+        approximate: bool,
+    },
+    /// Evicts entries with IDs lower than the provided ID (MINID)
+    MinId {
+        /// The min entry ID to keep
+        id: String,
+        /// If true, trimming is done only when whole nodes can be removed, which is more efficient (~)
+        approximate: bool,
+    },
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+/// Holds a single entry which was delivered to a consumer but not yet acknowledged
+/// (see redis [XPENDING](https://redis.io/commands/xpending) command).
+pub struct StreamPendingEntry {
+    /// The entry ID
+    pub id: String,
+    /// The consumer the entry was delivered to
+    pub consumer: String,
+    /// The time passed since the entry was last delivered
+    pub idle: Duration,
+    /// The amount of times the entry was delivered
+    pub delivery_count: u64,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+/// Holds the redis [XAUTOCLAIM](https://redis.io/commands/xautoclaim) command output.
+pub struct StreamAutoClaimResult {
+    /// The ID to use as the start of the next scan, `0-0` once the entire pending list was scanned
+    pub next_id: String,
+    /// The claimed entries
+    pub entries: Vec<StreamEntry>,
+    /// The IDs of claimed entries which no longer exist in the stream (and were removed from the pending list)
+    pub deleted_ids: Vec<String>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+/// The result of a stream entry handling, returned by the stream consumer on_entry handler.
+pub enum StreamEntryStatus {
+    /// The entry was handled and will be acknowledged
+    Processed,
+    /// The entry handling failed, the entry is left pending and will be delivered again
+    Failed,
+}
+
+#[derive(Debug, Clone, Copy)]
+/// Defines the behaviour of a stream consumer.
+pub struct StreamConsumerOptions {
+    /// Max amount of entries read on each iteration
+    pub count: usize,
+    /// Max time to block while waiting for new entries, unless overridden by the interrupts next polling time
+    pub block: Duration,
+    /// Min time to wait before failed entries are delivered again
+    pub retry_interval: Duration,
+    /// If defined, entries pending (on any consumer) longer than this time are claimed by this consumer
+    pub claim_min_idle: Option<Duration>,
+    /// Min time between pending entries claim attempts
+    pub claim_interval: Duration,
+    /// If true, the consumer group (and the stream) are created in case they do not exist
+    pub create_group: bool,
+}
+
+impl StreamConsumerOptions {
+    /// Returns a new instance.
+    pub fn new() -> StreamConsumerOptions {
+        Default::default()
+    }
+}
+
+impl Default for StreamConsumerOptions {
+    fn default() -> Self {
+        StreamConsumerOptions {
+            count: 10,
+            block: Duration::from_secs(5),
+            retry_interval: Duration::from_secs(1),
+            claim_min_idle: Some(Duration::from_secs(60)),
+            claim_interval: Duration::from_secs(30),
+            create_group: true,
+        }
+    }
+}
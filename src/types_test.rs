@@ -1,9 +1,16 @@
 use super::*;
 use std::io::Write;
 
+fn create_server_error(response: &[u8]) -> redis::RedisError {
+    redis::parse_redis_value(response)
+        .unwrap()
+        .extract_error()
+        .unwrap_err()
+}
+
 #[test]
 fn redis_error_description() {
-    let redis_error = RedisError::Description("test");
+    let redis_error = RedisError::UnexpectedResponse("test");
 
     assert_eq!(redis_error.to_string(), "test");
 
@@ -11,3 +18,166 @@ fn redis_error_description() {
     write!(&mut writer, "formatted {}", redis_error).unwrap();
     assert_eq!(writer, b"formatted test");
 }
+
+#[test]
+fn redis_error_from_io_error() {
+    let mut error: RedisError =
+        redis::RedisError::from(std::io::Error::from(std::io::ErrorKind::ConnectionRefused)).into();
+    assert!(matches!(error, RedisError::NotConnected(Some(_))));
+    assert!(error.is_connection_error());
+    assert!(error.is_retryable());
+    assert!(error.source().is_some());
+
+    error = redis::RedisError::from(std::io::Error::from(std::io::ErrorKind::TimedOut)).into();
+    assert!(matches!(error, RedisError::Timeout(Some(_))));
+    assert!(error.is_connection_error());
+    assert!(error.is_retryable());
+}
+
+#[test]
+fn redis_error_from_server_error() {
+    let mut error: RedisError =
+        create_server_error(b"-WRONGTYPE Operation against a key\r\n").into();
+    match error {
+        RedisError::WrongType { ref message } => {
+            assert_eq!(message, "Operation against a key")
+        }
+        _ => panic!("Invalid error: {:?}", error),
+    }
+    assert!(!error.is_connection_error());
+    assert!(!error.is_retryable());
+
+    error = create_server_error(b"-ERR unknown command\r\n").into();
+    match error {
+        RedisError::ServerError {
+            ref code,
+            ref message,
+        } => {
+            assert_eq!(code, "ERR");
+            assert_eq!(message, "unknown command");
+        }
+        _ => panic!("Invalid error: {:?}", error),
+    }
+    assert_eq!(error.to_string(), "ERR unknown command");
+    assert!(!error.is_retryable());
+
+    error = create_server_error(b"-LOADING Redis is loading the dataset\r\n").into();
+    assert!(matches!(error, RedisError::ServerError { .. }));
+    assert!(error.is_retryable());
+    assert!(!error.is_connection_error());
+}
+
+#[test]
+fn redis_error_from_client_error() {
+    let error: RedisError =
+        redis::RedisError::from((redis::ErrorKind::InvalidClientConfig, "bad config")).into();
+
+    assert!(matches!(error, RedisError::RedisError(_)));
+    assert!(!error.is_connection_error());
+    assert!(!error.is_retryable());
+}
+
+#[test]
+fn from_redis_error_type_error() {
+    let error = from_redis_error::<i64>(redis::RedisError::from((
+        redis::ErrorKind::TypeError,
+        "bad type",
+    )));
+
+    match error {
+        RedisError::Parse {
+            key,
+            field,
+            raw_value,
+            target_type,
+        } => {
+            assert!(key.is_none());
+            assert!(field.is_none());
+            assert!(raw_value.is_none());
+            assert_eq!(target_type, "i64");
+        }
+        _ => panic!("Invalid error: {:?}", error),
+    }
+}
+
+#[test]
+fn create_parse_error_display() {
+    let mut error = create_parse_error::<u32>(Some("my_key"), None, Some("abc".to_string()));
+    assert_eq!(
+        error.to_string(),
+        "Unable to parse value of key: my_key to type: u32."
+    );
+
+    error = create_parse_error::<u32>(Some("my_key"), Some("my_field"), None);
+    assert_eq!(
+        error.to_string(),
+        "Unable to parse field: my_field of key: my_key to type: u32."
+    );
+
+    error = create_parse_error::<u32>(None, None, None);
+    assert_eq!(error.to_string(), "Unable to parse value to type: u32.");
+}
+
+#[test]
+fn create_not_found_error_field() {
+    let error = create_not_found_error("my_key", Some("my_field"));
+    match error {
+        RedisError::NotFound { key, field } => {
+            assert_eq!(key, "my_key");
+            assert_eq!(field.unwrap(), "my_field");
+        }
+        _ => panic!("Invalid error: {:?}", error),
+    }
+}
+
+#[test]
+fn redis_error_display() {
+    assert_eq!(
+        RedisError::NotFound {
+            key: "my_key".to_string(),
+            field: None,
+        }
+        .to_string(),
+        "Key: my_key not found."
+    );
+    assert_eq!(
+        RedisError::NotFound {
+            key: "my_key".to_string(),
+            field: Some("my_field".to_string()),
+        }
+        .to_string(),
+        "Key: my_key field: my_field not found."
+    );
+    assert_eq!(
+        RedisError::NoSubscriptions.to_string(),
+        "No subscriptions defined."
+    );
+    assert!(RedisError::TransactionAborted.is_retryable());
+    assert!(!RedisError::NoSubscriptions.is_retryable());
+}
+
+#[test]
+fn subscription_handle_take_changes() {
+    let handle = SubscriptionHandle::new();
+    let cloned_handle = handle.clone();
+
+    handle.subscribe("sub1");
+    cloned_handle.punsubscribe("sub2*");
+
+    assert_eq!(
+        handle.take_changes(),
+        vec![
+            SubscriptionChange::Subscribe("sub1".to_string()),
+            SubscriptionChange::PUnsubscribe("sub2*".to_string()),
+        ]
+    );
+    assert!(cloned_handle.take_changes().is_empty());
+}
+
+#[test]
+fn health_check_default() {
+    assert_eq!(
+        HealthCheck::default(),
+        HealthCheck::Idle(Duration::from_secs(1))
+    );
+}
@@ -0,0 +1,93 @@
+#![cfg(feature = "async")]
+
+use futures_util::StreamExt;
+use std::time::Duration;
+
+#[tokio::test]
+async fn async_commands() {
+    let mut client = simple_redis::create_async("redis://127.0.0.1:6379/").unwrap();
+
+    client.set("async_commands", 15).await.unwrap();
+    assert_eq!(client.get::<i32>("async_commands").await.unwrap(), 15);
+    assert_eq!(client.incr("async_commands").await.unwrap(), 16);
+    client.del("async_commands").await.unwrap();
+    assert!(!client.exists("async_commands").await.unwrap());
+
+    client.del("async_commands_hash").await.unwrap();
+    client
+        .hset("async_commands_hash", "field", "value")
+        .await
+        .unwrap();
+    let map = client.hgetall("async_commands_hash").await.unwrap();
+    assert_eq!(map.get("field").unwrap(), "value");
+
+    client.del("async_commands_list").await.unwrap();
+    client.rpush("async_commands_list", "1").await.unwrap();
+    client.rpush("async_commands_list", "2").await.unwrap();
+    assert_eq!(
+        client.lrange("async_commands_list", 0, -1).await.unwrap(),
+        vec!["1", "2"]
+    );
+
+    client.del("async_commands_set").await.unwrap();
+    client.sadd("async_commands_set", "member").await.unwrap();
+    assert!(client
+        .sismember("async_commands_set", "member")
+        .await
+        .unwrap());
+
+    client.del("async_commands_zset").await.unwrap();
+    client.zadd("async_commands_zset", 1.5, "a").await.unwrap();
+    client.zadd("async_commands_zset", 0.5, "b").await.unwrap();
+    assert_eq!(
+        client
+            .zrange_withscores("async_commands_zset", 0, -1)
+            .await
+            .unwrap(),
+        vec![("b".to_string(), 0.5), ("a".to_string(), 1.5)]
+    );
+}
+
+#[tokio::test]
+async fn async_commands_shared_between_tasks() {
+    let client = simple_redis::create_async("redis://127.0.0.1:6379/").unwrap();
+
+    let mut handles = vec![];
+    for index in 0..5 {
+        let mut task_client = client.clone();
+        handles.push(tokio::spawn(async move {
+            let key = format!("async_commands_shared_{}", index);
+            task_client.set(&key, index).await.unwrap();
+            task_client.get::<i32>(&key).await.unwrap()
+        }));
+    }
+
+    for (index, handle) in handles.into_iter().enumerate() {
+        assert_eq!(handle.await.unwrap(), index as i32);
+    }
+}
+
+#[tokio::test]
+async fn async_message_stream() {
+    let mut subscriber = simple_redis::create_async("redis://127.0.0.1:6379/").unwrap();
+    subscriber.subscribe("async_message_stream").unwrap();
+    let mut messages = subscriber.message_stream().unwrap();
+
+    let publisher = tokio::spawn(async {
+        let mut publisher = simple_redis::create_async("redis://127.0.0.1:6379/").unwrap();
+        loop {
+            tokio::time::sleep(Duration::from_millis(50)).await;
+            publisher
+                .publish("async_message_stream", "my message")
+                .await
+                .unwrap();
+        }
+    });
+
+    let message = messages.next().await.unwrap().unwrap();
+    publisher.abort();
+
+    assert_eq!(message.get_channel_name(), "async_message_stream");
+    let payload: String = message.get_payload().unwrap();
+    assert_eq!(payload, "my message");
+}
@@ -0,0 +1,116 @@
+use simple_redis::{Interrupts, Message};
+use std::{thread, time};
+
+const DATA: &[u8] = &[0, 1, 2, 159, 146, 150, 255, b'\r', b'\n'];
+
+#[test]
+fn strings_binary() {
+    let mut client = simple_redis::create("redis://127.0.0.1:6379/").unwrap();
+
+    client.del("strings_binary").unwrap();
+    client.setnx_binary("strings_binary", DATA).unwrap();
+    client.setnx_binary("strings_binary", b"other").unwrap();
+    assert_eq!(client.get_binary("strings_binary").unwrap(), DATA);
+
+    client.append_binary("strings_binary", &[0]).unwrap();
+    let old_value = client.getset_binary("strings_binary", b"new").unwrap();
+    let mut expected = DATA.to_vec();
+    expected.push(0);
+    assert_eq!(old_value, expected);
+
+    client.setex_binary("strings_binary", DATA, 10).unwrap();
+    assert_eq!(client.get_binary("strings_binary").unwrap(), DATA);
+}
+
+#[test]
+fn hash_binary() {
+    let mut client = simple_redis::create("redis://127.0.0.1:6379/").unwrap();
+
+    client.del("hash_binary").unwrap();
+    client.hset_binary("hash_binary", "field1", DATA).unwrap();
+    client.hsetnx_binary("hash_binary", "field2", &[1]).unwrap();
+    client.hsetnx_binary("hash_binary", "field2", &[2]).unwrap();
+
+    assert_eq!(client.hget_binary("hash_binary", "field1").unwrap(), DATA);
+    assert!(client.hget_binary("hash_binary", "missing").is_err());
+
+    let map = client.hgetall_binary("hash_binary").unwrap();
+    assert_eq!(map.len(), 2);
+    assert_eq!(map.get("field2").unwrap(), &vec![1]);
+
+    let values = client.hvals_binary("hash_binary").unwrap();
+    assert_eq!(values.len(), 2);
+}
+
+#[test]
+fn list_binary() {
+    let mut client = simple_redis::create("redis://127.0.0.1:6379/").unwrap();
+
+    client.del("list_binary").unwrap();
+    client.lpush_binary("list_binary", &[1]).unwrap();
+    client.rpush_binary("list_binary", DATA).unwrap();
+    client.rpush_binary("list_binary", &[3]).unwrap();
+
+    let values = client.lrange_binary("list_binary", 0, -1).unwrap();
+    assert_eq!(values, vec![vec![1], DATA.to_vec(), vec![3]]);
+
+    client.lset_binary("list_binary", 0, &[4]).unwrap();
+    assert_eq!(client.lindex_binary("list_binary", 0).unwrap(), vec![4]);
+    assert_eq!(client.lpop_binary("list_binary").unwrap(), vec![4]);
+    assert_eq!(client.rpop_binary("list_binary").unwrap(), vec![3]);
+    assert_eq!(client.rpop_binary("list_binary").unwrap(), DATA);
+    assert!(client.rpop_binary("list_binary").is_err());
+}
+
+#[test]
+fn set_binary_members() {
+    let mut client = simple_redis::create("redis://127.0.0.1:6379/").unwrap();
+
+    client.del("set_binary_members").unwrap();
+    assert_eq!(client.sadd_binary("set_binary_members", DATA).unwrap(), 1);
+    assert_eq!(client.sadd_binary("set_binary_members", DATA).unwrap(), 0);
+    assert!(client.sismember_binary("set_binary_members", DATA).unwrap());
+
+    let members = client.smembers_binary("set_binary_members").unwrap();
+    assert_eq!(members, vec![DATA.to_vec()]);
+
+    client.srem_binary("set_binary_members", DATA).unwrap();
+    assert!(!client.sismember_binary("set_binary_members", DATA).unwrap());
+}
+
+#[test]
+fn run_binary_command() {
+    let mut client = simple_redis::create("redis://127.0.0.1:6379/").unwrap();
+
+    client
+        .run_binary_command::<()>("SET", vec![b"run_binary_command", DATA])
+        .unwrap();
+    let value = client
+        .run_binary_command::<Vec<u8>>("GET", vec![b"run_binary_command"])
+        .unwrap();
+    assert_eq!(value, DATA);
+}
+
+#[test]
+fn pub_sub_binary() {
+    let mut subscriber = simple_redis::create("redis://127.0.0.1:6379/").unwrap();
+
+    subscriber.subscribe("pub_sub_binary").unwrap();
+
+    thread::spawn(|| {
+        thread::sleep(time::Duration::from_secs(2));
+        let mut publisher = simple_redis::create("redis://127.0.0.1:6379/").unwrap();
+        publisher.publish_binary("pub_sub_binary", DATA).unwrap();
+    });
+
+    subscriber
+        .fetch_messages(
+            &mut |message: Message| -> bool {
+                let payload = message.get_payload_bytes();
+                assert_eq!(payload, DATA);
+                true
+            },
+            &mut || -> Interrupts { Interrupts::new() },
+        )
+        .unwrap();
+}
@@ -1,4 +1,3 @@
-use simple_redis;
 use simple_redis::{Interrupts, Message};
 use std::{thread, time};
 
@@ -195,3 +194,62 @@ fn quit_live_subscriptions() {
 
     assert!(client.is_connection_open());
 }
+
+#[test]
+fn health_check_never() {
+    let mut client = simple_redis::create("redis://127.0.0.1:6379/").unwrap();
+    client.set_health_check(simple_redis::HealthCheck::Never);
+
+    assert!(!client.is_connection_open());
+
+    match client.echo("testing") {
+        Ok(value) => assert_eq!(value, "testing"),
+        _ => panic!("test error"),
+    }
+
+    match client.echo("testing2") {
+        Ok(value) => assert_eq!(value, "testing2"),
+        _ => panic!("test error"),
+    }
+
+    assert!(client.is_connection_open());
+}
+
+#[test]
+fn health_check_idle() {
+    let mut client = simple_redis::create("redis://127.0.0.1:6379/").unwrap();
+    client.set_health_check(simple_redis::HealthCheck::Idle(
+        time::Duration::from_millis(100),
+    ));
+
+    match client.echo("testing") {
+        Ok(value) => assert_eq!(value, "testing"),
+        _ => panic!("test error"),
+    }
+
+    thread::sleep(time::Duration::from_millis(200));
+
+    match client.echo("testing2") {
+        Ok(value) => assert_eq!(value, "testing2"),
+        _ => panic!("test error"),
+    }
+}
+
+#[test]
+fn builder_with_options() {
+    let mut client = simple_redis::ClientBuilder::new("redis://127.0.0.1:6379/")
+        .connect_timeout(time::Duration::from_secs(5))
+        .read_timeout(time::Duration::from_secs(5))
+        .write_timeout(time::Duration::from_secs(5))
+        .client_name("builder_test_client")
+        .db(1)
+        .build()
+        .unwrap();
+
+    assert!(!client.is_connection_open());
+
+    let name = client.run_command_string_response("CLIENT", vec!["GETNAME"]);
+    assert_eq!(name.unwrap(), "builder_test_client");
+
+    assert!(client.is_connection_open());
+}
@@ -0,0 +1,54 @@
+#![cfg(feature = "cluster")]
+
+const NODES: &[&str] = &[
+    "redis://127.0.0.1:7000/",
+    "redis://127.0.0.1:7001/",
+    "redis://127.0.0.1:7002/",
+];
+
+#[test]
+fn cluster_set_get_multiple_slots() {
+    let mut client = simple_redis::create_cluster(NODES.to_vec()).unwrap();
+
+    for index in 0..20 {
+        let key = format!("cluster_set_get_{}", index);
+        client.set(&key, index).unwrap();
+    }
+
+    for index in 0..20 {
+        let key = format!("cluster_set_get_{}", index);
+        let value: i64 = client.get(&key).unwrap();
+        assert_eq!(value, index);
+    }
+}
+
+#[test]
+fn cluster_pipeline_same_slot() {
+    let mut client = simple_redis::create_cluster(NODES.to_vec()).unwrap();
+
+    let mut pipeline = client.pipeline();
+    pipeline
+        .set("{cluster_pipeline}.first", "1")
+        .set("{cluster_pipeline}.second", "2")
+        .get("{cluster_pipeline}.first");
+
+    let results = client.run_pipeline(&pipeline).unwrap();
+    let value: String = results.get(2).unwrap();
+    assert_eq!(value, "1");
+}
+
+#[test]
+fn cluster_connect_single_node() {
+    // the entire topology is discovered from a single node
+    let mut client = simple_redis::create_cluster(vec![NODES[1]]).unwrap();
+
+    client.set("cluster_connect_single_node", "value").unwrap();
+    let value = client.get_string("cluster_connect_single_node").unwrap();
+    assert_eq!(value, "value");
+
+    let slot = simple_redis::cluster::get_key_slot("cluster_connect_single_node");
+    let server_slot: u16 = client
+        .run_command("CLUSTER", vec!["KEYSLOT", "cluster_connect_single_node"])
+        .unwrap();
+    assert_eq!(slot, server_slot);
+}
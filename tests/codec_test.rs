@@ -0,0 +1,101 @@
+#![cfg(feature = "serde")]
+
+use serde::{Deserialize, Serialize};
+use simple_redis::codec::JsonCodec;
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+struct User {
+    name: String,
+    age: u8,
+}
+
+fn create_user(name: &str) -> User {
+    User {
+        name: name.to_string(),
+        age: 30,
+    }
+}
+
+#[test]
+fn set_get_json() {
+    let mut client = simple_redis::create("redis://127.0.0.1:6379/").unwrap();
+
+    client
+        .set_json("set_get_json", &create_user("user1"))
+        .unwrap();
+    let user: User = client.get_json("set_get_json").unwrap();
+    assert_eq!(user, create_user("user1"));
+
+    let raw = client.get_string("set_get_json").unwrap();
+    assert_eq!(raw, r#"{"name":"user1","age":30}"#);
+
+    client.set("set_get_json", "bad json").unwrap();
+    let result: Result<User, simple_redis::RedisError> = client.get_json("set_get_json");
+    assert!(result.is_err());
+}
+
+#[test]
+fn hset_hget_json() {
+    let mut client = simple_redis::create("redis://127.0.0.1:6379/").unwrap();
+
+    client
+        .hset_json("hset_hget_json", "user", &create_user("user1"))
+        .unwrap();
+    let user: User = client.hget_json("hset_hget_json", "user").unwrap();
+    assert_eq!(user, create_user("user1"));
+}
+
+#[test]
+fn lpush_lrange_json() {
+    let mut client = simple_redis::create("redis://127.0.0.1:6379/").unwrap();
+
+    client.del("lpush_lrange_json").unwrap();
+    client
+        .lpush_json("lpush_lrange_json", &create_user("user1"))
+        .unwrap();
+    client
+        .lpush_json("lpush_lrange_json", &create_user("user2"))
+        .unwrap();
+
+    let users: Vec<User> = client.lrange_json("lpush_lrange_json", 0, -1).unwrap();
+    assert_eq!(users, vec![create_user("user2"), create_user("user1")]);
+}
+
+#[test]
+fn set_get_encoded() {
+    let mut client = simple_redis::create("redis://127.0.0.1:6379/").unwrap();
+
+    client
+        .set_encoded(&JsonCodec, "set_get_encoded", &create_user("user1"))
+        .unwrap();
+    let user: User = client.get_encoded(&JsonCodec, "set_get_encoded").unwrap();
+    assert_eq!(user, create_user("user1"));
+}
+
+#[test]
+#[cfg(feature = "msgpack")]
+fn set_get_msgpack() {
+    let codec = simple_redis::codec::MsgPackCodec;
+    let mut client = simple_redis::create("redis://127.0.0.1:6379/").unwrap();
+
+    client
+        .set_encoded(&codec, "set_get_msgpack", &create_user("user1"))
+        .unwrap();
+    let user: User = client.get_encoded(&codec, "set_get_msgpack").unwrap();
+    assert_eq!(user, create_user("user1"));
+}
+
+#[test]
+#[cfg(feature = "bincode")]
+fn set_get_bincode() {
+    let codec = simple_redis::codec::BincodeCodec;
+    let mut client = simple_redis::create("redis://127.0.0.1:6379/").unwrap();
+
+    client
+        .hset_encoded(&codec, "set_get_bincode", "user", &create_user("user1"))
+        .unwrap();
+    let user: User = client
+        .hget_encoded(&codec, "set_get_bincode", "user")
+        .unwrap();
+    assert_eq!(user, create_user("user1"));
+}
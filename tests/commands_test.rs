@@ -1,5 +1,6 @@
-use simple_redis;
-use simple_redis::{Interrupts, Message};
+use simple_redis::types::{Aggregate, ZAddComparison, ZAddCondition, ZAddOptions};
+use simple_redis::{Interrupts, Message, RedisError};
+use std::time::Duration;
 use std::{thread, time};
 
 #[test]
@@ -305,7 +306,7 @@ fn set_get_bool() {
     assert!(client.is_connection_open());
 
     match client.get::<bool>("set_get_bool") {
-        Ok(value) => assert_eq!(value, true),
+        Ok(value) => assert!(value),
         _ => panic!("test error"),
     }
 }
@@ -736,6 +737,31 @@ fn hget_hset_hdel() {
     assert!(!bool_result);
 }
 
+#[test]
+fn hget_not_found() {
+    let mut client = simple_redis::create("redis://127.0.0.1:6379/").unwrap();
+
+    client.del("hget_not_found").unwrap();
+
+    match client.hget_string("hget_not_found", "field1") {
+        Err(RedisError::NotFound { key, field }) => {
+            assert_eq!(key, "hget_not_found");
+            assert_eq!(field.unwrap(), "field1");
+        }
+        result => panic!("Invalid result: {:?}", result),
+    }
+
+    client.hset("hget_not_found", "field1", "test").unwrap();
+
+    match client.hget::<i64>("hget_not_found", "field1") {
+        Err(RedisError::Parse { key, field, .. }) => {
+            assert_eq!(key.unwrap(), "hget_not_found");
+            assert_eq!(field.unwrap(), "field1");
+        }
+        result => panic!("Invalid result: {:?}", result),
+    }
+}
+
 #[test]
 fn hgetall() {
     let mut client = simple_redis::create("redis://127.0.0.1:6379/").unwrap();
@@ -957,3 +983,134 @@ fn set_all() {
     vec_result = client.zrange("zset_test_key", -2, -1).unwrap();
     assert_eq!(vec_result, vec!["two", "three"]);
 }
+
+#[test]
+fn zset_all() {
+    let mut client = simple_redis::create("redis://127.0.0.1:6379/").unwrap();
+
+    client.del("zset_all_1").unwrap();
+    client.del("zset_all_2").unwrap();
+    client.del("zset_all_dest").unwrap();
+
+    let mut int_result = client
+        .zadd_with_options(
+            "zset_all_1",
+            ZAddOptions::new(),
+            vec![(1.5, "one"), (2.5, "two"), (3.5, "three")],
+        )
+        .unwrap();
+    assert_eq!(int_result, 3);
+
+    let mut options = ZAddOptions::new();
+    options.condition = Some(ZAddCondition::NotExists);
+    int_result = client
+        .zadd_with_options("zset_all_1", options, vec![(10.0, "one"), (4.5, "four")])
+        .unwrap();
+    assert_eq!(int_result, 1);
+    assert_eq!(client.zscore("zset_all_1", "one").unwrap(), Some(1.5));
+
+    let mut options = ZAddOptions::new();
+    options.comparison = Some(ZAddComparison::GreaterThan);
+    options.changed = true;
+    int_result = client
+        .zadd_with_options("zset_all_1", options, vec![(0.5, "one"), (5.5, "four")])
+        .unwrap();
+    assert_eq!(int_result, 1);
+    assert_eq!(client.zscore("zset_all_1", "four").unwrap(), Some(5.5));
+    assert_eq!(client.zscore("zset_all_1", "missing").unwrap(), None);
+
+    let mut options = ZAddOptions::new();
+    options.condition = Some(ZAddCondition::Exists);
+    let score = client
+        .zadd_incr("zset_all_1", options, 1, "missing")
+        .unwrap();
+    assert_eq!(score, None);
+    let score = client.zadd_incr("zset_all_1", options, 1, "one").unwrap();
+    assert_eq!(score, Some(2.5));
+
+    let float_result = client.zincrby("zset_all_1", 0.5, "one").unwrap();
+    assert_eq!(float_result, 3.0);
+
+    assert_eq!(client.zcard("zset_all_1").unwrap(), 4);
+    assert_eq!(client.zcount("zset_all_1", 2.5, "+inf").unwrap(), 4);
+    assert_eq!(client.zcount("zset_all_1", "(2.5", 3.5).unwrap(), 2);
+
+    assert_eq!(client.zrank("zset_all_1", "two").unwrap(), Some(0));
+    assert_eq!(client.zrevrank("zset_all_1", "two").unwrap(), Some(3));
+    assert_eq!(client.zrank("zset_all_1", "missing").unwrap(), None);
+
+    let pairs = client.zrange_withscores("zset_all_1", 0, 1).unwrap();
+    assert_eq!(
+        pairs,
+        vec![("two".to_string(), 2.5), ("one".to_string(), 3.0)]
+    );
+
+    let mut vec_result = client
+        .zrangebyscore("zset_all_1", "-inf", "+inf", Some((1, 2)))
+        .unwrap();
+    assert_eq!(vec_result, vec!["one", "three"]);
+    vec_result = client.zrangebyscore("zset_all_1", 3, 5.5, None).unwrap();
+    assert_eq!(vec_result, vec!["one", "three", "four"]);
+
+    client
+        .zadd_with_options(
+            "zset_all_2",
+            ZAddOptions::new(),
+            vec![(0, "a"), (0, "b"), (0, "c"), (0, "d")],
+        )
+        .unwrap();
+    vec_result = client.zrangebylex("zset_all_2", "[b", "+", None).unwrap();
+    assert_eq!(vec_result, vec!["b", "c", "d"]);
+    vec_result = client
+        .zrangebylex("zset_all_2", "-", "+", Some((0, 2)))
+        .unwrap();
+    assert_eq!(vec_result, vec!["a", "b"]);
+
+    assert_eq!(client.zrem("zset_all_2", "d").unwrap(), 1);
+    assert_eq!(client.zrem("zset_all_2", "d").unwrap(), 0);
+
+    int_result = client
+        .zunionstore(
+            "zset_all_dest",
+            vec!["zset_all_1", "zset_all_2"],
+            Some(vec![2.0, 1.0]),
+            Some(Aggregate::Sum),
+        )
+        .unwrap();
+    assert_eq!(int_result, 7);
+    assert_eq!(client.zscore("zset_all_dest", "one").unwrap(), Some(6.0));
+
+    client.zadd("zset_all_2", 1, "one").unwrap();
+    int_result = client
+        .zinterstore(
+            "zset_all_dest",
+            vec!["zset_all_1", "zset_all_2"],
+            None,
+            Some(Aggregate::Min),
+        )
+        .unwrap();
+    assert_eq!(int_result, 1);
+    assert_eq!(client.zscore("zset_all_dest", "one").unwrap(), Some(1.0));
+
+    assert_eq!(client.zremrangebyscore("zset_all_1", 5, 10).unwrap(), 1);
+
+    let popped = client.zpopmin("zset_all_1", 1).unwrap();
+    assert_eq!(popped, vec![("two".to_string(), 2.5)]);
+    let popped = client.zpopmax("zset_all_1", 1).unwrap();
+    assert_eq!(popped, vec![("three".to_string(), 3.5)]);
+
+    let popped = client
+        .bzpopmin(
+            vec!["zset_all_missing", "zset_all_1"],
+            Duration::from_secs(1),
+        )
+        .unwrap();
+    assert_eq!(
+        popped,
+        Some(("zset_all_1".to_string(), "one".to_string(), 3.0))
+    );
+    let popped = client
+        .bzpopmin(vec!["zset_all_1"], Duration::from_millis(100))
+        .unwrap();
+    assert_eq!(popped, None);
+}
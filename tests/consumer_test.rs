@@ -0,0 +1,177 @@
+use simple_redis::types::{StreamConsumerOptions, StreamEntry, StreamEntryStatus};
+use simple_redis::{Interrupts, StreamConsumer};
+use std::cell::RefCell;
+use std::time::{Duration, Instant};
+
+#[test]
+fn consumer_retry_and_ack() {
+    let mut client = simple_redis::create("redis://127.0.0.1:6379/").unwrap();
+
+    client.del("stream_consumer_retry").unwrap();
+
+    let mut options = StreamConsumerOptions::new();
+    options.retry_interval = Duration::from_millis(50);
+    options.claim_min_idle = None;
+    let mut consumer = StreamConsumer::new("stream_consumer_retry", "group1", "worker1", options);
+
+    // creates the group
+    consumer
+        .run(
+            &mut client,
+            &mut |_entry: &StreamEntry| -> StreamEntryStatus { StreamEntryStatus::Processed },
+            &mut || -> Interrupts {
+                let mut interrupts = Interrupts::new();
+                interrupts.stop = true;
+                interrupts
+            },
+        )
+        .unwrap();
+
+    client
+        .xadd("stream_consumer_retry", None, vec![("job", "1")], None)
+        .unwrap();
+    client
+        .xadd("stream_consumer_retry", None, vec![("job", "2")], None)
+        .unwrap();
+
+    let calls = RefCell::new(vec![]);
+    consumer
+        .run(
+            &mut client,
+            &mut |entry: &StreamEntry| -> StreamEntryStatus {
+                let job = entry.fields.get("job").unwrap().to_string();
+                calls.borrow_mut().push(job.clone());
+
+                // the first job fails on the first attempt
+                if job == "1" && calls.borrow().len() == 1 {
+                    StreamEntryStatus::Failed
+                } else {
+                    StreamEntryStatus::Processed
+                }
+            },
+            &mut || -> Interrupts {
+                let mut interrupts = Interrupts::new();
+                interrupts.next_polling_time = Some(10);
+                interrupts.stop = calls.borrow().len() >= 3;
+                interrupts
+            },
+        )
+        .unwrap();
+
+    assert_eq!(calls.into_inner(), vec!["1", "2", "1"]);
+
+    let pending = client
+        .xpending("stream_consumer_retry", "group1", "-", "+", 10, None)
+        .unwrap();
+    assert!(pending.is_empty());
+}
+
+#[test]
+fn consumer_retry_within_interval() {
+    let mut client = simple_redis::create("redis://127.0.0.1:6379/").unwrap();
+
+    client.del("stream_consumer_retry_interval").unwrap();
+    client
+        .xgroup_create("stream_consumer_retry_interval", "group1", "$", true)
+        .unwrap();
+    client
+        .xadd(
+            "stream_consumer_retry_interval",
+            None,
+            vec![("job", "1")],
+            None,
+        )
+        .unwrap();
+
+    // the default block time (5 seconds) is much longer than the retry interval
+    let mut options = StreamConsumerOptions::new();
+    options.retry_interval = Duration::from_millis(500);
+    options.claim_min_idle = None;
+    let retry_interval = options.retry_interval;
+    let mut consumer = StreamConsumer::new(
+        "stream_consumer_retry_interval",
+        "group1",
+        "worker1",
+        options,
+    );
+
+    let calls = RefCell::new(vec![]);
+    consumer
+        .run(
+            &mut client,
+            &mut |_entry: &StreamEntry| -> StreamEntryStatus {
+                calls.borrow_mut().push(Instant::now());
+
+                if calls.borrow().len() == 1 {
+                    StreamEntryStatus::Failed
+                } else {
+                    StreamEntryStatus::Processed
+                }
+            },
+            &mut || -> Interrupts {
+                let mut interrupts = Interrupts::new();
+                interrupts.stop = calls.borrow().len() >= 2;
+                interrupts
+            },
+        )
+        .unwrap();
+
+    let calls = calls.into_inner();
+    let retry_delay = calls[1].duration_since(calls[0]);
+    assert!(retry_delay >= retry_interval);
+    assert!(retry_delay < retry_interval * 2);
+}
+
+#[test]
+fn consumer_claim_idle_entries() {
+    let mut client = simple_redis::create("redis://127.0.0.1:6379/").unwrap();
+
+    client.del("stream_consumer_claim").unwrap();
+    client
+        .xgroup_create("stream_consumer_claim", "group1", "$", true)
+        .unwrap();
+    client
+        .xadd("stream_consumer_claim", None, vec![("job", "1")], None)
+        .unwrap();
+
+    // a dead consumer reads the entry and never acknowledges it
+    client
+        .xreadgroup(
+            "group1",
+            "dead_worker",
+            vec![("stream_consumer_claim", ">")],
+            None,
+            None,
+            false,
+        )
+        .unwrap();
+
+    let mut options = StreamConsumerOptions::new();
+    options.claim_min_idle = Some(Duration::from_millis(0));
+    let mut consumer = StreamConsumer::new("stream_consumer_claim", "group1", "worker1", options);
+
+    let jobs = RefCell::new(vec![]);
+    consumer
+        .run(
+            &mut client,
+            &mut |entry: &StreamEntry| -> StreamEntryStatus {
+                jobs.borrow_mut()
+                    .push(entry.fields.get("job").unwrap().to_string());
+                StreamEntryStatus::Processed
+            },
+            &mut || -> Interrupts {
+                let mut interrupts = Interrupts::new();
+                interrupts.next_polling_time = Some(10);
+                interrupts.stop = !jobs.borrow().is_empty();
+                interrupts
+            },
+        )
+        .unwrap();
+
+    assert_eq!(jobs.into_inner(), vec!["1"]);
+
+    let pending = client
+        .xpending("stream_consumer_claim", "group1", "-", "+", 10, None)
+        .unwrap();
+    assert!(pending.is_empty());
+}
@@ -0,0 +1,61 @@
+use simple_redis::types::FunctionRestorePolicy;
+
+const LIBRARY_CODE: &str = "#!lua name=int_functions_lib
+redis.register_function('int_echo', function(keys, args) return args[1] end)
+redis.register_function{
+  function_name='int_get',
+  callback=function(keys, args) return redis.call('GET', keys[1]) end,
+  flags={ 'no-writes' }
+}";
+
+#[test]
+fn function_flow() {
+    let mut client = simple_redis::create("redis://127.0.0.1:6379/").unwrap();
+
+    // only the test library is removed, other libraries may be used by tests running in parallel
+    client.function_delete("int_functions_lib").ok();
+
+    let name = client.function_load(LIBRARY_CODE, false).unwrap();
+    assert_eq!(name, "int_functions_lib");
+
+    let result = client.function_load(LIBRARY_CODE, false);
+    assert!(result.is_err());
+    let name = client.function_load(LIBRARY_CODE, true).unwrap();
+    assert_eq!(name, "int_functions_lib");
+
+    let libraries = client.function_list(Some("int_functions_*"), true).unwrap();
+    assert_eq!(libraries.len(), 1);
+    assert_eq!(libraries[0].name, "int_functions_lib");
+    assert_eq!(libraries[0].engine, "LUA");
+    assert_eq!(libraries[0].functions.len(), 2);
+    assert_eq!(libraries[0].code, Some(LIBRARY_CODE.to_string()));
+
+    let value: String = client.fcall("int_echo", vec![], vec!["hello"]).unwrap();
+    assert_eq!(value, "hello");
+
+    client.set("int_functions_key", "my_value").unwrap();
+    let value: String = client
+        .fcall_ro("int_get", vec!["int_functions_key"], vec![])
+        .unwrap();
+    assert_eq!(value, "my_value");
+
+    let payload = client.function_dump().unwrap();
+    client.function_delete("int_functions_lib").unwrap();
+    assert!(client
+        .function_list(Some("int_functions_*"), false)
+        .unwrap()
+        .is_empty());
+
+    client
+        .function_restore(&payload, Some(FunctionRestorePolicy::Replace))
+        .unwrap();
+    assert_eq!(
+        client
+            .function_list(Some("int_functions_*"), false)
+            .unwrap()
+            .len(),
+        1
+    );
+
+    client.function_delete("int_functions_lib").unwrap();
+}
@@ -0,0 +1,98 @@
+#![cfg(feature = "derive")]
+
+use simple_redis::{RedisError, RedisHash};
+
+#[derive(Debug, Clone, PartialEq, RedisHash)]
+struct User {
+    name: String,
+    age: u32,
+    #[redis(rename = "mail")]
+    email: Option<String>,
+}
+
+#[test]
+fn hset_hget_struct() {
+    let mut client = simple_redis::create("redis://127.0.0.1:6379/").unwrap();
+    client.del("hset_hget_struct").unwrap();
+
+    let user = User {
+        name: "user1".to_string(),
+        age: 30,
+        email: Some("user1@example.com".to_string()),
+    };
+    client.hset_struct("hset_hget_struct", &user).unwrap();
+
+    assert_eq!(
+        client.hget_string("hset_hget_struct", "mail").unwrap(),
+        "user1@example.com"
+    );
+    let stored_user: User = client.hget_struct("hset_hget_struct").unwrap();
+    assert_eq!(stored_user, user);
+
+    client.hdel("hset_hget_struct", "mail").unwrap();
+    let stored_user: User = client.hget_struct("hset_hget_struct").unwrap();
+    assert!(stored_user.email.is_none());
+}
+
+#[test]
+fn hset_struct_remove_none_field() {
+    let mut client = simple_redis::create("redis://127.0.0.1:6379/").unwrap();
+    client.del("hset_struct_remove_none_field").unwrap();
+
+    let mut user = User {
+        name: "user1".to_string(),
+        age: 30,
+        email: Some("user1@example.com".to_string()),
+    };
+    client
+        .hset_struct("hset_struct_remove_none_field", &user)
+        .unwrap();
+    assert!(client
+        .hexists("hset_struct_remove_none_field", "mail")
+        .unwrap());
+
+    user.email = None;
+    client
+        .hset_struct("hset_struct_remove_none_field", &user)
+        .unwrap();
+    assert!(!client
+        .hexists("hset_struct_remove_none_field", "mail")
+        .unwrap());
+
+    let stored_user: User = client.hget_struct("hset_struct_remove_none_field").unwrap();
+    assert_eq!(stored_user, user);
+}
+
+#[test]
+fn hget_struct_invalid_field() {
+    let mut client = simple_redis::create("redis://127.0.0.1:6379/").unwrap();
+    client.del("hget_struct_invalid_field").unwrap();
+
+    client
+        .hset("hget_struct_invalid_field", "name", "user1")
+        .unwrap();
+    client
+        .hset("hget_struct_invalid_field", "age", "old")
+        .unwrap();
+
+    match client.hget_struct::<User>("hget_struct_invalid_field") {
+        Err(RedisError::Parse {
+            field, raw_value, ..
+        }) => {
+            assert_eq!(field.unwrap(), "age");
+            assert_eq!(raw_value.unwrap(), "old");
+        }
+        result => panic!("Invalid result: {:?}", result),
+    }
+}
+
+#[test]
+fn hget_struct_not_found() {
+    let mut client = simple_redis::create("redis://127.0.0.1:6379/").unwrap();
+    client.del("hget_struct_not_found").unwrap();
+
+    match client.hget_struct::<User>("hget_struct_not_found") {
+        Err(RedisError::NotFound { field, .. }) => assert!(field.is_none()),
+        result => panic!("Invalid result: {:?}", result),
+    }
+}
@@ -0,0 +1,69 @@
+#[test]
+fn run_pipeline() {
+    let mut client = simple_redis::create("redis://127.0.0.1:6379/").unwrap();
+
+    let mut pipeline = client.pipeline();
+    pipeline
+        .set("int_pipeline_1", "my_value")
+        .del("int_pipeline_2")
+        .hset("int_pipeline_2", "field", 12)
+        .get("int_pipeline_1")
+        .hget("int_pipeline_2", "field");
+
+    let results = client.run_pipeline(&pipeline).unwrap();
+    assert_eq!(results.len(), 5);
+
+    let value: String = results.get(3).unwrap();
+    assert_eq!(value, "my_value");
+
+    let field: i32 = results.get_from_string(4).unwrap();
+    assert_eq!(field, 12);
+}
+
+#[test]
+fn run_pipeline_error_preserved() {
+    let mut client = simple_redis::create("redis://127.0.0.1:6379/").unwrap();
+
+    let mut pipeline = client.pipeline();
+    pipeline
+        .set("int_pipeline_3", "text")
+        .incr("int_pipeline_3")
+        .get("int_pipeline_3");
+
+    let results = client.run_pipeline(&pipeline).unwrap();
+    assert!(!results.is_error(0));
+    assert!(results.is_error(1));
+    assert!(results.get::<i64>(1).is_err());
+
+    let value: String = results.get(2).unwrap();
+    assert_eq!(value, "text");
+
+    assert!(client.is_connection_open());
+}
+
+#[test]
+fn run_pipeline_typed() {
+    let mut client = simple_redis::create("redis://127.0.0.1:6379/").unwrap();
+
+    let mut pipeline = client.pipeline();
+    pipeline
+        .del("int_pipeline_4")
+        .rpush("int_pipeline_4", "1")
+        .rpush("int_pipeline_4", "2")
+        .lrange("int_pipeline_4", 0, -1);
+
+    let (_, _, length, values): ((), i32, i32, Vec<String>) =
+        client.run_pipeline_typed(&pipeline).unwrap();
+    assert_eq!(length, 2);
+    assert_eq!(values, vec!["1", "2"]);
+}
+
+#[test]
+fn run_pipeline_empty() {
+    let mut client = simple_redis::create("redis://127.0.0.1:6379/").unwrap();
+
+    let pipeline = client.pipeline();
+    let results = client.run_pipeline(&pipeline).unwrap();
+    assert!(results.is_empty());
+    assert!(!client.is_connection_open());
+}
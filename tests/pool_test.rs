@@ -0,0 +1,52 @@
+use simple_redis::{ClientBuilder, PoolOptions};
+use std::thread;
+
+#[test]
+fn pool_commands() {
+    let pool = simple_redis::pool::create(
+        ClientBuilder::new("redis://127.0.0.1:6379/"),
+        PoolOptions::new(),
+    )
+    .unwrap();
+
+    let mut client = pool.get().unwrap();
+
+    let result = client.set("pool_commands", "my_value");
+    assert!(result.is_ok());
+
+    let value = client.get_string("pool_commands").unwrap();
+    assert_eq!(value, "my_value");
+
+    assert!(client.is_connection_open());
+}
+
+#[test]
+fn pool_shared_between_threads() {
+    let mut options = PoolOptions::new();
+    options.max_size = 2;
+
+    let pool =
+        simple_redis::pool::create(ClientBuilder::new("redis://127.0.0.1:6379/"), options).unwrap();
+
+    let mut handles = vec![];
+    for index in 0..6 {
+        let pool = pool.clone();
+        handles.push(thread::spawn(move || {
+            let key = format!("pool_shared_{}", index);
+            let mut client = pool.get().unwrap();
+
+            client.set(&key, index).unwrap();
+            let value = client.get::<i32>(&key).unwrap();
+            assert_eq!(value, index);
+
+            client.del(&key).unwrap();
+        }));
+    }
+
+    for handle in handles {
+        handle.join().unwrap();
+    }
+
+    assert!(pool.size() <= 2);
+    assert_eq!(pool.size(), pool.idle_count());
+}
@@ -1,4 +1,4 @@
-use simple_redis;
+use simple_redis::types::SubscriptionEvent;
 use simple_redis::{Interrupts, Message};
 use std::{thread, time};
 
@@ -104,7 +104,7 @@ fn pub_sub() {
                         panic!("test error");
                     },
                     &mut || -> Interrupts {
-                        counter = counter + 1;
+                        counter += 1;
 
                         let mut interrupts = Interrupts::new();
                         interrupts.stop = counter == 5;
@@ -196,3 +196,245 @@ fn pub_psub() {
         _ => panic!("test error"),
     };
 }
+
+#[test]
+fn pub_sub_resubscribe_after_connection_loss() {
+    let mut subscriber = simple_redis::create("redis://127.0.0.1:6379/").unwrap();
+    let mut policy = simple_redis::ReconnectPolicy::new();
+    policy.max_attempts = 5;
+    subscriber.set_reconnect_policy(policy);
+
+    subscriber.subscribe("int_pub_sub_resubscribe").unwrap();
+
+    thread::spawn(|| {
+        let mut publisher = simple_redis::create("redis://127.0.0.1:6379/").unwrap();
+        thread::sleep(time::Duration::from_secs(1));
+
+        // drop all pubsub connections
+        publisher
+            .run_command::<i64>("CLIENT", vec!["KILL", "TYPE", "pubsub"])
+            .unwrap();
+
+        loop {
+            thread::sleep(time::Duration::from_millis(200));
+            publisher
+                .publish("int_pub_sub_resubscribe", "after reconnect")
+                .unwrap();
+        }
+    });
+
+    let mut events = vec![];
+    subscriber
+        .fetch_messages_with_events(
+            &mut |message: Message| -> bool {
+                let payload: String = message.get_payload().unwrap();
+                assert_eq!(payload, "after reconnect");
+                true
+            },
+            &mut |event: SubscriptionEvent| events.push(event),
+            &mut || -> Interrupts { Interrupts::new() },
+        )
+        .unwrap();
+
+    assert_eq!(events.len(), 2);
+    assert!(matches!(events[0], SubscriptionEvent::Disconnected(_)));
+    assert!(matches!(events[1], SubscriptionEvent::Reconnected));
+}
+
+#[test]
+fn pub_sub_subscription_handle() {
+    let mut subscriber = simple_redis::create("redis://127.0.0.1:6379/").unwrap();
+    subscriber.subscribe("int_pub_sub_handle_control").unwrap();
+    let handle = subscriber.subscription_handle();
+
+    thread::spawn(|| {
+        let mut publisher = simple_redis::create("redis://127.0.0.1:6379/").unwrap();
+        thread::sleep(time::Duration::from_secs(1));
+
+        publisher
+            .publish("int_pub_sub_handle_control", "int_pub_sub_handle_data")
+            .unwrap();
+
+        loop {
+            thread::sleep(time::Duration::from_millis(200));
+            publisher
+                .publish("int_pub_sub_handle_data", "data message")
+                .unwrap();
+        }
+    });
+
+    let mut counter = 0;
+    subscriber
+        .fetch_messages(
+            &mut |message: Message| -> bool {
+                let payload: String = message.get_payload().unwrap();
+                counter += 1;
+
+                match counter {
+                    1 => {
+                        assert_eq!(message.get_channel_name(), "int_pub_sub_handle_control");
+                        handle.subscribe(&payload);
+                        false
+                    }
+                    _ => {
+                        assert_eq!(message.get_channel_name(), "int_pub_sub_handle_data");
+                        assert_eq!(payload, "data message");
+                        true
+                    }
+                }
+            },
+            &mut || -> Interrupts { Interrupts::new() },
+        )
+        .unwrap();
+
+    assert!(subscriber.is_subscribed("int_pub_sub_handle_data"));
+}
+
+#[test]
+fn pub_sub_subscription_handle_from_thread() {
+    let mut subscriber = simple_redis::create("redis://127.0.0.1:6379/").unwrap();
+    subscriber
+        .subscribe("int_pub_sub_handle_thread_idle")
+        .unwrap();
+    let handle = subscriber.subscription_handle();
+
+    thread::spawn(move || {
+        let mut publisher = simple_redis::create("redis://127.0.0.1:6379/").unwrap();
+        thread::sleep(time::Duration::from_secs(1));
+
+        // the subscriber is blocked on a read with a long polling time while the change is requested
+        handle.subscribe("int_pub_sub_handle_thread_data");
+
+        loop {
+            thread::sleep(time::Duration::from_millis(200));
+            publisher
+                .publish("int_pub_sub_handle_thread_data", "data message")
+                .unwrap();
+        }
+    });
+
+    let start_time = time::Instant::now();
+    subscriber
+        .fetch_messages(
+            &mut |message: Message| -> bool {
+                assert_eq!(message.get_channel_name(), "int_pub_sub_handle_thread_data");
+                true
+            },
+            &mut || -> Interrupts {
+                let mut interrupts = Interrupts::new();
+                interrupts.next_polling_time = Some(60000);
+                interrupts
+            },
+        )
+        .unwrap();
+
+    assert!(start_time.elapsed() < time::Duration::from_secs(10));
+}
+
+#[test]
+fn pub_sub_messages_iterator() {
+    let mut subscriber = simple_redis::create("redis://127.0.0.1:6379/").unwrap();
+    subscriber.subscribe("int_pub_sub_messages").unwrap();
+
+    thread::spawn(|| {
+        let mut publisher = simple_redis::create("redis://127.0.0.1:6379/").unwrap();
+
+        loop {
+            thread::sleep(time::Duration::from_millis(200));
+            publisher
+                .publish("int_pub_sub_messages", "iterator message")
+                .unwrap();
+        }
+    });
+
+    let mut messages = subscriber.messages();
+    messages.set_timeout(Some(time::Duration::from_secs(10)));
+
+    for result in messages.take(2) {
+        let message = result.unwrap();
+        let payload: String = message.get_payload().unwrap();
+        assert_eq!(payload, "iterator message");
+    }
+
+    // the client can still be used while the subscriber was running
+    assert!(subscriber.is_subscribed("int_pub_sub_messages"));
+}
+
+#[test]
+fn pub_sub_spawn_subscriber() {
+    let mut subscriber = simple_redis::create("redis://127.0.0.1:6379/").unwrap();
+    subscriber.subscribe("int_pub_sub_spawn").unwrap();
+
+    let (receiver, shutdown) = subscriber.spawn_subscriber(1);
+
+    thread::spawn(|| {
+        let mut publisher = simple_redis::create("redis://127.0.0.1:6379/").unwrap();
+
+        loop {
+            thread::sleep(time::Duration::from_millis(200));
+            publisher
+                .publish("int_pub_sub_spawn", "spawned message")
+                .unwrap();
+        }
+    });
+
+    let message = receiver
+        .recv_timeout(time::Duration::from_secs(10))
+        .unwrap()
+        .unwrap();
+    let payload: String = message.get_payload().unwrap();
+    assert_eq!(payload, "spawned message");
+
+    assert!(shutdown.is_running());
+    shutdown.shutdown();
+
+    // buffered messages can still be read but no new messages are fetched
+    while receiver.recv().is_ok() {}
+}
+
+#[test]
+fn pub_sub_message_router() {
+    let mut subscriber = simple_redis::create("redis://127.0.0.1:6379/").unwrap();
+
+    thread::spawn(|| {
+        let mut publisher = simple_redis::create("redis://127.0.0.1:6379/").unwrap();
+
+        loop {
+            thread::sleep(time::Duration::from_millis(200));
+            publisher
+                .publish("int_pub_sub_router.pattern", "pattern message")
+                .unwrap();
+            publisher
+                .publish("int_pub_sub_router", "channel message")
+                .unwrap();
+        }
+    });
+
+    let mut pattern_messages = 0;
+    let mut channel_messages = 0;
+    {
+        let mut router = simple_redis::MessageRouter::new();
+        router.add_pattern_handler("int_pub_sub_router.*", |message: Message| -> bool {
+            let payload: String = message.get_payload().unwrap();
+            assert_eq!(payload, "pattern message");
+            pattern_messages += 1;
+            false
+        });
+        router.add_channel_handler("int_pub_sub_router", |message: Message| -> bool {
+            let payload: String = message.get_payload().unwrap();
+            assert_eq!(payload, "channel message");
+            channel_messages += 1;
+            channel_messages == 2
+        });
+        router.set_fallback_handler(|_| panic!("test error"));
+
+        subscriber
+            .route_messages(&mut router, &mut || -> Interrupts { Interrupts::new() })
+            .unwrap();
+    }
+
+    assert!(pattern_messages > 0);
+    assert_eq!(channel_messages, 2);
+    assert!(subscriber.is_subscribed("int_pub_sub_router"));
+    assert!(subscriber.is_psubscribed("int_pub_sub_router.*"));
+}
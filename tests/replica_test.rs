@@ -0,0 +1,126 @@
+use simple_redis::client::Client;
+use simple_redis::types::RedisEmptyResult;
+use simple_redis::{Pipeline, ReplicaSelection};
+
+#[test]
+fn read_from_replica() {
+    // the replica is simulated by another database of the same server
+    let mut client = simple_redis::ClientBuilder::new("redis://127.0.0.1:6379/0")
+        .replica("redis://127.0.0.1:6379/1")
+        .build()
+        .unwrap();
+    let mut replica = simple_redis::create("redis://127.0.0.1:6379/1").unwrap();
+
+    client.set("replica_test_key", "primary").unwrap();
+    replica.set("replica_test_key", "replica").unwrap();
+
+    let value = client.get_string("replica_test_key").unwrap();
+    assert_eq!(value, "replica");
+
+    let exists: bool = client
+        .run_command_bool_response("EXISTS", vec!["replica_test_key"])
+        .unwrap();
+    assert!(exists);
+
+    client.del("replica_test_key").unwrap();
+    let value = client.get_string("replica_test_key").unwrap();
+    assert_eq!(value, "replica");
+
+    replica.del("replica_test_key").unwrap();
+}
+
+#[test]
+fn transaction_reads_from_primary() {
+    let mut client = simple_redis::ClientBuilder::new("redis://127.0.0.1:6379/0")
+        .replica("redis://127.0.0.1:6379/1")
+        .build()
+        .unwrap();
+    let mut replica = simple_redis::create("redis://127.0.0.1:6379/1").unwrap();
+
+    client.set("replica_test_transaction", 10).unwrap();
+    replica.set("replica_test_transaction", 1).unwrap();
+
+    client
+        .transaction(
+            vec!["replica_test_transaction"],
+            0,
+            &mut |client: &mut Client, pipeline: &mut Pipeline| -> RedisEmptyResult {
+                let value = client.get::<i64>("replica_test_transaction")?;
+                assert_eq!(value, 10);
+
+                pipeline.set("replica_test_transaction", value + 1);
+                Ok(())
+            },
+        )
+        .unwrap();
+
+    let mut primary = simple_redis::create("redis://127.0.0.1:6379/0").unwrap();
+    let value = primary.get::<i64>("replica_test_transaction").unwrap();
+    assert_eq!(value, 11);
+
+    // reads outside of the transaction are sent to the replica again
+    let value = client.get::<i64>("replica_test_transaction").unwrap();
+    assert_eq!(value, 1);
+
+    primary.del("replica_test_transaction").unwrap();
+    replica.del("replica_test_transaction").unwrap();
+}
+
+#[test]
+fn read_from_replicas_round_robin() {
+    let mut client = simple_redis::ClientBuilder::new("redis://127.0.0.1:6379/0")
+        .replica("redis://127.0.0.1:6379/1")
+        .replica("redis://127.0.0.1:6379/2")
+        .replica_selection(ReplicaSelection::RoundRobin)
+        .build()
+        .unwrap();
+    let mut replica1 = simple_redis::create("redis://127.0.0.1:6379/1").unwrap();
+    let mut replica2 = simple_redis::create("redis://127.0.0.1:6379/2").unwrap();
+
+    replica1.set("replica_test_round_robin", "1").unwrap();
+    replica2.set("replica_test_round_robin", "2").unwrap();
+
+    let first = client.get_string("replica_test_round_robin").unwrap();
+    let second = client.get_string("replica_test_round_robin").unwrap();
+    let third = client.get_string("replica_test_round_robin").unwrap();
+    assert_eq!(first, "1");
+    assert_eq!(second, "2");
+    assert_eq!(third, "1");
+
+    replica1.del("replica_test_round_robin").unwrap();
+    replica2.del("replica_test_round_robin").unwrap();
+}
+
+#[test]
+fn read_from_replicas_least_latency() {
+    let mut client = simple_redis::ClientBuilder::new("redis://127.0.0.1:6379/0")
+        .replica("redis://127.0.0.1:6379/1")
+        .replica_selection(ReplicaSelection::LeastLatency)
+        .build()
+        .unwrap();
+    let mut replica = simple_redis::create("redis://127.0.0.1:6379/1").unwrap();
+
+    replica
+        .set("replica_test_least_latency", "replica")
+        .unwrap();
+
+    let value = client.get_string("replica_test_least_latency").unwrap();
+    assert_eq!(value, "replica");
+
+    replica.del("replica_test_least_latency").unwrap();
+}
+
+#[test]
+fn read_fallback_to_primary() {
+    let mut client = simple_redis::ClientBuilder::new("redis://127.0.0.1:6379/0")
+        .replica("redis://127.0.0.1:1/")
+        .build()
+        .unwrap();
+
+    client.set("replica_test_fallback", "primary").unwrap();
+
+    let value = client.get_string("replica_test_fallback").unwrap();
+    assert_eq!(value, "primary");
+
+    client.del("replica_test_fallback").unwrap();
+}
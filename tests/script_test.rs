@@ -0,0 +1,64 @@
+use simple_redis::Script;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+#[test]
+fn eval() {
+    let mut client = simple_redis::create("redis://127.0.0.1:6379/").unwrap();
+
+    let value: i64 = client
+        .eval("return tonumber(ARGV[1]) * 2", vec![], vec!["21"])
+        .unwrap();
+    assert_eq!(value, 42);
+}
+
+#[test]
+fn script_load_and_evalsha() {
+    let mut client = simple_redis::create("redis://127.0.0.1:6379/").unwrap();
+
+    let script = Script::new("return KEYS[1]");
+    let sha1 = client.script_load(script.get_code()).unwrap();
+    assert_eq!(sha1, script.get_hash());
+
+    assert!(client.script_exists(&sha1).unwrap());
+
+    let value: String = client.evalsha(&sha1, vec!["int_script_1"], vec![]).unwrap();
+    assert_eq!(value, "int_script_1");
+}
+
+#[test]
+fn run_script_not_cached() {
+    let mut client = simple_redis::create("redis://127.0.0.1:6379/").unwrap();
+
+    // the comment makes the script unique per test run, so it is not cached by a previous run
+    let run_id = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap()
+        .as_nanos();
+    let script = Script::new(&format!(
+        "redis.call('SET', KEYS[1], ARGV[1]); return redis.call('GET', KEYS[1]) -- run_script_not_cached {}",
+        run_id
+    ));
+    assert!(!client.script_exists(script.get_hash()).unwrap());
+
+    let value: String = client
+        .run_script(&script, vec!["int_script_2"], vec!["my_value"])
+        .unwrap();
+    assert_eq!(value, "my_value");
+
+    assert!(client.script_exists(script.get_hash()).unwrap());
+}
+
+#[test]
+fn run_script_from_string_response() {
+    let mut client = simple_redis::create("redis://127.0.0.1:6379/").unwrap();
+
+    let script = Script::new("return ARGV[1]");
+
+    let value: f64 = client
+        .run_script_from_string_response(&script, vec![], vec!["1.5"])
+        .unwrap();
+    assert_eq!(value, 1.5);
+
+    let result = client.run_script_from_string_response::<f64>(&script, vec![], vec!["text"]);
+    assert!(result.is_err());
+}
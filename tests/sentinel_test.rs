@@ -0,0 +1,58 @@
+use simple_redis::{Interrupts, Message};
+use std::{thread, time};
+
+/// The sentinel started by the start-redis-sentinel task, which monitors the local redis server as `mymaster`
+const SENTINEL_ADDRESS: &str = "127.0.0.1:26379";
+
+#[test]
+fn sentinel_create() {
+    let connection_string = format!("redis+sentinel://127.0.0.1:1,{}/mymaster", SENTINEL_ADDRESS);
+    let mut client = simple_redis::create(&connection_string).unwrap();
+
+    client.set("sentinel_create", "value").unwrap();
+    let value = client.get_string("sentinel_create").unwrap();
+    assert_eq!(value, "value");
+}
+
+#[test]
+fn sentinel_builder_unknown_master() {
+    let mut client = simple_redis::ClientBuilder::sentinel(vec![SENTINEL_ADDRESS], "unknown")
+        .build()
+        .unwrap();
+
+    let result = client.get_string("sentinel_unknown_master");
+    assert!(result.is_err());
+}
+
+#[test]
+fn sentinel_pub_sub() {
+    let mut subscriber = simple_redis::ClientBuilder::sentinel(vec![SENTINEL_ADDRESS], "mymaster")
+        .build()
+        .unwrap();
+    subscriber.subscribe("int_sentinel_pub_sub").unwrap();
+
+    thread::spawn(|| {
+        let mut publisher =
+            simple_redis::ClientBuilder::sentinel(vec![SENTINEL_ADDRESS], "mymaster")
+                .build()
+                .unwrap();
+
+        loop {
+            thread::sleep(time::Duration::from_millis(200));
+            publisher
+                .publish("int_sentinel_pub_sub", "sentinel message")
+                .unwrap();
+        }
+    });
+
+    subscriber
+        .fetch_messages(
+            &mut |message: Message| -> bool {
+                let payload: String = message.get_payload().unwrap();
+                assert_eq!(payload, "sentinel message");
+                true
+            },
+            &mut || -> Interrupts { Interrupts::new() },
+        )
+        .unwrap();
+}
@@ -0,0 +1,202 @@
+use simple_redis::types::StreamTrim;
+use std::time::Duration;
+
+#[test]
+fn stream_flow() {
+    let mut client = simple_redis::create("redis://127.0.0.1:6379/").unwrap();
+
+    client.del("stream_flow_1").unwrap();
+    client.del("stream_flow_2").unwrap();
+
+    let id = client
+        .xadd("stream_flow_1", Some("1-1"), vec![("event", "first")], None)
+        .unwrap();
+    assert_eq!(id, "1-1");
+    let second_id = client
+        .xadd(
+            "stream_flow_1",
+            None,
+            vec![("event", "second"), ("user", "user1")],
+            None,
+        )
+        .unwrap();
+    client
+        .xadd("stream_flow_1", None, vec![("event", "third")], None)
+        .unwrap();
+    assert_eq!(client.xlen("stream_flow_1").unwrap(), 3);
+
+    let entries = client.xrange("stream_flow_1", "-", "+", None).unwrap();
+    assert_eq!(entries.len(), 3);
+    assert_eq!(entries[0].id, "1-1");
+    assert_eq!(entries[0].fields.get("event").unwrap(), "first");
+    assert_eq!(entries[1].id, second_id);
+    assert_eq!(entries[1].fields.get("user").unwrap(), "user1");
+
+    let entries = client
+        .xrevrange("stream_flow_1", "+", "-", Some(1))
+        .unwrap();
+    assert_eq!(entries.len(), 1);
+    assert_eq!(entries[0].fields.get("event").unwrap(), "third");
+
+    client
+        .xadd("stream_flow_2", Some("5-0"), vec![("event", "other")], None)
+        .unwrap();
+    let streams = client
+        .xread(
+            vec![("stream_flow_1", "1-1"), ("stream_flow_2", "0")],
+            Some(10),
+            None,
+        )
+        .unwrap();
+    assert_eq!(streams.len(), 2);
+    assert_eq!(streams[0].0, "stream_flow_1");
+    assert_eq!(streams[0].1.len(), 2);
+    assert_eq!(streams[1].0, "stream_flow_2");
+    assert_eq!(streams[1].1[0].id, "5-0");
+
+    let streams = client
+        .xread(
+            vec![("stream_flow_2", "$")],
+            None,
+            Some(Duration::from_millis(100)),
+        )
+        .unwrap();
+    assert!(streams.is_empty());
+
+    assert_eq!(client.xdel("stream_flow_1", vec!["1-1", "9-9"]).unwrap(), 1);
+    assert_eq!(client.xlen("stream_flow_1").unwrap(), 2);
+
+    let evicted = client
+        .xtrim(
+            "stream_flow_1",
+            StreamTrim::MaxLen {
+                count: 1,
+                approximate: false,
+            },
+        )
+        .unwrap();
+    assert_eq!(evicted, 1);
+
+    client
+        .xadd(
+            "stream_flow_1",
+            None,
+            vec![("event", "fourth")],
+            Some(StreamTrim::MaxLen {
+                count: 1,
+                approximate: false,
+            }),
+        )
+        .unwrap();
+    assert_eq!(client.xlen("stream_flow_1").unwrap(), 1);
+}
+
+#[test]
+fn consumer_group_flow() {
+    let mut client = simple_redis::create("redis://127.0.0.1:6379/").unwrap();
+
+    client.del("stream_group_flow").unwrap();
+
+    client
+        .xgroup_create("stream_group_flow", "group1", "$", true)
+        .unwrap();
+    assert!(client
+        .xgroup_create("stream_group_flow", "group1", "$", true)
+        .is_err());
+
+    let first_id = client
+        .xadd("stream_group_flow", None, vec![("event", "first")], None)
+        .unwrap();
+    let second_id = client
+        .xadd("stream_group_flow", None, vec![("event", "second")], None)
+        .unwrap();
+
+    let streams = client
+        .xreadgroup(
+            "group1",
+            "consumer1",
+            vec![("stream_group_flow", ">")],
+            Some(10),
+            None,
+            false,
+        )
+        .unwrap();
+    assert_eq!(streams.len(), 1);
+    assert_eq!(streams[0].1.len(), 2);
+
+    let pending = client
+        .xpending("stream_group_flow", "group1", "-", "+", 10, None)
+        .unwrap();
+    assert_eq!(pending.len(), 2);
+    assert_eq!(pending[0].id, first_id);
+    assert_eq!(pending[0].consumer, "consumer1");
+    assert_eq!(pending[0].delivery_count, 1);
+
+    assert_eq!(
+        client
+            .xack("stream_group_flow", "group1", vec![&first_id])
+            .unwrap(),
+        1
+    );
+
+    let claimed = client
+        .xclaim(
+            "stream_group_flow",
+            "group1",
+            "consumer2",
+            Duration::from_millis(0),
+            vec![&second_id],
+        )
+        .unwrap();
+    assert_eq!(claimed.len(), 1);
+    assert_eq!(claimed[0].id, second_id);
+
+    let pending = client
+        .xpending(
+            "stream_group_flow",
+            "group1",
+            "-",
+            "+",
+            10,
+            Some("consumer2"),
+        )
+        .unwrap();
+    assert_eq!(pending.len(), 1);
+    assert_eq!(pending[0].delivery_count, 2);
+
+    let result = client
+        .xautoclaim(
+            "stream_group_flow",
+            "group1",
+            "consumer3",
+            Duration::from_millis(0),
+            "0-0",
+            Some(10),
+        )
+        .unwrap();
+    assert_eq!(result.next_id, "0-0");
+    assert_eq!(result.entries.len(), 1);
+    assert_eq!(result.entries[0].fields.get("event").unwrap(), "second");
+
+    client
+        .xgroup_setid("stream_group_flow", "group1", "0")
+        .unwrap();
+    let streams = client
+        .xreadgroup(
+            "group1",
+            "consumer1",
+            vec![("stream_group_flow", ">")],
+            None,
+            None,
+            true,
+        )
+        .unwrap();
+    assert_eq!(streams[0].1.len(), 2);
+
+    assert!(client
+        .xgroup_destroy("stream_group_flow", "group1")
+        .unwrap());
+    assert!(!client
+        .xgroup_destroy("stream_group_flow", "group1")
+        .unwrap());
+}
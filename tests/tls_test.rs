@@ -0,0 +1,49 @@
+#![cfg(feature = "tls-rustls")]
+
+use std::fs;
+
+/// The certificates are generated by the start-redis-tls task
+const CERTIFICATES_DIRECTORY: &str = "./target/redis-tls";
+
+fn read_certificate(name: &str) -> Vec<u8> {
+    fs::read(format!("{}/{}", CERTIFICATES_DIRECTORY, name)).unwrap()
+}
+
+#[test]
+fn tls_connection() {
+    let mut client = simple_redis::ClientBuilder::new("rediss://localhost:6380/")
+        .tls_ca_certificates(&read_certificate("ca.crt"))
+        .build()
+        .unwrap();
+
+    client.set("tls_test_key", "tls_value").unwrap();
+    let value = client.get_string("tls_test_key").unwrap();
+    assert_eq!(value, "tls_value");
+
+    client.del("tls_test_key").unwrap();
+}
+
+#[test]
+fn tls_mutual_authentication() {
+    let mut client = simple_redis::ClientBuilder::new("rediss://localhost:6380/")
+        .tls_ca_certificates(&read_certificate("ca.crt"))
+        .tls_client_certificate(
+            &read_certificate("client.crt"),
+            &read_certificate("client.key"),
+        )
+        .build()
+        .unwrap();
+
+    let value = client
+        .run_command::<String>("ECHO", vec!["testing"])
+        .unwrap();
+    assert_eq!(value, "testing");
+}
+
+#[test]
+fn tls_untrusted_server() {
+    let mut client = simple_redis::create("rediss://localhost:6380/").unwrap();
+
+    let result = client.run_command::<String>("ECHO", vec!["testing"]);
+    assert!(result.is_err());
+}
@@ -0,0 +1,103 @@
+use simple_redis::client::Client;
+use simple_redis::types::RedisEmptyResult;
+use simple_redis::Pipeline;
+
+#[test]
+fn transaction_check_and_set() {
+    let mut client = simple_redis::create("redis://127.0.0.1:6379/").unwrap();
+    client.set("int_transaction_1", 10).unwrap();
+
+    let results = client
+        .transaction(
+            vec!["int_transaction_1"],
+            5,
+            &mut |client: &mut Client, pipeline: &mut Pipeline| -> RedisEmptyResult {
+                let value = client.get::<i64>("int_transaction_1")?;
+                pipeline
+                    .set("int_transaction_1", value * 2)
+                    .get("int_transaction_1");
+                Ok(())
+            },
+        )
+        .unwrap();
+
+    assert_eq!(results.len(), 2);
+    let value: i64 = results.get(1).unwrap();
+    assert_eq!(value, 20);
+}
+
+#[test]
+fn transaction_retry_on_conflict() {
+    let mut client = simple_redis::create("redis://127.0.0.1:6379/").unwrap();
+    client.set("int_transaction_2", 1).unwrap();
+
+    let mut attempts = 0;
+    let results = client
+        .transaction(
+            vec!["int_transaction_2"],
+            5,
+            &mut |client: &mut Client, pipeline: &mut Pipeline| -> RedisEmptyResult {
+                attempts += 1;
+
+                let value = client.get::<i64>("int_transaction_2")?;
+                if attempts == 1 {
+                    // modify the watched key from another connection
+                    let mut other_client = simple_redis::create("redis://127.0.0.1:6379/").unwrap();
+                    other_client.set("int_transaction_2", 100).unwrap();
+                }
+
+                pipeline.set("int_transaction_2", value + 1);
+                Ok(())
+            },
+        )
+        .unwrap();
+
+    assert_eq!(attempts, 2);
+    assert_eq!(results.len(), 1);
+
+    let value = client.get::<i64>("int_transaction_2").unwrap();
+    assert_eq!(value, 101);
+}
+
+#[test]
+fn transaction_retries_exhausted() {
+    let mut client = simple_redis::create("redis://127.0.0.1:6379/").unwrap();
+    client.set("int_transaction_3", 1).unwrap();
+
+    let mut attempts = 0;
+    let result = client.transaction(
+        vec!["int_transaction_3"],
+        2,
+        &mut |_client: &mut Client, pipeline: &mut Pipeline| -> RedisEmptyResult {
+            attempts += 1;
+
+            let mut other_client = simple_redis::create("redis://127.0.0.1:6379/").unwrap();
+            other_client.incr("int_transaction_3").unwrap();
+
+            pipeline.set("int_transaction_3", 0);
+            Ok(())
+        },
+    );
+
+    assert!(result.is_err());
+    assert_eq!(attempts, 3);
+}
+
+#[test]
+fn transaction_queued_error() {
+    let mut client = simple_redis::create("redis://127.0.0.1:6379/").unwrap();
+
+    let results = client
+        .transaction(vec![], 0, &mut |_client: &mut Client,
+                                      pipeline: &mut Pipeline|
+         -> RedisEmptyResult {
+            pipeline
+                .set("int_transaction_4", "text")
+                .incr("int_transaction_4");
+            Ok(())
+        })
+        .unwrap();
+
+    assert!(!results.is_error(0));
+    assert!(results.is_error(1));
+}
@@ -0,0 +1,86 @@
+#![cfg(unix)]
+
+use simple_redis::{Interrupts, Message};
+use std::{thread, time};
+
+/// The unix socket of the redis server started by the start-redis task
+const SOCKET_PATH: &str = "/tmp/simple_redis.sock";
+
+#[test]
+fn unix_socket_commands() {
+    let mut client = simple_redis::create(&format!("unix://{}", SOCKET_PATH)).unwrap();
+
+    client.set("unix_socket_commands", "value").unwrap();
+    let value = client.get_string("unix_socket_commands").unwrap();
+    assert_eq!(value, "value");
+
+    client.del("unix_socket_commands").unwrap();
+}
+
+#[test]
+fn unix_socket_db_selection() {
+    let mut client = simple_redis::create(&format!("redis+unix://{}?db=1", SOCKET_PATH)).unwrap();
+    let mut tcp_client = simple_redis::create("redis://127.0.0.1:6379/1").unwrap();
+
+    client.set("unix_socket_db_selection", "db1").unwrap();
+    let value = tcp_client.get_string("unix_socket_db_selection").unwrap();
+    assert_eq!(value, "db1");
+
+    let mut builder_client = simple_redis::ClientBuilder::new(&format!("unix://{}", SOCKET_PATH))
+        .db(1)
+        .build()
+        .unwrap();
+    let value = builder_client
+        .get_string("unix_socket_db_selection")
+        .unwrap();
+    assert_eq!(value, "db1");
+
+    client.del("unix_socket_db_selection").unwrap();
+}
+
+#[test]
+fn unix_socket_pub_sub() {
+    let mut subscriber = simple_redis::create(&format!("unix://{}", SOCKET_PATH)).unwrap();
+    subscriber.subscribe("int_unix_socket_pub_sub").unwrap();
+
+    thread::spawn(|| {
+        let mut publisher = simple_redis::create(&format!("unix://{}", SOCKET_PATH)).unwrap();
+
+        loop {
+            thread::sleep(time::Duration::from_millis(50));
+            publisher
+                .publish("int_unix_socket_pub_sub", "unix message")
+                .unwrap();
+        }
+    });
+
+    let mut payload = String::new();
+    subscriber
+        .fetch_messages(
+            &mut |message: Message| -> bool {
+                payload = message.get_payload().unwrap();
+                true
+            },
+            &mut || -> Interrupts { Interrupts::new() },
+        )
+        .unwrap();
+    assert_eq!(payload, "unix message");
+}
+
+#[test]
+fn unix_socket_password() {
+    // the server behind this socket is started by the start-redis task with requirepass
+    let mut client =
+        simple_redis::create("redis+unix:///tmp/simple_redis_auth.sock?pass=simple_redis_secret")
+            .unwrap();
+
+    client.set("unix_socket_password", "value").unwrap();
+    let value = client.get_string("unix_socket_password").unwrap();
+    assert_eq!(value, "value");
+
+    client.del("unix_socket_password").unwrap();
+
+    let mut client = simple_redis::create("redis+unix:///tmp/simple_redis_auth.sock").unwrap();
+    let result = client.get_string("unix_socket_password");
+    assert!(result.is_err());
+}